@@ -50,7 +50,7 @@ impl ItemProcessor<Person, Person> for UpperCaseProcessor {
             email: item.email.to_uppercase(),
             birth_date: Date::from_calendar_date(2019, Month::January, 1).unwrap(),
         };
-        Ok(person)
+        Ok(Some(person))
     }
 }
 
@@ -61,7 +61,7 @@ fn main() -> Result<()> {
 
     let reader = JsonItemReaderBuilder::new().from_reader(file);
 
-    let processor = UpperCaseProcessor::default();
+    let processor = UpperCaseProcessor;
 
     let writer = CsvItemWriterBuilder::new()
         .has_headers(true)
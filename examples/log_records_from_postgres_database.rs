@@ -45,10 +45,10 @@ async fn main() -> Result<()> {
 
     // Prepare reader
     let query = "SELECT * from person";
-    let row_mapper = PersonRowMapper::default();
+    let row_mapper = PersonRowMapper;
     let reader = RdbcItemReaderBuilder::new()
         .pool(&pool)
-        .query(&query)
+        .query(query)
         .row_mapper(&row_mapper)
         .page_size(5)
         .build();
@@ -1,29 +1,12 @@
 use anyhow::Result;
-use mongodb::{
-    bson::{doc, oid::ObjectId},
-    sync::Client,
-};
+use mongodb::sync::Client;
 use serde::{Deserialize, Serialize};
 use spring_batch_rs::{
     core::step::{Step, StepBuilder, StepInstance},
     item::csv::csv_reader::CsvItemReaderBuilder,
-    item::mongodb::{mongodb_reader::WithObjectId, mongodb_writer::MongodbItemWriterBuilder},
+    item::mongodb::mongodb_writer::MongodbItemWriterBuilder,
 };
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-struct Book {
-    #[serde(rename = "oid")]
-    id: ObjectId,
-    title: String,
-    author: String,
-}
-
-impl WithObjectId for Book {
-    fn get_id(&self) -> ObjectId {
-        self.id
-    }
-}
-
 #[derive(Serialize, Deserialize, Clone)]
 struct FormattedBook {
     title: String,
@@ -31,7 +14,7 @@ struct FormattedBook {
 }
 
 fn main() -> Result<()> {
-    let url = format!("mongodb://127.0.0.1:27017/");
+    let url = "mongodb://127.0.0.1:27017/".to_string();
 
     let client: Client = Client::with_uri_str(&url).unwrap();
 
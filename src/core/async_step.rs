@@ -0,0 +1,795 @@
+use std::{
+    cell::{Cell, OnceCell, RefCell},
+    time::Instant,
+};
+
+use log::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::BatchError;
+
+use super::{
+    build_name,
+    item::{AsyncItemReader, AsyncItemWriter, DefaultProcessor, ItemProcessor},
+    parameters::JobParameters,
+    serde_millis,
+    step::{
+        ChunkStatistics, ChunkStatus, LimitSkipPolicy, SkipPolicy, StepExecution, StepListener,
+        StepStatus,
+    },
+};
+
+type StepResult<T> = Result<T, T>;
+
+type ChunkResult<T> = Result<T, BatchError>;
+
+/// A chunk-oriented step awaiting an asynchronous reader and writer.
+///
+/// It runs inside an existing async runtime, without blocking a thread on each read or write.
+/// Items are processed with a regular, synchronous `ItemProcessor`. Errors are skipped by the
+/// skip limit or the skip policy, and listeners are notified, as in `StepInstance`.
+///
+/// Failed reads, processes and writes are not retried: the step cannot wait between attempts
+/// without depending on a runtime. An asynchronous writer retries its own writes if needed.
+/// Skipped items are not written to a skip writer, and the step has no timeout, flush
+/// interval nor adaptive chunk size.
+pub struct AsyncStepInstance<'a, R, W> {
+    id: Uuid,
+    name: OnceCell<String>,
+    status: Cell<StepStatus>,
+    reader: &'a dyn AsyncItemReader<R>,
+    processor: &'a dyn ItemProcessor<R, W>,
+    writer: &'a dyn AsyncItemWriter<W>,
+    chunk_size: usize,
+    skip_limit: usize,
+    skip_policy: Option<&'a dyn SkipPolicy>,
+    listeners: Vec<&'a dyn StepListener>,
+    read_count: Cell<usize>,
+    process_count: Cell<usize>,
+    write_count: Cell<usize>,
+    read_error_count: Cell<usize>,
+    process_error_count: Cell<usize>,
+    write_error_count: Cell<usize>,
+    filter_count: Cell<usize>,
+    warnings: RefCell<Vec<BatchError>>,
+    first_error: RefCell<Option<BatchError>>,
+    chunk_statistics: RefCell<ChunkStatistics>,
+    start: Cell<Instant>,
+}
+
+impl<'a, R, W> AsyncStepInstance<'a, R, W> {
+    /// Executes the step.
+    ///
+    /// Returns the execution details of the step, as an error if the step failed.
+    // The execution details are returned in both cases, so boxing the error would not help.
+    #[allow(clippy::result_large_err)]
+    pub async fn execute(&self) -> StepResult<StepExecution> {
+        self.start.set(Instant::now());
+        self.reset();
+        info!("Start of step: {}, id: {}", self.get_name(), self.id);
+        self.notify(|listener, execution| listener.before_step(execution));
+
+        self.manage_error(self.writer.open().await);
+
+        let mut read_items: Vec<R> = Vec::with_capacity(self.chunk_size);
+
+        loop {
+            self.notify(|listener, execution| listener.before_chunk(execution));
+            let read_chunk_result = self.read_chunk(&mut read_items).await;
+
+            if read_chunk_result.is_err() {
+                self.status.set(StepStatus::ReadError);
+                break;
+            }
+
+            let chunk_start = Instant::now();
+            let processor_chunk_result = self.process_chunk(&read_items);
+
+            if processor_chunk_result.is_err() {
+                self.status.set(StepStatus::ProcessorError);
+                break;
+            }
+
+            let write_chunk_result = self.write_chunk(&processor_chunk_result.unwrap()).await;
+            if !read_items.is_empty() {
+                self.chunk_statistics
+                    .borrow_mut()
+                    .record(chunk_start.elapsed(), false);
+            }
+            self.notify(|listener, execution| listener.after_chunk(execution));
+
+            if write_chunk_result.is_err() {
+                self.status.set(StepStatus::WriteError);
+                break;
+            }
+
+            if read_chunk_result.unwrap() == ChunkStatus::Finished {
+                self.status.set(StepStatus::Success);
+                break;
+            }
+        }
+
+        self.manage_error(self.writer.close().await);
+
+        info!("End of step: {}, id: {}", self.get_name(), self.id);
+
+        let step_execution = self.execution();
+        for listener in &self.listeners {
+            listener.after_step(&step_execution);
+        }
+
+        if StepStatus::Success == self.status.get() {
+            Ok(step_execution)
+        } else {
+            Err(step_execution)
+        }
+    }
+
+    /// Gets the status of the step.
+    pub fn get_status(&self) -> StepStatus {
+        self.status.get()
+    }
+
+    /// Gets the name of the step.
+    pub fn get_name(&self) -> &String {
+        self.name.get_or_init(build_name)
+    }
+
+    /// Gets the ID of the step.
+    pub fn get_id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Gets the number of items read by the step.
+    pub fn get_read_count(&self) -> usize {
+        self.read_count.get()
+    }
+
+    /// Gets the number of items written by the step.
+    pub fn get_write_count(&self) -> usize {
+        self.write_count.get()
+    }
+
+    /// Gets the number of read errors encountered by the step.
+    pub fn get_read_error_count(&self) -> usize {
+        self.read_error_count.get()
+    }
+
+    /// Gets the number of write errors encountered by the step.
+    pub fn get_write_error_count(&self) -> usize {
+        self.write_error_count.get()
+    }
+
+    /// Gets the number of items filtered by the processor.
+    pub fn get_filter_count(&self) -> usize {
+        self.filter_count.get()
+    }
+
+    /// Builds the execution details of the step from its current state.
+    fn execution(&self) -> StepExecution {
+        let start = self.start.get();
+        let duration = start.elapsed();
+        let (started_at, ended_at) = serde_millis::wall_clock(duration);
+
+        StepExecution {
+            start,
+            end: Instant::now(),
+            started_at,
+            ended_at,
+            duration,
+            read_count: self.read_count.get(),
+            process_count: self.process_count.get(),
+            write_count: self.write_count.get(),
+            filter_count: self.filter_count.get(),
+            read_error_count: self.read_error_count.get(),
+            process_error_count: self.process_error_count.get(),
+            write_error_count: self.write_error_count.get(),
+            retry_count: 0,
+            warnings: self.warnings.borrow().clone(),
+            first_error: self.first_error.borrow().clone(),
+            parameters: JobParameters::new(),
+            chunk_statistics: self.chunk_statistics.borrow().clone(),
+            total_hint: None,
+        }
+    }
+
+    /// Checks if the error must make the step fail, according to the skip policy or
+    /// the skip limit.
+    fn is_skip_limit_reached(&self, error: &BatchError) -> bool {
+        let error_count = self.read_error_count.get()
+            + self.write_error_count.get()
+            + self.process_error_count.get();
+
+        let should_skip = match self.skip_policy {
+            Some(policy) => policy.should_skip(error, error_count),
+            None => LimitSkipPolicy::new(self.skip_limit).should_skip(error, error_count),
+        };
+
+        !should_skip
+    }
+
+    /// Resets the status and the counters of the step, which may run several times.
+    fn reset(&self) {
+        self.status.set(StepStatus::Starting);
+        for count in [
+            &self.read_count,
+            &self.process_count,
+            &self.write_count,
+            &self.filter_count,
+            &self.read_error_count,
+            &self.process_error_count,
+            &self.write_error_count,
+        ] {
+            count.set(0);
+        }
+        self.warnings.borrow_mut().clear();
+        self.first_error.replace(None);
+        self.chunk_statistics.replace(ChunkStatistics::default());
+    }
+
+    /// Notifies the listeners with the current execution of the step.
+    fn notify(&self, event: impl Fn(&dyn StepListener, &StepExecution)) {
+        if self.listeners.is_empty() {
+            return;
+        }
+
+        let execution = self.execution();
+        for listener in &self.listeners {
+            event(*listener, &execution);
+        }
+    }
+
+    /// Notifies the listeners of a skipped error.
+    fn notify_skip(&self, error: &BatchError) {
+        for listener in &self.listeners {
+            listener.on_skip(error);
+        }
+    }
+
+    /// Reads a chunk of items from the reader.
+    async fn read_chunk(&self, read_items: &mut Vec<R>) -> ChunkResult<ChunkStatus> {
+        debug!("Start reading chunk");
+        read_items.clear();
+
+        loop {
+            match self.reader.read().await {
+                Ok(Some(item)) => {
+                    read_items.push(item);
+                    self.read_count.set(self.read_count.get() + 1);
+
+                    if read_items.len() >= self.chunk_size {
+                        debug!("End reading chunk: FULL");
+                        return Ok(ChunkStatus::Full);
+                    }
+                }
+                Ok(None) => {
+                    debug!("End reading chunk: FINISHED");
+                    return Ok(ChunkStatus::Finished);
+                }
+                Err(err) => {
+                    self.read_error_count.set(self.read_error_count.get() + 1);
+                    if self.is_skip_limit_reached(&err) {
+                        self.record_failure(err);
+                        return Err(BatchError::ItemReader("error limit reached".to_string()));
+                    }
+                    warn!("Error occurred during read item: {}", err);
+                    self.notify_skip(&err);
+                }
+            }
+        }
+    }
+
+    /// Processes a chunk of read items using the processor.
+    fn process_chunk(&self, read_items: &[R]) -> Result<Vec<W>, BatchError> {
+        match self.processor.process_chunk(read_items) {
+            Some(Ok(processed_items)) => {
+                self.process_count
+                    .set(self.process_count.get() + processed_items.len());
+                return Ok(processed_items);
+            }
+            Some(Err(err)) => {
+                self.process_error_count
+                    .set(self.process_error_count.get() + read_items.len());
+                if self.is_skip_limit_reached(&err) {
+                    let error = BatchError::ItemProcessor(err.to_string());
+                    self.record_failure(err);
+                    return Err(error);
+                }
+                warn!("ItemProcessor error: {}", err);
+                self.notify_skip(&err);
+                return Ok(Vec::new());
+            }
+            None => {}
+        }
+
+        let mut processed_items = Vec::with_capacity(read_items.len());
+
+        debug!("Start processing chunk");
+        for item in read_items {
+            match self.processor.process(item) {
+                Ok(Some(item)) => {
+                    self.process_count.set(self.process_count.get() + 1);
+                    processed_items.push(item)
+                }
+                Ok(None) => {
+                    debug!("Item filtered");
+                    self.filter_count.set(self.filter_count.get() + 1);
+                }
+                Err(err) => {
+                    self.process_error_count
+                        .set(self.process_error_count.get() + 1);
+                    if self.is_skip_limit_reached(&err) {
+                        let error = BatchError::ItemProcessor(err.to_string());
+                        self.record_failure(err);
+                        return Err(error);
+                    }
+                    warn!("ItemProcessor error: {}", err);
+                    self.notify_skip(&err);
+                }
+            }
+        }
+        debug!("End processing chunk");
+
+        Ok(processed_items)
+    }
+
+    /// Writes and flushes a chunk of processed items using the writer.
+    async fn write_chunk(&self, processed_items: &[W]) -> Result<(), BatchError> {
+        debug!("Start writing chunk");
+
+        let result = match self.writer.write(processed_items).await {
+            Ok(()) => self.writer.flush().await,
+            Err(err) => Err(err),
+        };
+
+        match result {
+            Ok(()) => {
+                self.write_count
+                    .set(self.write_count.get() + processed_items.len());
+                debug!("End writing chunk");
+                Ok(())
+            }
+            Err(err) => {
+                let written = match &err {
+                    BatchError::PartialWrite { written, .. } => *written,
+                    _ => 0,
+                };
+                self.write_count.set(self.write_count.get() + written);
+                self.write_error_count
+                    .set(self.write_error_count.get() + processed_items.len() - written);
+                if self.is_skip_limit_reached(&err) {
+                    let error = BatchError::ItemWriter(err.to_string());
+                    self.record_failure(err);
+                    return Err(error);
+                }
+                warn!("Error occurred during write item: {}", err);
+                self.notify_skip(&err);
+                Ok(())
+            }
+        }
+    }
+
+    /// Records the error which makes the step fail, unless one has already been recorded.
+    fn record_failure(&self, error: BatchError) {
+        self.first_error.borrow_mut().get_or_insert(error);
+    }
+
+    /// Logs a non-fatal error and collects it in the warnings of the step execution.
+    fn manage_error(&self, result: Result<(), BatchError>) {
+        if let Err(error) = result {
+            warn!("Non-fatal error occurred: {}", error);
+            self.warnings.borrow_mut().push(error);
+        }
+    }
+}
+
+/// Builder for creating an `AsyncStepInstance`.
+#[derive(Default)]
+pub struct AsyncStepBuilder<'a, R, W> {
+    name: Option<String>,
+    reader: Option<&'a dyn AsyncItemReader<R>>,
+    processor: Option<&'a dyn ItemProcessor<R, W>>,
+    writer: Option<&'a dyn AsyncItemWriter<W>>,
+    chunk_size: usize,
+    skip_limit: usize,
+    skip_policy: Option<&'a dyn SkipPolicy>,
+    listeners: Vec<&'a dyn StepListener>,
+}
+
+impl<'a, R: 'static, W: 'static + Clone> AsyncStepBuilder<'a, R, W> {
+    /// Creates a new `AsyncStepBuilder` with a chunk size of 1 and no skip.
+    pub fn new() -> AsyncStepBuilder<'a, R, W> {
+        Self {
+            name: None,
+            reader: None,
+            processor: None,
+            writer: None,
+            chunk_size: 1,
+            skip_limit: 0,
+            skip_policy: None,
+            listeners: Vec::new(),
+        }
+    }
+
+    /// Sets the name of the step.
+    pub fn name(mut self, name: String) -> AsyncStepBuilder<'a, R, W> {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the asynchronous reader of the step.
+    pub fn reader(mut self, reader: &'a impl AsyncItemReader<R>) -> AsyncStepBuilder<'a, R, W> {
+        self.reader = Some(reader);
+        self
+    }
+
+    /// Sets the processor of the step. Items are passed through unchanged by default.
+    pub fn processor(
+        mut self,
+        processor: &'a impl ItemProcessor<R, W>,
+    ) -> AsyncStepBuilder<'a, R, W> {
+        self.processor = Some(processor);
+        self
+    }
+
+    /// Sets the asynchronous writer of the step.
+    pub fn writer(mut self, writer: &'a impl AsyncItemWriter<W>) -> AsyncStepBuilder<'a, R, W> {
+        self.writer = Some(writer);
+        self
+    }
+
+    /// Sets the number of items read before processing and writing them.
+    pub fn chunk(mut self, chunk_size: usize) -> AsyncStepBuilder<'a, R, W> {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Sets the number of read, process and write errors tolerated before the step fails.
+    pub fn skip_limit(mut self, skip_limit: usize) -> AsyncStepBuilder<'a, R, W> {
+        self.skip_limit = skip_limit;
+        self
+    }
+
+    /// Decides which errors are skipped with a custom policy, instead of skipping any
+    /// error up to the `skip_limit`.
+    pub fn skip_policy(mut self, skip_policy: &'a dyn SkipPolicy) -> AsyncStepBuilder<'a, R, W> {
+        self.skip_policy = Some(skip_policy);
+        self
+    }
+
+    /// Registers a listener of the lifecycle of the step and of its chunks.
+    ///
+    /// Several listeners can be registered; they are called in registration order.
+    pub fn listener(mut self, listener: &'a dyn StepListener) -> AsyncStepBuilder<'a, R, W> {
+        self.listeners.push(listener);
+        self
+    }
+
+    /// Builds the `AsyncStepInstance`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the configuration is invalid, as reported by `try_build`.
+    pub fn build(self) -> AsyncStepInstance<'a, R, W> {
+        self.try_build().unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Builds the `AsyncStepInstance`, checking its configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BatchError::Configuration` if the reader or the writer is not set,
+    /// or if the chunk size is 0.
+    pub fn try_build(self) -> Result<AsyncStepInstance<'a, R, W>, BatchError> {
+        let default_processor = &DefaultProcessor;
+
+        let reader = self
+            .reader
+            .ok_or_else(|| BatchError::Configuration("a step requires a reader".to_string()))?;
+        let writer = self
+            .writer
+            .ok_or_else(|| BatchError::Configuration("a step requires a writer".to_string()))?;
+        if self.chunk_size == 0 {
+            return Err(BatchError::Configuration(
+                "the chunk size of a step must be positive".to_string(),
+            ));
+        }
+
+        Ok(AsyncStepInstance {
+            id: Uuid::new_v4(),
+            name: self.name.map(OnceCell::from).unwrap_or_default(),
+            status: Cell::new(StepStatus::Starting),
+            reader,
+            processor: self.processor.unwrap_or(default_processor),
+            writer,
+            chunk_size: self.chunk_size,
+            skip_limit: self.skip_limit,
+            skip_policy: self.skip_policy,
+            listeners: self.listeners,
+            read_count: Cell::new(0),
+            process_count: Cell::new(0),
+            write_count: Cell::new(0),
+            read_error_count: Cell::new(0),
+            process_error_count: Cell::new(0),
+            write_error_count: Cell::new(0),
+            filter_count: Cell::new(0),
+            warnings: RefCell::new(Vec::new()),
+            first_error: RefCell::new(None),
+            chunk_statistics: RefCell::new(ChunkStatistics::default()),
+            start: Cell::new(Instant::now()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use async_trait::async_trait;
+
+    use crate::{
+        core::{
+            item::{
+                AsyncItemReader, AsyncItemWriter, ItemProcessor, ItemProcessorResult,
+                ItemReaderResult, ItemWriterResult,
+            },
+            step::{SkipPolicy, StepExecution, StepListener, StepStatus},
+        },
+        BatchError,
+    };
+
+    use super::AsyncStepBuilder;
+
+    struct VecReader {
+        items: RefCell<Vec<i32>>,
+    }
+
+    impl VecReader {
+        fn new(items: Vec<i32>) -> Self {
+            Self {
+                items: RefCell::new(items.into_iter().rev().collect()),
+            }
+        }
+    }
+
+    #[async_trait(?Send)]
+    impl AsyncItemReader<i32> for VecReader {
+        async fn read(&self) -> ItemReaderResult<i32> {
+            tokio::task::yield_now().await;
+            Ok(self.items.borrow_mut().pop())
+        }
+    }
+
+    #[derive(Default)]
+    struct VecWriter {
+        chunks: RefCell<Vec<Vec<i32>>>,
+    }
+
+    #[async_trait(?Send)]
+    impl AsyncItemWriter<i32> for VecWriter {
+        async fn write(&self, items: &[i32]) -> ItemWriterResult {
+            tokio::task::yield_now().await;
+            self.chunks.borrow_mut().push(items.to_vec());
+            Ok(())
+        }
+    }
+
+    struct RejectNegative;
+
+    impl ItemProcessor<i32, i32> for RejectNegative {
+        fn process(&self, item: &i32) -> ItemProcessorResult<i32> {
+            if *item < 0 {
+                Err(BatchError::ItemProcessor(format!("negative: {}", item)))
+            } else {
+                Ok(Some(item * 10))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn async_step_should_write_chunks() {
+        let reader = VecReader::new(vec![1, 2, 3, 4, 5]);
+        let writer = VecWriter::default();
+
+        let step = AsyncStepBuilder::<i32, i32>::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .build();
+
+        let execution = step.execute().await.unwrap();
+
+        assert_eq!(step.get_status(), StepStatus::Success);
+        assert_eq!(execution.read_count, 5);
+        assert_eq!(execution.write_count, 5);
+        assert_eq!(
+            *writer.chunks.borrow(),
+            vec![vec![1, 2], vec![3, 4], vec![5]]
+        );
+    }
+
+    #[tokio::test]
+    async fn async_step_should_apply_the_skip_limit() {
+        let reader = VecReader::new(vec![1, -2, 3, -4]);
+        let writer = VecWriter::default();
+        let processor = RejectNegative;
+
+        let tolerant = AsyncStepBuilder::new()
+            .reader(&reader)
+            .processor(&processor)
+            .writer(&writer)
+            .chunk(10)
+            .skip_limit(2)
+            .build();
+
+        let execution = tolerant.execute().await.unwrap();
+        assert_eq!(execution.process_error_count, 2);
+        assert_eq!(*writer.chunks.borrow(), vec![vec![10, 30]]);
+
+        let reader = VecReader::new(vec![1, -2, 3, -4]);
+        let strict = AsyncStepBuilder::new()
+            .reader(&reader)
+            .processor(&processor)
+            .writer(&writer)
+            .chunk(10)
+            .skip_limit(1)
+            .build();
+
+        assert!(strict.execute().await.is_err());
+        assert_eq!(strict.get_status(), StepStatus::ProcessorError);
+    }
+
+    struct FailingWriter {
+        calls: Cell<usize>,
+    }
+
+    #[async_trait(?Send)]
+    impl AsyncItemWriter<i32> for FailingWriter {
+        async fn write(&self, _items: &[i32]) -> ItemWriterResult {
+            self.calls.set(self.calls.get() + 1);
+            Err(BatchError::ItemWriter("connection reset".to_string()))
+        }
+    }
+
+    struct SkipProcessErrors;
+
+    impl SkipPolicy for SkipProcessErrors {
+        fn should_skip(&self, error: &BatchError, _error_count: usize) -> bool {
+            matches!(error, BatchError::ItemProcessor(_))
+        }
+    }
+
+    #[derive(Default)]
+    struct EventListener {
+        events: RefCell<Vec<String>>,
+    }
+
+    impl StepListener for EventListener {
+        fn before_step(&self, _execution: &StepExecution) {
+            self.events.borrow_mut().push("before_step".to_string());
+        }
+
+        fn after_step(&self, execution: &StepExecution) {
+            let event = format!("after_step: {}", execution.write_count);
+            self.events.borrow_mut().push(event);
+        }
+
+        fn after_chunk(&self, _execution: &StepExecution) {
+            self.events.borrow_mut().push("after_chunk".to_string());
+        }
+
+        fn on_skip(&self, error: &BatchError) {
+            self.events.borrow_mut().push(error.to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn async_step_should_apply_the_skip_policy_and_notify_listeners() {
+        let reader = VecReader::new(vec![1, -2, 3, -4]);
+        let writer = VecWriter::default();
+        let processor = RejectNegative;
+        let listener = EventListener::default();
+
+        let step = AsyncStepBuilder::new()
+            .reader(&reader)
+            .processor(&processor)
+            .writer(&writer)
+            .chunk(10)
+            .skip_policy(&SkipProcessErrors)
+            .listener(&listener)
+            .build();
+
+        let execution = step.execute().await.unwrap();
+
+        assert_eq!(execution.process_error_count, 2);
+        assert_eq!(
+            *listener.events.borrow(),
+            vec![
+                "before_step",
+                "Error occurred in the ItemProcessor: negative: -2",
+                "Error occurred in the ItemProcessor: negative: -4",
+                "after_chunk",
+                "after_step: 2",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn async_step_should_not_retry_failed_writes() {
+        let reader = VecReader::new(vec![1, 2, 3]);
+        let writer = FailingWriter {
+            calls: Cell::new(0),
+        };
+
+        let step = AsyncStepBuilder::<i32, i32>::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .skip_limit(10)
+            .build();
+
+        let execution = step.execute().await.unwrap();
+
+        assert_eq!(writer.calls.get(), 2);
+        assert_eq!(execution.write_error_count, 3);
+        assert_eq!(execution.retry_count, 0);
+    }
+
+    #[test]
+    fn zero_chunk_size_should_be_rejected() {
+        let reader = VecReader::new(vec![]);
+        let writer = VecWriter::default();
+
+        let result = AsyncStepBuilder::<i32, i32>::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(0)
+            .try_build();
+
+        assert_eq!(
+            result.err(),
+            Some(BatchError::Configuration(
+                "the chunk size of a step must be positive".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the chunk size of a step must be positive")]
+    fn zero_chunk_size_should_panic_on_build() {
+        let reader = VecReader::new(vec![]);
+        let writer = VecWriter::default();
+
+        AsyncStepBuilder::<i32, i32>::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(0)
+            .build();
+    }
+
+    #[tokio::test]
+    async fn async_step_should_reset_its_counters_on_each_execution() {
+        let reader = VecReader::new(vec![1, -2, 3]);
+        let writer = VecWriter::default();
+        let processor = RejectNegative;
+
+        let step = AsyncStepBuilder::new()
+            .reader(&reader)
+            .processor(&processor)
+            .writer(&writer)
+            .chunk(2)
+            .skip_limit(1)
+            .build();
+
+        let first = step.execute().await.unwrap();
+        *reader.items.borrow_mut() = vec![3, -2, 1];
+        let second = step.execute().await.unwrap();
+
+        for execution in [first, second] {
+            assert_eq!(execution.read_count, 3);
+            assert_eq!(execution.write_count, 2);
+            assert_eq!(execution.process_error_count, 1);
+        }
+        assert_eq!(step.get_status(), StepStatus::Success);
+    }
+}
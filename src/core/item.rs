@@ -1,4 +1,16 @@
-use std::any::Any;
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    hash::Hash,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, PoisonError},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
 
 use crate::error::BatchError;
 
@@ -6,7 +18,9 @@ use crate::error::BatchError;
 pub type ItemReaderResult<R> = Result<Option<R>, BatchError>;
 
 /// Represents the result of processing an item by the processor.
-pub type ItemProcessorResult<W> = Result<W, BatchError>;
+///
+/// `Ok(None)` means that the item has been filtered and must not be written.
+pub type ItemProcessorResult<W> = Result<Option<W>, BatchError>;
 
 /// Represents the result of writing items by the writer.
 pub type ItemWriterResult = Result<(), BatchError>;
@@ -14,13 +28,51 @@ pub type ItemWriterResult = Result<(), BatchError>;
 /// A trait for reading items.
 pub trait ItemReader<R> {
     /// Reads an item from the reader.
+    ///
+    /// A record which has been consumed but cannot be turned into an item must be reported
+    /// as a `BatchError::Deserialization`, which the step skips without retrying the read.
     fn read(&self) -> ItemReaderResult<R>;
+
+    /// Opens the reader, acquiring its resources such as a file or a database cursor.
+    ///
+    /// Steps call it before the first read: a reader failing to open fails the step.
+    fn open(&self) -> Result<(), BatchError> {
+        Ok(())
+    }
+
+    /// Closes the reader, releasing its resources.
+    ///
+    /// Steps call it after the last read, when the reader has been opened. A reader
+    /// failing to close does not fail the step: the error is reported as a warning.
+    fn close(&self) -> Result<(), BatchError> {
+        Ok(())
+    }
+
+    /// Returns the total number of items the reader is expected to return, if known
+    /// upfront, such as the row count of a file or a `COUNT(*)` of a query.
+    ///
+    /// Steps pass it to their progress callback. Defaults to `None`.
+    fn total_hint(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// A trait for processing items.
 pub trait ItemProcessor<R, W> {
     /// Processes an item and returns the processed result.
+    ///
+    /// Returns `Ok(None)` to filter the item out of the chunk.
     fn process(&self, item: &R) -> ItemProcessorResult<W>;
+
+    /// Processes a whole chunk at once, for processors combining several items into one,
+    /// such as `GroupingProcessor`.
+    ///
+    /// Steps call it with each chunk read: when it returns `Some`, the returned items are
+    /// written and `process` is not called. A failed chunk counts as a process error for each
+    /// of its items, and is not retried. Returns `None` by default, to process items one by one.
+    fn process_chunk(&self, _items: &[R]) -> Option<Result<Vec<W>, BatchError>> {
+        None
+    }
 }
 
 /// A trait for writing items.
@@ -42,6 +94,47 @@ pub trait ItemWriter<W> {
     fn close(&self) -> ItemWriterResult {
         Ok(())
     }
+
+    /// Returns `true` once the destination no longer accepts items, for example when
+    /// the consumer of a pipe went away.
+    ///
+    /// The step stops reading and completes successfully when its writer is closed.
+    fn is_closed(&self) -> bool {
+        false
+    }
+}
+
+/// A trait for reading items from an asynchronous source.
+///
+/// The futures are not required to be `Send`: an `AsyncStepInstance` runs on the task awaiting it.
+#[async_trait(?Send)]
+pub trait AsyncItemReader<R> {
+    /// Reads an item from the reader.
+    async fn read(&self) -> ItemReaderResult<R>;
+}
+
+/// A trait for writing items to an asynchronous destination.
+///
+/// The futures are not required to be `Send`: an `AsyncStepInstance` runs on the task awaiting it.
+#[async_trait(?Send)]
+pub trait AsyncItemWriter<W> {
+    /// Writes the given items.
+    async fn write(&self, items: &[W]) -> ItemWriterResult;
+
+    /// Flushes any buffered data.
+    async fn flush(&self) -> ItemWriterResult {
+        Ok(())
+    }
+
+    /// Opens the writer.
+    async fn open(&self) -> ItemWriterResult {
+        Ok(())
+    }
+
+    /// Closes the writer.
+    async fn close(&self) -> ItemWriterResult {
+        Ok(())
+    }
 }
 
 /// A default implementation of the `ItemProcessor` trait.
@@ -53,8 +146,2094 @@ impl<R: Any, W: Clone + Any> ItemProcessor<R, W> for DefaultProcessor {
         let value_any = item as &dyn Any;
 
         match value_any.downcast_ref::<W>() {
-            Some(as_w) => Ok(as_w.clone()),
+            Some(as_w) => Ok(Some(as_w.clone())),
             None => Err(BatchError::ItemProcessor("Cannot downcast".to_string())),
         }
     }
 }
+
+/// A reader returning the results of a closure, for one-off sources, tests and prototypes.
+///
+/// The closure returns `Ok(None)` once the source is exhausted.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{ClosureItemReader, ItemReader};
+///
+/// let mut remaining = 2;
+/// let reader = ClosureItemReader::new(move || {
+///     remaining -= 1;
+///     Ok((remaining >= 0).then_some(remaining))
+/// });
+///
+/// assert_eq!(reader.read(), Ok(Some(1)));
+/// assert_eq!(reader.read(), Ok(Some(0)));
+/// assert_eq!(reader.read(), Ok(None));
+/// ```
+pub struct ClosureItemReader<T, F: FnMut() -> Result<Option<T>, BatchError>> {
+    read: RefCell<F>,
+}
+
+impl<T, F: FnMut() -> Result<Option<T>, BatchError>> ClosureItemReader<T, F> {
+    /// Creates a `ClosureItemReader` calling `read` for each item.
+    pub fn new(read: F) -> Self {
+        Self {
+            read: RefCell::new(read),
+        }
+    }
+}
+
+impl<T, F: FnMut() -> Result<Option<T>, BatchError>> ItemReader<T> for ClosureItemReader<T, F> {
+    fn read(&self) -> ItemReaderResult<T> {
+        (self.read.borrow_mut())()
+    }
+}
+
+/// A reader returning the items of a collection or an iterator, such as a `Vec`, for tests
+/// and glue code feeding pre-built items into a step.
+///
+/// The reader returns `Ok(None)` once the iterator is exhausted. Its total hint is the
+/// length of the iterator, when known exactly upfront.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{ItemReader, IterItemReader};
+///
+/// let reader = IterItemReader::new(vec!["a", "b"]);
+///
+/// assert_eq!(reader.total_hint(), Some(2));
+/// assert_eq!(reader.read(), Ok(Some("a")));
+/// assert_eq!(reader.read(), Ok(Some("b")));
+/// assert_eq!(reader.read(), Ok(None));
+/// ```
+pub struct IterItemReader<T> {
+    items: RefCell<Box<dyn Iterator<Item = T>>>,
+    total: Option<usize>,
+}
+
+impl<T> IterItemReader<T> {
+    /// Creates an `IterItemReader` returning the items of `items`.
+    pub fn new<I>(items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: 'static,
+    {
+        let items = items.into_iter();
+        let total = match items.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(lower),
+            _ => None,
+        };
+
+        Self {
+            items: RefCell::new(Box::new(items)),
+            total,
+        }
+    }
+}
+
+impl<T: 'static> From<Vec<T>> for IterItemReader<T> {
+    fn from(items: Vec<T>) -> Self {
+        Self::new(items)
+    }
+}
+
+impl<T> ItemReader<T> for IterItemReader<T> {
+    fn read(&self) -> ItemReaderResult<T> {
+        Ok(self.items.borrow_mut().next())
+    }
+
+    fn total_hint(&self) -> Option<usize> {
+        self.total
+    }
+}
+
+/// A writer passing each chunk to a closure, for one-off destinations, tests and prototypes.
+///
+/// Opening, flushing and closing the writer do nothing.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{ClosureItemWriter, ItemWriter};
+/// use std::cell::Cell;
+///
+/// let total = Cell::new(0);
+/// let writer = ClosureItemWriter::new(|items: &[u32]| {
+///     total.set(total.get() + items.iter().sum::<u32>());
+///     Ok(())
+/// });
+///
+/// writer.write(&[1, 2, 3]).unwrap();
+/// assert_eq!(total.get(), 6);
+/// ```
+pub struct ClosureItemWriter<T, F: Fn(&[T]) -> Result<(), BatchError>> {
+    write: F,
+    _pd: PhantomData<fn(&[T])>,
+}
+
+impl<T, F: Fn(&[T]) -> Result<(), BatchError>> ClosureItemWriter<T, F> {
+    /// Creates a `ClosureItemWriter` calling `write` with each chunk.
+    pub fn new(write: F) -> Self {
+        Self {
+            write,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<T, F: Fn(&[T]) -> Result<(), BatchError>> ItemWriter<T> for ClosureItemWriter<T, F> {
+    fn write(&self, items: &[T]) -> ItemWriterResult {
+        (self.write)(items)
+    }
+}
+
+/// A processor transforming each item with a closure, for the transforms which do not
+/// deserve a dedicated type.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{ClosureProcessor, ItemProcessor};
+/// use spring_batch_rs::BatchError;
+///
+/// let parse = ClosureProcessor::new(|item: &String| {
+///     item.parse::<u32>()
+///         .map_err(|error| BatchError::ItemProcessor(error.to_string()))
+/// });
+/// let length = ClosureProcessor::map(|item: &String| item.len());
+///
+/// assert_eq!(parse.process(&"42".to_string()).unwrap(), Some(42));
+/// assert!(parse.process(&"forty-two".to_string()).is_err());
+/// assert_eq!(length.process(&"42".to_string()).unwrap(), Some(2));
+/// ```
+pub struct ClosureProcessor<I, O, F: Fn(&I) -> Result<O, BatchError>> {
+    transform: F,
+    _pd: PhantomData<fn(&I) -> O>,
+}
+
+impl<I, O, F: Fn(&I) -> Result<O, BatchError>> ClosureProcessor<I, O, F> {
+    /// Creates a `ClosureProcessor` replacing each item by the result of `transform`.
+    ///
+    /// Errors returned by `transform` are processor errors, to which the skip limit applies.
+    pub fn new(transform: F) -> Self {
+        Self {
+            transform,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<I, O> ClosureProcessor<I, O, fn(&I) -> Result<O, BatchError>> {
+    /// Creates a `ClosureProcessor` from an infallible transform.
+    pub fn map(
+        transform: impl Fn(&I) -> O,
+    ) -> ClosureProcessor<I, O, impl Fn(&I) -> Result<O, BatchError>> {
+        ClosureProcessor::new(move |item: &I| Ok(transform(item)))
+    }
+}
+
+impl<I, O, F: Fn(&I) -> Result<O, BatchError>> ItemProcessor<I, O> for ClosureProcessor<I, O, F> {
+    fn process(&self, item: &I) -> ItemProcessorResult<O> {
+        (self.transform)(item).map(Some)
+    }
+}
+
+/// A processor grouping the items of each chunk by key, and reducing each group to a single
+/// output item, for writers more efficient with aggregated payloads, such as order lines
+/// grouped by order.
+///
+/// Groups are formed within a chunk only: items of the same key read in two chunks
+/// produce two output items, one per chunk. Sort the input by key, and pick a chunk size
+/// large enough for the expected groups, to limit the split groups. Outputs are written
+/// in the order in which their key first appears in the chunk. When run outside of a step,
+/// by `process`, each item forms its own group.
+///
+/// The `process_count` of the step counts the output items, not the items read.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{GroupingProcessor, ItemProcessor};
+///
+/// struct Line {
+///     order: u32,
+///     amount: u32,
+/// }
+///
+/// let totals = GroupingProcessor::new(
+///     |line: &Line| line.order,
+///     |order, lines| (*order, lines.iter().map(|line| line.amount).sum::<u32>()),
+/// );
+///
+/// let lines = [
+///     Line { order: 1, amount: 10 },
+///     Line { order: 2, amount: 5 },
+///     Line { order: 1, amount: 20 },
+/// ];
+///
+/// let totals = totals.process_chunk(&lines).unwrap().unwrap();
+/// assert_eq!(totals, vec![(1, 30), (2, 5)]);
+/// ```
+pub struct GroupingProcessor<I, K, O, KF, RF>
+where
+    KF: Fn(&I) -> K,
+    RF: Fn(&K, &[&I]) -> O,
+{
+    key: KF,
+    reduce: RF,
+    _pd: PhantomData<fn(I, K) -> O>,
+}
+
+impl<I, K, O, KF, RF> GroupingProcessor<I, K, O, KF, RF>
+where
+    K: Eq + Hash + Clone,
+    KF: Fn(&I) -> K,
+    RF: Fn(&K, &[&I]) -> O,
+{
+    /// Creates a `GroupingProcessor` grouping items by `key`, and calling `reduce` with
+    /// the key and the items of each group.
+    pub fn new(key: KF, reduce: RF) -> Self {
+        Self {
+            key,
+            reduce,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Groups the items by key, keeping the order in which keys first appear.
+    fn group<'i>(&self, items: &'i [I]) -> Vec<(K, Vec<&'i I>)> {
+        let mut groups: Vec<(K, Vec<&I>)> = Vec::new();
+        let mut positions: HashMap<K, usize> = HashMap::new();
+
+        for item in items {
+            let key = (self.key)(item);
+            match positions.get(&key) {
+                Some(&position) => groups[position].1.push(item),
+                None => {
+                    positions.insert(key.clone(), groups.len());
+                    groups.push((key, vec![item]));
+                }
+            }
+        }
+
+        groups
+    }
+}
+
+impl<I, K, O, KF, RF> ItemProcessor<I, O> for GroupingProcessor<I, K, O, KF, RF>
+where
+    K: Eq + Hash + Clone,
+    KF: Fn(&I) -> K,
+    RF: Fn(&K, &[&I]) -> O,
+{
+    fn process(&self, item: &I) -> ItemProcessorResult<O> {
+        Ok(Some((self.reduce)(&(self.key)(item), &[item])))
+    }
+
+    fn process_chunk(&self, items: &[I]) -> Option<Result<Vec<O>, BatchError>> {
+        let outputs = self
+            .group(items)
+            .into_iter()
+            .map(|(key, group)| (self.reduce)(&key, &group))
+            .collect();
+
+        Some(Ok(outputs))
+    }
+}
+
+/// A processor keeping the items matching a predicate.
+///
+/// Items for which the predicate returns `false` are filtered: they are not written and
+/// are counted in the `filter_count` of the step, not as errors.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{FilterProcessor, ItemProcessor};
+///
+/// let even = FilterProcessor::new(|item: &i32| item % 2 == 0);
+///
+/// assert_eq!(even.process(&2).unwrap(), Some(2));
+/// assert_eq!(even.process(&3).unwrap(), None);
+/// ```
+pub struct FilterProcessor<T, F: Fn(&T) -> bool> {
+    predicate: F,
+    _pd: PhantomData<T>,
+}
+
+impl<T, F: Fn(&T) -> bool> FilterProcessor<T, F> {
+    /// Creates a `FilterProcessor` keeping the items for which `predicate` returns `true`.
+    pub fn new(predicate: F) -> Self {
+        Self {
+            predicate,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone, F: Fn(&T) -> bool> ItemProcessor<T, T> for FilterProcessor<T, F> {
+    fn process(&self, item: &T) -> ItemProcessorResult<T> {
+        if (self.predicate)(item) {
+            Ok(Some(item.clone()))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// A processor running a closure on each item for its side effect, such as counting,
+/// sampling or logging, and passing the item through unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{ItemProcessor, PeekProcessor};
+/// use std::cell::Cell;
+///
+/// let seen = Cell::new(0);
+/// let counter = PeekProcessor::new(|_item: &i32| seen.set(seen.get() + 1));
+///
+/// assert_eq!(counter.process(&7).unwrap(), Some(7));
+/// assert_eq!(seen.get(), 1);
+/// ```
+pub struct PeekProcessor<T, F: Fn(&T)> {
+    peek: F,
+    _pd: PhantomData<T>,
+}
+
+impl<T, F: Fn(&T)> PeekProcessor<T, F> {
+    /// Creates a `PeekProcessor` calling `peek` with each item.
+    pub fn new(peek: F) -> Self {
+        Self {
+            peek,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone, F: Fn(&T)> ItemProcessor<T, T> for PeekProcessor<T, F> {
+    fn process(&self, item: &T) -> ItemProcessorResult<T> {
+        (self.peek)(item);
+        Ok(Some(item.clone()))
+    }
+}
+
+/// A processor running a sequence of processors, each one receiving the output of the previous one.
+///
+/// The chain stops as soon as a stage filters the item (returns `Ok(None)`): later stages are not
+/// run and the item is counted as filtered by the step. Cheap filters should therefore be added
+/// before expensive stages.
+///
+/// Errors are not caught by the chain: they propagate to the step, where the skip limit applies.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{ItemProcessor, ItemProcessorResult, ProcessorChainBuilder};
+///
+/// struct Positive;
+/// impl ItemProcessor<i32, i32> for Positive {
+///     fn process(&self, item: &i32) -> ItemProcessorResult<i32> {
+///         Ok(if *item > 0 { Some(*item) } else { None })
+///     }
+/// }
+///
+/// struct Double;
+/// impl ItemProcessor<i32, i32> for Double {
+///     fn process(&self, item: &i32) -> ItemProcessorResult<i32> {
+///         Ok(Some(item * 2))
+///     }
+/// }
+///
+/// let chain = ProcessorChainBuilder::new().add(&Positive).add(&Double).build();
+///
+/// assert_eq!(chain.process(&2).unwrap(), Some(4));
+/// assert_eq!(chain.process(&-2).unwrap(), None);
+/// ```
+pub struct ProcessorChain<'a, T> {
+    processors: Vec<&'a dyn ItemProcessor<T, T>>,
+}
+
+impl<'a, T: Clone> ItemProcessor<T, T> for ProcessorChain<'a, T> {
+    fn process(&self, item: &T) -> ItemProcessorResult<T> {
+        let mut current = item.clone();
+
+        for processor in &self.processors {
+            match processor.process(&current)? {
+                Some(item) => current = item,
+                None => return Ok(None),
+            }
+        }
+
+        Ok(Some(current))
+    }
+}
+
+/// Builder for creating a `ProcessorChain`.
+#[derive(Default)]
+pub struct ProcessorChainBuilder<'a, T> {
+    processors: Vec<&'a dyn ItemProcessor<T, T>>,
+}
+
+impl<'a, T> ProcessorChainBuilder<'a, T> {
+    /// Creates a new `ProcessorChainBuilder` instance.
+    pub fn new() -> Self {
+        Self {
+            processors: Vec::new(),
+        }
+    }
+
+    /// Adds a stage at the end of the chain.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(mut self, processor: &'a impl ItemProcessor<T, T>) -> Self {
+        self.processors.push(processor);
+        self
+    }
+
+    /// Builds a `ProcessorChain` instance.
+    pub fn build(self) -> ProcessorChain<'a, T> {
+        ProcessorChain {
+            processors: self.processors,
+        }
+    }
+}
+
+/// Function applying the stages of a `CompositeItemProcessor`.
+type Stages<'a, I, O> = dyn Fn(&I) -> ItemProcessorResult<O> + 'a;
+
+/// A processor applying stages in sequence, where the output type of each stage is the
+/// input type of the next one.
+///
+/// Unlike `ProcessorChain`, whose stages all process the same type, the type of the items
+/// can change at each stage: the final output type is the one consumed by the writer.
+/// Errors of any stage are propagated as-is, and an item filtered by a stage is not passed
+/// to the next stages.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::{
+///     core::item::{CompositeItemProcessor, ItemProcessor, ItemProcessorResult},
+///     BatchError,
+/// };
+///
+/// struct Parse;
+/// impl ItemProcessor<String, i32> for Parse {
+///     fn process(&self, item: &String) -> ItemProcessorResult<i32> {
+///         item.parse()
+///             .map(Some)
+///             .map_err(|_| BatchError::ItemProcessor(format!("not a number: {}", item)))
+///     }
+/// }
+///
+/// struct Format;
+/// impl ItemProcessor<i32, String> for Format {
+///     fn process(&self, item: &i32) -> ItemProcessorResult<String> {
+///         Ok(Some(format!("#{}", item)))
+///     }
+/// }
+///
+/// let processor = CompositeItemProcessor::new(&Parse).then(&Format);
+/// assert_eq!(processor.process(&"42".to_string()).unwrap(), Some("#42".to_string()));
+/// ```
+pub struct CompositeItemProcessor<'a, I, O> {
+    stages: Box<Stages<'a, I, O>>,
+}
+
+impl<'a, I: 'a, O: 'a> CompositeItemProcessor<'a, I, O> {
+    /// Creates a `CompositeItemProcessor` with its first stage.
+    pub fn new(processor: &'a impl ItemProcessor<I, O>) -> Self {
+        Self {
+            stages: Box::new(move |item| processor.process(item)),
+        }
+    }
+
+    /// Adds a stage processing the output of the previous stages.
+    pub fn then<P: 'a>(
+        self,
+        processor: &'a impl ItemProcessor<O, P>,
+    ) -> CompositeItemProcessor<'a, I, P> {
+        let stages = self.stages;
+
+        CompositeItemProcessor {
+            stages: Box::new(move |item| match stages(item)? {
+                Some(output) => processor.process(&output),
+                None => Ok(None),
+            }),
+        }
+    }
+}
+
+impl<'a, I, O> ItemProcessor<I, O> for CompositeItemProcessor<'a, I, O> {
+    fn process(&self, item: &I) -> ItemProcessorResult<O> {
+        (self.stages)(item)
+    }
+}
+
+/// A writer routing each item to a named output.
+///
+/// The processor classifies items by returning `(output, item)` pairs, for example
+/// `("main", item)` for valid records and `("reject", item)` for rejected ones.
+/// Each output has its own writer, and the number of items written per output is tracked.
+///
+/// Writing an item to an unknown output fails the whole chunk. Otherwise every output is
+/// written, and the failures are combined in a single error prefixed with the name of the
+/// output. A write which succeeded on some of the outputs fails with a
+/// `BatchError::PartialWrite`, so that the step does not retry it and write the items of
+/// those outputs twice. Flushing, opening and closing also go through every output.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{ItemWriter, RoutingItemWriterBuilder};
+/// use spring_batch_rs::item::logger::LoggerWriter;
+///
+/// let main = LoggerWriter;
+/// let reject = LoggerWriter;
+///
+/// let writer = RoutingItemWriterBuilder::new()
+///     .writer_for("main", &main)
+///     .writer_for("reject", &reject)
+///     .build();
+///
+/// writer.write(&[("main", 1), ("reject", 2), ("main", 3)]).unwrap();
+///
+/// assert_eq!(writer.get_write_count("main"), 2);
+/// assert_eq!(writer.get_write_count("reject"), 1);
+/// ```
+pub struct RoutingItemWriter<'a, O> {
+    outputs: Vec<(String, &'a dyn ItemWriter<O>, Cell<usize>)>,
+}
+
+impl<'a, O> RoutingItemWriter<'a, O> {
+    /// Gets the number of items written to an output.
+    pub fn get_write_count(&self, output: &str) -> usize {
+        self.outputs
+            .iter()
+            .find(|(name, _, _)| name == output)
+            .map_or(0, |(_, _, count)| count.get())
+    }
+
+    /// Applies an operation to every output writer, combining their errors.
+    fn for_each_writer(
+        &self,
+        operation: impl Fn(&dyn ItemWriter<O>) -> ItemWriterResult,
+    ) -> ItemWriterResult {
+        let errors: Vec<String> = self
+            .outputs
+            .iter()
+            .filter_map(|(name, writer, _)| {
+                operation(*writer)
+                    .err()
+                    .map(|error| format!("output {}: {}", name, error))
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(BatchError::ItemWriter(errors.join("; ")))
+        }
+    }
+}
+
+impl<'a, K: AsRef<str>, O: Clone> ItemWriter<(K, O)> for RoutingItemWriter<'a, O> {
+    fn write(&self, items: &[(K, O)]) -> ItemWriterResult {
+        let mut routed: Vec<Vec<O>> = vec![Vec::new(); self.outputs.len()];
+
+        for (output, item) in items {
+            let index = self
+                .outputs
+                .iter()
+                .position(|(name, _, _)| name == output.as_ref())
+                .ok_or_else(|| {
+                    BatchError::ItemWriter(format!("unknown output: {}", output.as_ref()))
+                })?;
+            routed[index].push(item.clone());
+        }
+
+        let mut written = 0;
+        let mut errors = Vec::new();
+        for ((name, writer, count), items) in self.outputs.iter().zip(routed) {
+            if items.is_empty() {
+                continue;
+            }
+            match writer.write(&items) {
+                Ok(()) => {
+                    count.set(count.get() + items.len());
+                    written += items.len();
+                }
+                Err(error) => errors.push(format!("output {}: {}", name, error)),
+            }
+        }
+
+        match (errors.is_empty(), written) {
+            (true, _) => Ok(()),
+            (false, 0) => Err(BatchError::ItemWriter(errors.join("; "))),
+            (false, written) => Err(BatchError::PartialWrite {
+                written,
+                message: errors.join("; "),
+            }),
+        }
+    }
+
+    fn flush(&self) -> ItemWriterResult {
+        self.for_each_writer(|writer| writer.flush())
+    }
+
+    fn open(&self) -> ItemWriterResult {
+        self.for_each_writer(|writer| writer.open())
+    }
+
+    fn close(&self) -> ItemWriterResult {
+        self.for_each_writer(|writer| writer.close())
+    }
+}
+
+/// Builder for creating a `RoutingItemWriter`.
+#[derive(Default)]
+pub struct RoutingItemWriterBuilder<'a, O> {
+    outputs: Vec<(String, &'a dyn ItemWriter<O>)>,
+}
+
+impl<'a, O> RoutingItemWriterBuilder<'a, O> {
+    /// Creates a new `RoutingItemWriterBuilder` instance.
+    pub fn new() -> Self {
+        Self {
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Sets the writer of an output.
+    pub fn writer_for(mut self, output: &str, writer: &'a impl ItemWriter<O>) -> Self {
+        self.outputs.push((output.to_string(), writer));
+        self
+    }
+
+    /// Builds a `RoutingItemWriter` instance.
+    pub fn build(self) -> RoutingItemWriter<'a, O> {
+        RoutingItemWriter {
+            outputs: self
+                .outputs
+                .into_iter()
+                .map(|(name, writer)| (name, writer, Cell::new(0)))
+                .collect(),
+        }
+    }
+}
+
+/// A writer forwarding each chunk to several writers, such as a database and an audit file.
+///
+/// Every operation is forwarded to all the writers in order. When some of them fail,
+/// the others are still called and a single error listing each failure, prefixed
+/// with the index of the writer, is returned. A write which succeeded on some of the
+/// writers fails with a `BatchError::PartialWrite`, so that the step does not retry it
+/// and write the chunk twice to those writers.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{CompositeItemWriter, ItemWriter, VecItemWriter};
+///
+/// let database = VecItemWriter::new();
+/// let audit = VecItemWriter::new();
+/// let writer = CompositeItemWriter::new(vec![&database, &audit]);
+///
+/// writer.write(&[1, 2]).unwrap();
+///
+/// assert_eq!(database.items(), vec![1, 2]);
+/// assert_eq!(audit.items(), vec![1, 2]);
+/// ```
+pub struct CompositeItemWriter<'a, T> {
+    writers: Vec<&'a dyn ItemWriter<T>>,
+}
+
+impl<'a, T> CompositeItemWriter<'a, T> {
+    /// Creates a `CompositeItemWriter` forwarding to the given writers in order.
+    pub fn new(writers: Vec<&'a dyn ItemWriter<T>>) -> Self {
+        Self { writers }
+    }
+
+    /// Runs an operation on every writer, combining their errors.
+    fn forward(
+        &self,
+        mut operation: impl FnMut(&dyn ItemWriter<T>) -> ItemWriterResult,
+    ) -> ItemWriterResult {
+        let errors: Vec<String> = self
+            .writers
+            .iter()
+            .enumerate()
+            .filter_map(|(index, writer)| {
+                operation(*writer)
+                    .err()
+                    .map(|error| format!("writer {}: {}", index, error))
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(BatchError::ItemWriter(errors.join("; ")))
+        }
+    }
+}
+
+impl<'a, T> ItemWriter<T> for CompositeItemWriter<'a, T> {
+    fn write(&self, items: &[T]) -> ItemWriterResult {
+        let mut succeeded = false;
+        let result = self.forward(|writer| {
+            let result = writer.write(items);
+            succeeded |= result.is_ok();
+            result
+        });
+
+        match result {
+            Err(BatchError::ItemWriter(message)) if succeeded => Err(BatchError::PartialWrite {
+                written: 0,
+                message,
+            }),
+            result => result,
+        }
+    }
+
+    fn flush(&self) -> ItemWriterResult {
+        self.forward(|writer| writer.flush())
+    }
+
+    fn open(&self) -> ItemWriterResult {
+        self.forward(|writer| writer.open())
+    }
+
+    fn close(&self) -> ItemWriterResult {
+        self.forward(|writer| writer.close())
+    }
+
+    /// Returns `true` once every writer is closed.
+    fn is_closed(&self) -> bool {
+        !self.writers.is_empty() && self.writers.iter().all(|writer| writer.is_closed())
+    }
+}
+
+/// A writer accumulating items across chunks and forwarding them to another writer
+/// by batches of `flush_threshold` items, plus the remainder on `close`.
+///
+/// It decouples the chunk size of the step from the size of the physical writes,
+/// which matters for databases where larger batches are faster. As the step considers
+/// items written as soon as they are buffered, an error of the inner writer is reported
+/// on the chunk reaching the threshold, and the whole failed batch is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{BufferingItemWriter, ItemWriter, VecItemWriter};
+///
+/// let database = VecItemWriter::new();
+/// let writer = BufferingItemWriter::new(&database, 3);
+///
+/// writer.write(&[1, 2]).unwrap();
+/// assert!(database.items().is_empty());
+///
+/// writer.write(&[3, 4]).unwrap();
+/// assert_eq!(database.items(), vec![1, 2, 3, 4]);
+///
+/// writer.write(&[5]).unwrap();
+/// writer.close().unwrap();
+/// assert_eq!(database.items(), vec![1, 2, 3, 4, 5]);
+/// ```
+pub struct BufferingItemWriter<'a, T> {
+    writer: &'a dyn ItemWriter<T>,
+    flush_threshold: usize,
+    buffer: RefCell<Vec<T>>,
+}
+
+impl<'a, T> BufferingItemWriter<'a, T> {
+    /// Creates a `BufferingItemWriter` forwarding items to `writer` once `flush_threshold`
+    /// items are buffered.
+    pub fn new(writer: &'a impl ItemWriter<T>, flush_threshold: usize) -> Self {
+        Self {
+            writer,
+            flush_threshold,
+            buffer: RefCell::new(Vec::with_capacity(flush_threshold)),
+        }
+    }
+
+    /// Writes and flushes the buffered items with the inner writer, emptying the buffer.
+    fn write_buffer(&self) -> ItemWriterResult {
+        let items = std::mem::take(&mut *self.buffer.borrow_mut());
+
+        if items.is_empty() {
+            return Ok(());
+        }
+
+        self.writer.write(&items)?;
+        self.writer.flush()
+    }
+}
+
+impl<'a, T: Clone> ItemWriter<T> for BufferingItemWriter<'a, T> {
+    fn write(&self, items: &[T]) -> ItemWriterResult {
+        self.buffer.borrow_mut().extend_from_slice(items);
+
+        if self.buffer.borrow().len() >= self.flush_threshold {
+            self.write_buffer()?;
+        }
+
+        Ok(())
+    }
+
+    /// Does nothing: buffered items are kept until the threshold is reached or the writer is closed.
+    fn flush(&self) -> ItemWriterResult {
+        Ok(())
+    }
+
+    fn open(&self) -> ItemWriterResult {
+        self.writer.open()
+    }
+
+    /// Writes the remaining buffered items, then closes the inner writer.
+    fn close(&self) -> ItemWriterResult {
+        let result = self.write_buffer();
+        let close_result = self.writer.close();
+
+        result.and(close_result)
+    }
+
+    fn is_closed(&self) -> bool {
+        self.writer.is_closed()
+    }
+}
+
+/// Creates the writer of a file of a `RollingFileItemWriter` from its path.
+type WriterFactory<'a, T> = Box<dyn Fn(&Path) -> Box<dyn ItemWriter<T> + 'a> + 'a>;
+
+/// A writer splitting its output into several files, rolling to a new file when the
+/// current one holds `max_items` items or when a time window ends.
+///
+/// Files are named from a pattern where `{index}` is replaced by the index of the file,
+/// starting at 0, and their writers are created by a factory from their path. A file is
+/// only created when the first item is written to it, and is closed when the writer
+/// rolls to the next file or is closed.
+///
+/// Time windows are aligned on the Unix epoch, so that a window of one day rolls at
+/// midnight UTC and a window of one hour at the start of each hour. The window is
+/// checked when items are written, so a window with no item creates no file.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{ItemWriter, RollingFileItemWriterBuilder, VecItemWriter};
+///
+/// let writer = RollingFileItemWriterBuilder::new("out-{index}.json")
+///     .max_items(2)
+///     .build(|_path| VecItemWriter::new());
+///
+/// writer.write(&[1, 2, 3]).unwrap();
+/// ItemWriter::<i32>::close(&writer).unwrap();
+///
+/// assert_eq!(
+///     writer.files(),
+///     vec![
+///         std::path::PathBuf::from("out-0.json"),
+///         std::path::PathBuf::from("out-1.json"),
+///     ]
+/// );
+/// ```
+pub struct RollingFileItemWriter<'a, T> {
+    pattern: String,
+    factory: WriterFactory<'a, T>,
+    max_items: Option<usize>,
+    window: Option<Duration>,
+    current: RefCell<Option<Box<dyn ItemWriter<T> + 'a>>>,
+    files: RefCell<Vec<PathBuf>>,
+    items_in_file: Cell<usize>,
+    window_index: Cell<Option<u128>>,
+}
+
+impl<'a, T> RollingFileItemWriter<'a, T> {
+    /// Returns the paths of the files created so far, in order.
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.files.borrow().clone()
+    }
+
+    /// Returns the index of the current time window, if the writer rolls over time.
+    fn current_window(&self) -> Option<u128> {
+        self.window.map(|window| {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default();
+            now.as_millis() / window.as_millis().max(1)
+        })
+    }
+
+    /// Closes the current file, so that the next items are written to a new one.
+    fn roll(&self) -> ItemWriterResult {
+        self.items_in_file.set(0);
+
+        match self.current.borrow_mut().take() {
+            Some(writer) => writer.close(),
+            None => Ok(()),
+        }
+    }
+
+    /// Creates and opens the next file if there is no current one.
+    fn ensure_file(&self) -> ItemWriterResult {
+        if self.current.borrow().is_some() {
+            return Ok(());
+        }
+
+        let index = self.files.borrow().len();
+        let path = PathBuf::from(self.pattern.replace("{index}", &index.to_string()));
+
+        let writer = (self.factory)(&path);
+        self.files.borrow_mut().push(path);
+        writer.open()?;
+
+        *self.current.borrow_mut() = Some(writer);
+        Ok(())
+    }
+}
+
+impl<'a, T> ItemWriter<T> for RollingFileItemWriter<'a, T> {
+    fn write(&self, items: &[T]) -> ItemWriterResult {
+        let window = self.current_window();
+        if window != self.window_index.get() {
+            self.window_index.set(window);
+            self.roll()?;
+        }
+
+        let mut remaining = items;
+
+        while !remaining.is_empty() {
+            if self
+                .max_items
+                .is_some_and(|max_items| self.items_in_file.get() >= max_items)
+            {
+                self.roll()?;
+            }
+
+            self.ensure_file()?;
+
+            let capacity = self.max_items.map_or(remaining.len(), |max_items| {
+                max_items - self.items_in_file.get()
+            });
+            let (batch, rest) = remaining.split_at(capacity.min(remaining.len()));
+
+            if let Some(writer) = self.current.borrow().as_ref() {
+                writer.write(batch)?;
+            }
+            self.items_in_file
+                .set(self.items_in_file.get() + batch.len());
+            remaining = rest;
+        }
+
+        Ok(())
+    }
+
+    fn flush(&self) -> ItemWriterResult {
+        match self.current.borrow().as_ref() {
+            Some(writer) => writer.flush(),
+            None => Ok(()),
+        }
+    }
+
+    /// Closes the current file. Writing again rolls to a new file.
+    fn close(&self) -> ItemWriterResult {
+        self.roll()
+    }
+}
+
+/// A builder for configuring a `RollingFileItemWriter`.
+pub struct RollingFileItemWriterBuilder {
+    pattern: String,
+    max_items: Option<usize>,
+    window: Option<Duration>,
+}
+
+impl RollingFileItemWriterBuilder {
+    /// Creates a new `RollingFileItemWriterBuilder` naming files from `pattern`, where
+    /// `{index}` is replaced by the index of the file, such as `out-{index}.json`.
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            max_items: None,
+            window: None,
+        }
+    }
+
+    /// Rolls to a new file once the current one holds `max_items` items.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Rolls to a new file at the end of each time window, such as one hour or one day.
+    pub fn rollover_every(mut self, window: Duration) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Builds the `RollingFileItemWriter`, creating the writer of each file with `factory`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_items` is 0, or if the pattern has no `{index}` while the writer
+    /// may roll to a new file.
+    pub fn build<'a, T, W: ItemWriter<T> + 'a>(
+        self,
+        factory: impl Fn(&Path) -> W + 'a,
+    ) -> RollingFileItemWriter<'a, T> {
+        assert!(
+            self.max_items != Some(0),
+            "max_items must be greater than 0"
+        );
+        assert!(
+            self.pattern.contains("{index}") || (self.max_items.is_none() && self.window.is_none()),
+            "The file pattern must contain {{index}}"
+        );
+
+        RollingFileItemWriter {
+            pattern: self.pattern,
+            factory: Box::new(move |path| Box::new(factory(path))),
+            max_items: self.max_items,
+            window: self.window,
+            current: RefCell::new(None),
+            files: RefCell::new(Vec::new()),
+            items_in_file: Cell::new(0),
+            window_index: Cell::new(None),
+        }
+    }
+}
+
+/// A reader reading several sources one after the other, as a single stream.
+///
+/// It moves to the next source when the current one returns `Ok(None)`, and only returns
+/// `Ok(None)` once every source is exhausted. A read error is returned immediately, prefixed
+/// with the index of the failing source; the next read retries the same source.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{ItemReader, ItemReaderResult, MultiItemReader};
+/// use std::cell::Cell;
+///
+/// struct Range(Cell<u32>, u32);
+/// impl ItemReader<u32> for Range {
+///     fn read(&self) -> ItemReaderResult<u32> {
+///         let next = self.0.get();
+///         self.0.set(next + 1);
+///         Ok((next < self.1).then_some(next))
+///     }
+/// }
+///
+/// let january = Range(Cell::new(0), 2);
+/// let february = Range(Cell::new(10), 11);
+/// let reader = MultiItemReader::new(vec![&january, &february]);
+///
+/// assert_eq!(reader.read(), Ok(Some(0)));
+/// assert_eq!(reader.read(), Ok(Some(1)));
+/// assert_eq!(reader.read(), Ok(Some(10)));
+/// assert_eq!(reader.read(), Ok(None));
+/// ```
+pub struct MultiItemReader<'a, T> {
+    readers: Vec<&'a dyn ItemReader<T>>,
+    current: Cell<usize>,
+}
+
+impl<'a, T> MultiItemReader<'a, T> {
+    /// Creates a `MultiItemReader` reading the given sources in order.
+    pub fn new(readers: Vec<&'a dyn ItemReader<T>>) -> Self {
+        Self {
+            readers,
+            current: Cell::new(0),
+        }
+    }
+}
+
+impl<'a, T> ItemReader<T> for MultiItemReader<'a, T> {
+    fn read(&self) -> ItemReaderResult<T> {
+        while let Some(reader) = self.readers.get(self.current.get()) {
+            match reader.read() {
+                Ok(Some(item)) => return Ok(Some(item)),
+                Ok(None) => self.current.set(self.current.get() + 1),
+                Err(error) => {
+                    return Err(BatchError::ItemReader(format!(
+                        "source {}: {}",
+                        self.current.get(),
+                        error
+                    )))
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Opens all the sources, so that none of them fails midway through the read.
+    fn open(&self) -> Result<(), BatchError> {
+        self.readers.iter().try_for_each(|reader| reader.open())
+    }
+
+    /// Closes all the sources, returning the first error.
+    fn close(&self) -> Result<(), BatchError> {
+        let results: Vec<_> = self.readers.iter().map(|reader| reader.close()).collect();
+        results.into_iter().collect()
+    }
+
+    /// Sums the totals of the sources, if they are all known.
+    fn total_hint(&self) -> Option<usize> {
+        self.readers.iter().map(|reader| reader.total_hint()).sum()
+    }
+}
+
+/// A reader limiting the throughput of another reader, to spare a shared resource
+/// such as a rate-limited API or a database replica.
+///
+/// It follows a token bucket: each read takes a token, tokens are added at
+/// `max_per_second` per second up to `burst` tokens, and a read without token sleeps
+/// until one is available. The bucket starts full, so the first `burst` reads are immediate.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{ItemReader, ItemReaderResult, ThrottledItemReaderBuilder};
+///
+/// struct Numbers;
+/// impl ItemReader<u32> for Numbers {
+///     fn read(&self) -> ItemReaderResult<u32> {
+///         Ok(Some(42))
+///     }
+/// }
+///
+/// let numbers = Numbers;
+/// let reader = ThrottledItemReaderBuilder::new()
+///     .reader(&numbers)
+///     .max_per_second(1000)
+///     .burst(10)
+///     .build();
+///
+/// assert_eq!(reader.read(), Ok(Some(42)));
+/// ```
+pub struct ThrottledItemReader<'a, T> {
+    reader: &'a dyn ItemReader<T>,
+    max_per_second: f64,
+    burst: f64,
+    tokens: Cell<f64>,
+    last_refill: Cell<Instant>,
+}
+
+impl<'a, T> ThrottledItemReader<'a, T> {
+    /// Takes a token from the bucket, waiting for one if the bucket is empty.
+    fn acquire(&self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill.get()).as_secs_f64();
+        let tokens = (self.tokens.get() + elapsed * self.max_per_second).min(self.burst);
+        self.last_refill.set(now);
+
+        if tokens < 1.0 {
+            let wait = Duration::from_secs_f64((1.0 - tokens) / self.max_per_second);
+            thread::sleep(wait);
+            self.last_refill.set(now + wait);
+            self.tokens.set(0.0);
+        } else {
+            self.tokens.set(tokens - 1.0);
+        }
+    }
+}
+
+impl<'a, T> ItemReader<T> for ThrottledItemReader<'a, T> {
+    fn read(&self) -> ItemReaderResult<T> {
+        self.acquire();
+        self.reader.read()
+    }
+
+    fn open(&self) -> Result<(), BatchError> {
+        self.reader.open()
+    }
+
+    fn close(&self) -> Result<(), BatchError> {
+        self.reader.close()
+    }
+
+    fn total_hint(&self) -> Option<usize> {
+        self.reader.total_hint()
+    }
+}
+
+/// Builder for creating a `ThrottledItemReader`.
+pub struct ThrottledItemReaderBuilder<'a, T> {
+    reader: Option<&'a dyn ItemReader<T>>,
+    max_per_second: u32,
+    burst: u32,
+}
+
+impl<'a, T> Default for ThrottledItemReaderBuilder<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> ThrottledItemReaderBuilder<'a, T> {
+    /// Creates a new `ThrottledItemReaderBuilder` instance.
+    pub fn new() -> Self {
+        Self {
+            reader: None,
+            max_per_second: 0,
+            burst: 1,
+        }
+    }
+
+    /// Sets the reader to throttle.
+    pub fn reader(mut self, reader: &'a impl ItemReader<T>) -> Self {
+        self.reader = Some(reader);
+        self
+    }
+
+    /// Sets the maximum number of items read per second.
+    pub fn max_per_second(mut self, max_per_second: u32) -> Self {
+        self.max_per_second = max_per_second;
+        self
+    }
+
+    /// Sets the number of items which can be read at once after an idle period.
+    ///
+    /// Defaults to 1, which spaces reads evenly.
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst;
+        self
+    }
+
+    /// Builds a `ThrottledItemReader` instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no reader is set, or if `max_per_second` or `burst` is 0.
+    pub fn build(self) -> ThrottledItemReader<'a, T> {
+        assert!(self.max_per_second > 0, "max_per_second must be positive");
+        assert!(self.burst > 0, "burst must be positive");
+
+        ThrottledItemReader {
+            reader: self.reader.expect("A reader is required"),
+            max_per_second: f64::from(self.max_per_second),
+            burst: f64::from(self.burst),
+            tokens: Cell::new(f64::from(self.burst)),
+            last_refill: Cell::new(Instant::now()),
+        }
+    }
+}
+
+/// A reader returning at most `limit` items of another reader, to sample a source
+/// or run a smoke test on its first items.
+///
+/// Only items count towards the limit: read errors are returned as they are and the
+/// next read goes on. Once the limit is reached, the reader returns `Ok(None)` without
+/// reading the source any further.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{ItemReader, ItemReaderResult, LimitItemReader};
+///
+/// struct Numbers;
+/// impl ItemReader<u32> for Numbers {
+///     fn read(&self) -> ItemReaderResult<u32> {
+///         Ok(Some(42))
+///     }
+/// }
+///
+/// let numbers = Numbers;
+/// let reader = LimitItemReader::new(&numbers, 2);
+///
+/// assert_eq!(reader.read(), Ok(Some(42)));
+/// assert_eq!(reader.read(), Ok(Some(42)));
+/// assert_eq!(reader.read(), Ok(None));
+/// ```
+pub struct LimitItemReader<'a, T> {
+    reader: &'a dyn ItemReader<T>,
+    limit: usize,
+    count: Cell<usize>,
+}
+
+impl<'a, T> LimitItemReader<'a, T> {
+    /// Creates a `LimitItemReader` returning at most `limit` items of `reader`.
+    pub fn new(reader: &'a impl ItemReader<T>, limit: usize) -> Self {
+        Self {
+            reader,
+            limit,
+            count: Cell::new(0),
+        }
+    }
+}
+
+impl<'a, T> ItemReader<T> for LimitItemReader<'a, T> {
+    fn read(&self) -> ItemReaderResult<T> {
+        if self.count.get() >= self.limit {
+            return Ok(None);
+        }
+
+        let item = self.reader.read()?;
+        if item.is_some() {
+            self.count.set(self.count.get() + 1);
+        }
+
+        Ok(item)
+    }
+
+    fn open(&self) -> Result<(), BatchError> {
+        self.reader.open()
+    }
+
+    fn close(&self) -> Result<(), BatchError> {
+        self.reader.close()
+    }
+
+    /// Returns the limit, or the total of the source if it is lower.
+    fn total_hint(&self) -> Option<usize> {
+        let total = self
+            .reader
+            .total_hint()
+            .map_or(self.limit, |total| total.min(self.limit));
+        Some(total)
+    }
+}
+
+/// A writer discarding the items it receives.
+///
+/// The step still counts the items as written, which makes it suitable for dry runs,
+/// validation steps, or measuring the throughput of a reader in isolation.
+pub struct NoOpItemWriter<T> {
+    _pd: PhantomData<T>,
+}
+
+impl<T> NoOpItemWriter<T> {
+    /// Creates a new `NoOpItemWriter`.
+    pub fn new() -> Self {
+        Self { _pd: PhantomData }
+    }
+}
+
+impl<T> Default for NoOpItemWriter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ItemWriter<T> for NoOpItemWriter<T> {
+    fn write(&self, _items: &[T]) -> ItemWriterResult {
+        Ok(())
+    }
+}
+
+/// A writer collecting the items in memory.
+///
+/// Clones share the same buffer, so a clone kept before running the step gives access
+/// to the items afterwards. The buffer is behind a mutex and can be shared across threads.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::core::item::{ItemWriter, VecItemWriter};
+///
+/// let writer = VecItemWriter::new();
+/// writer.write(&[1, 2]).unwrap();
+/// writer.write(&[3]).unwrap();
+///
+/// assert_eq!(writer.items(), vec![1, 2, 3]);
+/// ```
+pub struct VecItemWriter<T> {
+    items: Arc<Mutex<Vec<T>>>,
+}
+
+impl<T: Clone> VecItemWriter<T> {
+    /// Creates a new, empty `VecItemWriter`.
+    pub fn new() -> Self {
+        Self {
+            items: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Returns a copy of the items written so far.
+    pub fn items(&self) -> Vec<T> {
+        self.items
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Takes the items written so far, leaving the shared buffer empty.
+    pub fn into_inner(self) -> Vec<T> {
+        std::mem::take(&mut *self.items.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl<T: Clone> Default for VecItemWriter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for VecItemWriter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            items: Arc::clone(&self.items),
+        }
+    }
+}
+
+impl<T: Clone> ItemWriter<T> for VecItemWriter<T> {
+    fn write(&self, items: &[T]) -> ItemWriterResult {
+        self.items
+            .lock()
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))?
+            .extend_from_slice(items);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        time::{Duration, Instant},
+    };
+
+    use crate::{
+        core::step::{Step, StepBuilder, StepInstance},
+        BatchError,
+    };
+
+    use super::{
+        BufferingItemWriter, ClosureItemReader, ClosureItemWriter, ClosureProcessor,
+        CompositeItemProcessor, CompositeItemWriter, FilterProcessor, GroupingProcessor,
+        ItemProcessor, ItemProcessorResult, ItemReader, ItemReaderResult, ItemWriter,
+        ItemWriterResult, IterItemReader, LimitItemReader, MultiItemReader, NoOpItemWriter,
+        PeekProcessor, ProcessorChainBuilder, RollingFileItemWriterBuilder,
+        RoutingItemWriterBuilder, ThrottledItemReaderBuilder, VecItemWriter,
+    };
+
+    struct Even;
+
+    impl ItemProcessor<u32, u32> for Even {
+        fn process(&self, item: &u32) -> ItemProcessorResult<u32> {
+            Ok(item.is_multiple_of(2).then_some(*item))
+        }
+    }
+
+    #[derive(Default)]
+    struct Increment {
+        calls: Cell<usize>,
+    }
+
+    impl ItemProcessor<u32, u32> for Increment {
+        fn process(&self, item: &u32) -> ItemProcessorResult<u32> {
+            self.calls.set(self.calls.get() + 1);
+            Ok(Some(item + 1))
+        }
+    }
+
+    struct Fail;
+
+    impl ItemProcessor<u32, u32> for Fail {
+        fn process(&self, _item: &u32) -> ItemProcessorResult<u32> {
+            Err(BatchError::ItemProcessor("fail".to_string()))
+        }
+    }
+
+    #[test]
+    fn chain_should_apply_stages_in_order() {
+        let increment = Increment::default();
+        let chain = ProcessorChainBuilder::new()
+            .add(&increment)
+            .add(&Even)
+            .add(&increment)
+            .build();
+
+        assert_eq!(chain.process(&1).unwrap(), Some(3));
+        assert_eq!(increment.calls.get(), 2);
+    }
+
+    #[test]
+    fn filtered_item_should_skip_later_stages() {
+        let increment = Increment::default();
+        let chain = ProcessorChainBuilder::new()
+            .add(&Even)
+            .add(&increment)
+            .build();
+
+        assert_eq!(chain.process(&1).unwrap(), None);
+        assert_eq!(increment.calls.get(), 0);
+    }
+
+    #[test]
+    fn error_should_propagate() {
+        let chain = ProcessorChainBuilder::new().add(&Fail).add(&Even).build();
+
+        assert!(chain.process(&2).is_err());
+    }
+
+    #[derive(Default)]
+    struct VecWriter {
+        items: RefCell<Vec<u32>>,
+    }
+
+    impl ItemWriter<u32> for VecWriter {
+        fn write(&self, items: &[u32]) -> ItemWriterResult {
+            self.items.borrow_mut().extend_from_slice(items);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn items_should_be_routed_to_their_output() {
+        let main = VecWriter::default();
+        let reject = VecWriter::default();
+
+        let writer = RoutingItemWriterBuilder::new()
+            .writer_for("main", &main)
+            .writer_for("reject", &reject)
+            .build();
+
+        let result = writer.write(&[("main", 1), ("reject", 2), ("main", 3)]);
+
+        assert!(result.is_ok());
+        assert_eq!(*main.items.borrow(), vec![1, 3]);
+        assert_eq!(*reject.items.borrow(), vec![2]);
+        assert_eq!(writer.get_write_count("main"), 2);
+        assert_eq!(writer.get_write_count("reject"), 1);
+        assert_eq!(writer.get_write_count("other"), 0);
+    }
+
+    #[test]
+    fn unknown_output_should_fail() {
+        let main = VecWriter::default();
+
+        let writer = RoutingItemWriterBuilder::new()
+            .writer_for("main", &main)
+            .build();
+
+        let result = writer.write(&[("main".to_string(), 1), ("other".to_string(), 2)]);
+
+        assert!(result.is_err());
+        assert!(main.items.borrow().is_empty());
+    }
+
+    struct FailingOnce(Cell<bool>);
+
+    impl ItemWriter<u32> for FailingOnce {
+        fn write(&self, _items: &[u32]) -> ItemWriterResult {
+            if self.0.replace(false) {
+                return Err(BatchError::ItemWriter("timeout".to_string()));
+            }
+            Ok(())
+        }
+
+        fn close(&self) -> ItemWriterResult {
+            Err(BatchError::ItemWriter("already closed".to_string()))
+        }
+    }
+
+    #[test]
+    fn routing_writer_should_not_be_retried_after_a_partial_write() {
+        let main = VecWriter::default();
+        let reject = FailingOnce(Cell::new(true));
+        let writer = RoutingItemWriterBuilder::new()
+            .writer_for("main", &main)
+            .writer_for("reject", &reject)
+            .build();
+        let reader = IterItemReader::new([("main", 1), ("reject", 2), ("main", 3)]);
+
+        let step: StepInstance<(&str, u32), (&str, u32)> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(3)
+            .retry_limit(1)
+            .skip_limit(1)
+            .build();
+
+        let execution = step.execute().unwrap();
+
+        assert_eq!(*main.items.borrow(), vec![1, 3]);
+        assert_eq!(execution.retry_count, 0);
+        assert_eq!(execution.write_count, 2);
+        assert_eq!(execution.write_error_count, 1);
+    }
+
+    #[test]
+    fn routing_writer_should_close_every_output() {
+        #[derive(Default)]
+        struct Closing(Cell<bool>);
+
+        impl ItemWriter<u32> for Closing {
+            fn write(&self, _items: &[u32]) -> ItemWriterResult {
+                Ok(())
+            }
+
+            fn close(&self) -> ItemWriterResult {
+                self.0.set(true);
+                Ok(())
+            }
+        }
+
+        let failing = FailingOnce(Cell::new(false));
+        let closing = Closing::default();
+        let writer = RoutingItemWriterBuilder::new()
+            .writer_for("first", &failing)
+            .writer_for("second", &failing)
+            .writer_for("last", &closing)
+            .build();
+
+        let result = ItemWriter::<(&str, u32)>::close(&writer);
+
+        assert_eq!(
+            result,
+            Err(BatchError::ItemWriter(
+                "output first: Error occurred in the ItemWriter: already closed; \
+                 output second: Error occurred in the ItemWriter: already closed"
+                    .to_string()
+            ))
+        );
+        assert!(closing.0.get());
+    }
+
+    struct Parse;
+
+    impl ItemProcessor<String, i32> for Parse {
+        fn process(&self, item: &String) -> ItemProcessorResult<i32> {
+            item.trim()
+                .parse()
+                .map(Some)
+                .map_err(|_| BatchError::ItemProcessor(format!("not a number: {}", item)))
+        }
+    }
+
+    struct Validate;
+
+    impl ItemProcessor<i32, i32> for Validate {
+        fn process(&self, item: &i32) -> ItemProcessorResult<i32> {
+            if *item < 0 {
+                return Err(BatchError::ItemProcessor(format!("negative: {}", item)));
+            }
+            Ok(Some(*item))
+        }
+    }
+
+    struct Label;
+
+    impl ItemProcessor<i32, String> for Label {
+        fn process(&self, item: &i32) -> ItemProcessorResult<String> {
+            Ok(Some(format!("item-{}", item)))
+        }
+    }
+
+    #[test]
+    fn composite_should_parse_then_validate() {
+        let processor = CompositeItemProcessor::new(&Parse).then(&Validate);
+
+        assert_eq!(processor.process(&" 7 ".to_string()), Ok(Some(7)));
+        assert_eq!(
+            processor.process(&"-1".to_string()),
+            Err(BatchError::ItemProcessor("negative: -1".to_string()))
+        );
+        assert_eq!(
+            processor.process(&"x".to_string()),
+            Err(BatchError::ItemProcessor("not a number: x".to_string()))
+        );
+    }
+
+    #[test]
+    fn composite_should_change_type_across_three_stages() {
+        let processor = CompositeItemProcessor::new(&Parse)
+            .then(&Validate)
+            .then(&Label);
+
+        assert_eq!(
+            processor.process(&"3".to_string()),
+            Ok(Some("item-3".to_string()))
+        );
+    }
+
+    #[test]
+    fn peek_should_observe_items_between_stages() {
+        let seen = RefCell::new(Vec::new());
+        let peek = PeekProcessor::new(|item: &i32| seen.borrow_mut().push(*item));
+
+        let processor = CompositeItemProcessor::new(&Parse)
+            .then(&peek)
+            .then(&Validate);
+
+        assert_eq!(processor.process(&"42".to_string()).unwrap(), Some(42));
+        assert!(processor.process(&"-1".to_string()).is_err());
+        assert_eq!(*seen.borrow(), vec![42, -1]);
+    }
+
+    #[test]
+    fn closure_reader_and_writer_should_be_used_by_a_step() {
+        let mut next = 0;
+        let reader = ClosureItemReader::new(move || {
+            next += 1;
+            match next {
+                3 => Err(BatchError::ItemReader("unavailable".to_string())),
+                next if next <= 5 => Ok(Some(next)),
+                _ => Ok(None),
+            }
+        });
+        let written = RefCell::new(Vec::new());
+        let writer = ClosureItemWriter::new(|items: &[u32]| {
+            written.borrow_mut().push(items.to_vec());
+            Ok(())
+        });
+
+        let step: StepInstance<u32, u32> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .skip_limit(1)
+            .build();
+
+        assert!(step.run().is_ok());
+        assert_eq!(written.borrow().concat(), vec![1, 2, 4, 5]);
+        assert_eq!(step.get_read_error_count(), 1);
+    }
+
+    #[test]
+    fn iter_reader_should_feed_a_step() {
+        let reader = IterItemReader::new((1..=5).map(|item: u32| item * 10));
+        let writer = VecItemWriter::new();
+
+        let step: StepInstance<u32, u32> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .build();
+
+        let execution = step.run().unwrap();
+        assert_eq!(writer.items(), vec![10, 20, 30, 40, 50]);
+        assert_eq!(execution.total_hint, Some(5));
+        assert_eq!(reader.read(), Ok(None));
+
+        let unknown = IterItemReader::new((1..=5).filter(|item: &u32| item.is_multiple_of(2)));
+        assert_eq!(unknown.total_hint(), None);
+        assert_eq!(IterItemReader::from(vec![1]).read(), Ok(Some(1)));
+    }
+
+    #[test]
+    fn grouping_processor_should_group_items_within_each_chunk() {
+        let reader = ClosureItemReader::new({
+            let mut orders = vec![1, 1, 2, 2, 2, 3].into_iter();
+            move || Ok(orders.next())
+        });
+        let processor = GroupingProcessor::new(
+            |order: &u32| *order,
+            |order, lines| format!("order-{}:{}", order, lines.len()),
+        );
+        let writer = VecItemWriter::new();
+
+        let step: StepInstance<u32, String> = StepBuilder::new()
+            .reader(&reader)
+            .processor(&processor)
+            .writer(&writer)
+            .chunk(4)
+            .build();
+
+        let execution = step.run().unwrap();
+
+        assert_eq!(
+            writer.items(),
+            vec!["order-1:2", "order-2:2", "order-2:1", "order-3:1"]
+        );
+        assert_eq!(execution.read_count, 6);
+        assert_eq!(execution.process_count, 4);
+    }
+
+    #[test]
+    fn closure_processor_should_be_used_by_a_step() {
+        let reader = Countdown(Cell::new(3));
+        let processor = ClosureProcessor::map(|item: &u32| format!("item-{}", item));
+        let writer = VecItemWriter::new();
+
+        let step: StepInstance<u32, String> = StepBuilder::new()
+            .reader(&reader)
+            .processor(&processor)
+            .writer(&writer)
+            .chunk(2)
+            .build();
+
+        assert!(step.run().is_ok());
+        assert_eq!(writer.items(), vec!["item-3", "item-2", "item-1"]);
+    }
+
+    #[test]
+    fn filter_should_drop_items_failing_the_predicate() {
+        let filter = FilterProcessor::new(|item: &String| !item.is_empty());
+
+        assert_eq!(filter.process(&"a".to_string()), Ok(Some("a".to_string())));
+        assert_eq!(filter.process(&String::new()), Ok(None));
+    }
+
+    struct Countdown(Cell<u32>);
+
+    impl ItemReader<u32> for Countdown {
+        fn read(&self) -> ItemReaderResult<u32> {
+            let remaining = self.0.get();
+            self.0.set(remaining.saturating_sub(1));
+            Ok((remaining > 0).then_some(remaining))
+        }
+    }
+
+    #[test]
+    fn no_op_writer_should_count_discarded_items_as_written() {
+        let reader = Countdown(Cell::new(5));
+        let writer = NoOpItemWriter::default();
+
+        let step: StepInstance<u32, u32> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .build();
+
+        assert!(step.execute().is_ok());
+        assert_eq!(step.get_read_count(), 5);
+        assert_eq!(step.get_write_count(), 5);
+    }
+
+    #[test]
+    fn vec_writer_clones_should_share_the_items() {
+        let writer = VecItemWriter::new();
+        let handle = writer.clone();
+
+        let threads: Vec<_> = (0..4)
+            .map(|index| {
+                let writer = writer.clone();
+                std::thread::spawn(move || writer.write(&[index, index]).unwrap())
+            })
+            .collect();
+        threads
+            .into_iter()
+            .for_each(|thread| thread.join().unwrap());
+
+        assert_eq!(handle.items().len(), 8);
+        assert_eq!(writer.into_inner().len(), 8);
+        assert!(handle.items().is_empty());
+    }
+
+    #[test]
+    fn multi_reader_should_report_the_failing_source() {
+        struct Source(RefCell<Vec<ItemReaderResult<u32>>>);
+
+        impl ItemReader<u32> for Source {
+            fn read(&self) -> ItemReaderResult<u32> {
+                self.0.borrow_mut().pop().unwrap_or(Ok(None))
+            }
+        }
+
+        let first = Source(RefCell::new(vec![Ok(Some(1))]));
+        let empty = Source(RefCell::new(vec![]));
+        let failing = Source(RefCell::new(vec![
+            Ok(Some(3)),
+            Err(BatchError::ItemReader("bad line".to_string())),
+        ]));
+        let reader = MultiItemReader::new(vec![&first, &empty, &failing]);
+
+        assert_eq!(reader.read(), Ok(Some(1)));
+        assert_eq!(
+            reader.read(),
+            Err(BatchError::ItemReader(
+                "source 2: Error occurred in the ItemReader: bad line".to_string()
+            ))
+        );
+        assert_eq!(reader.read(), Ok(Some(3)));
+        assert_eq!(reader.read(), Ok(None));
+    }
+
+    #[test]
+    fn throttled_reader_should_cap_the_read_rate() {
+        struct Counter(Cell<u32>);
+
+        impl ItemReader<u32> for Counter {
+            fn read(&self) -> ItemReaderResult<u32> {
+                self.0.set(self.0.get() + 1);
+                Ok(Some(self.0.get()))
+            }
+        }
+
+        let counter = Counter(Cell::new(0));
+        let reader = ThrottledItemReaderBuilder::new()
+            .reader(&counter)
+            .max_per_second(100)
+            .burst(5)
+            .build();
+
+        let start = Instant::now();
+        for _ in 0..5 {
+            reader.read().unwrap();
+        }
+        assert!(start.elapsed() < Duration::from_millis(40));
+
+        for _ in 0..10 {
+            reader.read().unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(90));
+        assert_eq!(counter.0.get(), 15);
+    }
+
+    #[test]
+    fn composite_writer_should_attempt_every_writer() {
+        struct Failing;
+
+        impl ItemWriter<u32> for Failing {
+            fn write(&self, _items: &[u32]) -> ItemWriterResult {
+                Err(BatchError::ItemWriter("disk full".to_string()))
+            }
+        }
+
+        let first = VecItemWriter::new();
+        let last = VecItemWriter::new();
+        let writer = CompositeItemWriter::new(vec![&first, &Failing, &last]);
+
+        let result = writer.write(&[1, 2]);
+
+        assert_eq!(
+            result,
+            Err(BatchError::PartialWrite {
+                written: 0,
+                message: "writer 1: Error occurred in the ItemWriter: disk full".to_string()
+            })
+        );
+        assert_eq!(first.items(), vec![1, 2]);
+        assert_eq!(last.items(), vec![1, 2]);
+        assert!(writer.flush().is_ok());
+
+        let only_failing = CompositeItemWriter::new(vec![&Failing]);
+        assert!(matches!(
+            only_failing.write(&[1, 2]),
+            Err(BatchError::ItemWriter(_))
+        ));
+    }
+
+    #[test]
+    fn composite_writer_should_not_be_retried_after_a_partial_write() {
+        struct FailingOnce(Cell<bool>);
+
+        impl ItemWriter<u32> for FailingOnce {
+            fn write(&self, _items: &[u32]) -> ItemWriterResult {
+                if self.0.replace(false) {
+                    return Err(BatchError::ItemWriter("timeout".to_string()));
+                }
+                Ok(())
+            }
+        }
+
+        let audit = VecItemWriter::new();
+        let database = FailingOnce(Cell::new(true));
+        let writer = CompositeItemWriter::new(vec![&audit, &database]);
+        let reader = IterItemReader::new([1, 2, 3]);
+
+        let step: StepInstance<u32, u32> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(3)
+            .retry_limit(1)
+            .skip_limit(3)
+            .build();
+
+        let execution = step.execute().unwrap();
+
+        assert_eq!(audit.items(), vec![1, 2, 3]);
+        assert_eq!(execution.retry_count, 0);
+        assert_eq!(execution.write_error_count, 3);
+    }
+
+    #[test]
+    fn buffering_writer_should_write_the_remainder_on_close() {
+        #[derive(Default)]
+        struct Batches(RefCell<Vec<usize>>);
+
+        impl ItemWriter<u32> for Batches {
+            fn write(&self, items: &[u32]) -> ItemWriterResult {
+                self.0.borrow_mut().push(items.len());
+                Ok(())
+            }
+        }
+
+        let reader = Countdown(Cell::new(8));
+        let batches = Batches::default();
+        let writer = BufferingItemWriter::new(&batches, 4);
+
+        let step: StepInstance<u32, u32> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(3)
+            .build();
+
+        assert!(step.execute().is_ok());
+        assert_eq!(step.get_write_count(), 8);
+        assert_eq!(*batches.0.borrow(), vec![6, 2]);
+    }
+
+    #[test]
+    fn rolling_writer_should_split_chunks_across_files() {
+        let files: RefCell<Vec<VecItemWriter<u32>>> = RefCell::new(Vec::new());
+
+        let reader = Countdown(Cell::new(7));
+        let writer = RollingFileItemWriterBuilder::new("out-{index}.json")
+            .max_items(3)
+            .build(|_path| {
+                let file = VecItemWriter::new();
+                files.borrow_mut().push(file.clone());
+                file
+            });
+
+        let step: StepInstance<u32, u32> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .build();
+
+        assert!(step.execute().is_ok());
+        assert_eq!(
+            writer.files(),
+            ["out-0.json", "out-1.json", "out-2.json"].map(std::path::PathBuf::from)
+        );
+
+        let contents: Vec<Vec<u32>> = files.borrow().iter().map(|file| file.items()).collect();
+        assert_eq!(contents, vec![vec![7, 6, 5], vec![4, 3, 2], vec![1]]);
+    }
+
+    #[test]
+    fn rolling_writer_should_roll_when_the_time_window_ends() {
+        let writer = RollingFileItemWriterBuilder::new("out-{index}.json")
+            .rollover_every(Duration::from_millis(20))
+            .build(|_path| VecItemWriter::new());
+
+        writer.write(&[1]).unwrap();
+        std::thread::sleep(Duration::from_millis(25));
+        writer.write(&[2]).unwrap();
+        ItemWriter::<u32>::close(&writer).unwrap();
+
+        assert_eq!(writer.files().len(), 2);
+    }
+
+    #[test]
+    fn limit_reader_should_stop_after_the_limit() {
+        let countdown = Countdown(Cell::new(10));
+        let throttled = ThrottledItemReaderBuilder::new()
+            .reader(&countdown)
+            .max_per_second(10_000)
+            .burst(10)
+            .build();
+        let reader = LimitItemReader::new(&throttled, 3);
+        let writer = VecItemWriter::new();
+
+        let step: StepInstance<u32, u32> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .build();
+
+        assert!(step.execute().is_ok());
+        assert_eq!(writer.items(), vec![10, 9, 8]);
+        assert_eq!(countdown.0.get(), 7);
+    }
+}
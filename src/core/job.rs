@@ -1,26 +1,181 @@
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 
-use log::info;
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use uuid::Uuid;
 
 use crate::BatchError;
 
-use super::{build_name, step::Step};
+use super::{
+    build_name,
+    parameters::{JobParameters, Parameter},
+    repository::{JobInstanceKey, JobRepository, StepRecord},
+    serde_millis,
+    step::{Step, StepExecution, StepStatus},
+};
 
-type JobResult<T> = Result<T, BatchError>;
+type JobResult<T> = Result<T, JobError>;
 
 /// Represents a job that can be executed.
 pub trait Job {
     /// Runs the job and returns the result of the job execution.
+    ///
+    /// On failure, the error holds the partial execution of the steps run until the failure.
     fn run(&self) -> JobResult<JobExecution>;
 }
 
+/// Represents the final status of a job.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum BatchStatus {
+    /// All the steps of the job completed successfully.
+    Completed,
+    /// A step of the job failed.
+    Failed,
+}
+
 /// Represents the execution of a job.
-#[derive(Debug)]
+///
+/// It serializes as `StepExecution` does, to emit a report of the run.
+#[derive(Debug, Serialize, Deserialize)]
 pub struct JobExecution {
+    /// The name of the job.
+    pub job_name: String,
+    /// The id of the job instance, generated when the job is built, to tell its runs apart
+    /// from the runs of other instances of the same job.
+    pub job_instance_id: Uuid,
+    /// The start time of the job execution, to measure elapsed times.
+    #[serde(skip, default = "Instant::now")]
     pub start: Instant,
+    /// The end time of the job execution, to measure elapsed times.
+    #[serde(skip, default = "Instant::now")]
     pub end: Instant,
+    /// The wall-clock time at which the job started.
+    #[serde(with = "serde_millis::system_time")]
+    pub started_at: SystemTime,
+    /// The wall-clock time at which the job ended.
+    #[serde(with = "serde_millis::system_time")]
+    pub ended_at: SystemTime,
+    /// The duration of the job execution.
+    #[serde(rename = "duration_ms", with = "serde_millis::duration")]
     pub duration: Duration,
+    /// The final status of the job.
+    pub status: BatchStatus,
+    /// The executions of the steps run by the job, in order.
+    pub step_executions: Vec<StepExecution>,
+    /// The number of items read by all the steps.
+    pub read_count: usize,
+    /// The number of items returned by the processors of all the steps.
+    pub process_count: usize,
+    /// The number of items written by all the steps.
+    pub write_count: usize,
+    /// The number of read errors of all the steps.
+    pub read_error_count: usize,
+    /// The number of process errors of all the steps.
+    pub process_error_count: usize,
+    /// The number of write errors of all the steps.
+    pub write_error_count: usize,
+}
+
+impl JobExecution {
+    /// Builds the execution of a job from the executions of its steps.
+    fn new(
+        job: &JobInstance,
+        start: Instant,
+        status: BatchStatus,
+        step_executions: Vec<StepExecution>,
+    ) -> Self {
+        let total = |count: fn(&StepExecution) -> usize| step_executions.iter().map(count).sum();
+        let duration = start.elapsed();
+        let (started_at, ended_at) = serde_millis::wall_clock(duration);
+
+        JobExecution {
+            job_name: job.name.clone(),
+            job_instance_id: job.id,
+            start,
+            end: Instant::now(),
+            started_at,
+            ended_at,
+            duration,
+            status,
+            read_count: total(|execution| execution.read_count),
+            process_count: total(|execution| execution.process_count),
+            write_count: total(|execution| execution.write_count),
+            read_error_count: total(|execution| execution.read_error_count),
+            process_error_count: total(|execution| execution.process_error_count),
+            write_error_count: total(|execution| execution.write_error_count),
+            step_executions,
+        }
+    }
+}
+
+/// Error returned when a job fails.
+#[derive(Error, Debug)]
+#[error("{error}")]
+pub struct JobError {
+    /// The error which made the job fail.
+    pub error: BatchError,
+    /// The partial execution of the job, including the failed step.
+    pub execution: Box<JobExecution>,
+}
+
+/// Function generating a name from the kind of element and its position.
+type NamingFunction<'a> = dyn Fn(&str, usize) -> String + 'a;
+
+/// Defines how the job and its steps are named when no name has been set explicitly.
+#[derive(Default)]
+pub enum NamingStrategy<'a> {
+    /// Generates 8 random alphanumeric characters.
+    #[default]
+    Random,
+    /// Names the job `job` and the steps `step-1`, `step-2`... by position.
+    Sequence,
+    /// Calls the function with the kind of element (`"job"` or `"step"`) and its position
+    /// (0 for the job, starting at 1 for the steps).
+    Custom(Box<NamingFunction<'a>>),
+}
+
+impl<'a> NamingStrategy<'a> {
+    /// Generates the name of an element.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of element: `"job"` or `"step"`.
+    /// * `position` - 0 for the job, the position of the step starting at 1 otherwise.
+    pub fn name(&self, kind: &str, position: usize) -> String {
+        match self {
+            NamingStrategy::Random => build_name(),
+            NamingStrategy::Sequence if position == 0 => kind.to_string(),
+            NamingStrategy::Sequence => format!("{}-{}", kind, position),
+            NamingStrategy::Custom(naming) => naming(kind, position),
+        }
+    }
+}
+
+/// Condition on the terminal status of a step to follow a transition.
+#[derive(Debug, Clone, Copy)]
+enum Condition {
+    /// Matches the given status only.
+    Status(StepStatus),
+    /// Matches any status other than `StepStatus::Success`.
+    Failure,
+}
+
+impl Condition {
+    fn matches(&self, status: StepStatus) -> bool {
+        match self {
+            Condition::Status(expected) => *expected == status,
+            Condition::Failure => status != StepStatus::Success,
+        }
+    }
+}
+
+/// Transition from a step to the next one, by index in the steps of the job.
+#[derive(Debug)]
+struct Transition {
+    from: usize,
+    on: Condition,
+    to: usize,
 }
 
 /// Represents an instance of a job.
@@ -28,6 +183,78 @@ pub struct JobInstance<'a> {
     id: Uuid,
     name: String,
     steps: Vec<&'a dyn Step>,
+    transitions: Vec<Transition>,
+    parameters: JobParameters,
+    repository: Option<&'a dyn JobRepository>,
+    dry_run: bool,
+}
+
+impl<'a> JobInstance<'a> {
+    /// Gets the name of the job.
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    /// Gets the id of the job instance, generated when the job is built.
+    pub fn get_id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Checks if the step has already completed successfully in a previous run of this
+    /// job instance.
+    fn is_completed(&self, instance: &JobInstanceKey, step: &dyn Step) -> Result<bool, BatchError> {
+        let Some(repository) = self.repository else {
+            return Ok(false);
+        };
+
+        let record = repository.load_step_execution(instance, step.get_name())?;
+
+        Ok(record.is_some_and(|record| record.status == StepStatus::Success))
+    }
+
+    /// Saves the execution of a step in the repository, if any.
+    ///
+    /// Nothing is saved by a dry run, so that the steps run again in the real run.
+    fn save(
+        &self,
+        instance: &JobInstanceKey,
+        step: &dyn Step,
+        execution: &StepExecution,
+    ) -> Result<(), BatchError> {
+        match self.repository {
+            Some(_) if self.dry_run => Ok(()),
+            Some(repository) => repository.save_step_execution(
+                instance,
+                &StepRecord::new(step.get_name(), step.get_status(), execution),
+            ),
+            None => Ok(()),
+        }
+    }
+
+    /// Builds the error returned when the job fails.
+    fn failure(
+        &self,
+        start: Instant,
+        error: BatchError,
+        step_executions: Vec<StepExecution>,
+    ) -> JobError {
+        let execution = JobExecution::new(self, start, BatchStatus::Failed, step_executions);
+
+        JobError {
+            error,
+            execution: Box::new(execution),
+        }
+    }
+
+    /// Finds the step following a step which ended with the given status.
+    ///
+    /// Transitions are evaluated in the order they have been declared.
+    fn next_step(&self, from: usize, status: StepStatus) -> Option<usize> {
+        self.transitions
+            .iter()
+            .find(|transition| transition.from == from && transition.on.matches(status))
+            .map(|transition| transition.to)
+    }
 }
 
 impl<'a> Job for JobInstance<'a> {
@@ -35,21 +262,68 @@ impl<'a> Job for JobInstance<'a> {
         let start = Instant::now();
 
         info!("Start of job: {}, id: {}", self.name, self.id);
-        let steps = &self.steps;
-        for step in steps {
+        let mut step_executions = Vec::with_capacity(self.steps.len());
+        let mut current = if self.steps.is_empty() { None } else { Some(0) };
+        let instance = JobInstanceKey::new(&self.name, self.parameters.to_typed_strings());
+
+        while let Some(index) = current {
+            let step = self.steps[index];
+
+            match self.is_completed(&instance, step) {
+                Ok(true) => {
+                    info!("Step: {} already completed, skipped", step.get_name());
+                    current = self.next_step(index, StepStatus::Success);
+                    continue;
+                }
+                Ok(false) => {}
+                Err(error) => {
+                    error!(
+                        "Job: {} failed to load step: {}",
+                        self.name,
+                        step.get_name()
+                    );
+                    return Err(self.failure(start, error, step_executions));
+                }
+            }
+
+            // The step may be shared with other jobs: it is dry run for this run only
+            let dry_run = step.is_dry_run();
+            step.set_dry_run(dry_run || self.dry_run);
             let result = step.execute();
+            step.set_dry_run(dry_run);
+            let failed = result.is_err();
+            let step_execution = result.unwrap_or_else(|step_execution| step_execution);
+            let saved = self.save(&instance, step, &step_execution);
+            step_executions.push(step_execution);
+
+            if let Err(error) = saved {
+                error!(
+                    "Job: {} failed to save step: {}",
+                    self.name,
+                    step.get_name()
+                );
+                return Err(self.failure(start, error, step_executions));
+            }
 
-            if result.is_err() {
-                return Err(BatchError::Step(step.get_name().to_owned()));
+            current = self.next_step(index, step.get_status());
+
+            // A failed step without matching transition fails the job
+            if failed && current.is_none() {
+                error!("Job: {} failed at step: {}", self.name, step.get_name());
+                let error = BatchError::Step(step.get_name().to_owned());
+                return Err(self.failure(start, error, step_executions));
             }
         }
-        info!("End of job: {}, id: {}", self.name, self.id);
 
-        let job_execution = JobExecution {
-            start,
-            end: Instant::now(),
-            duration: start.elapsed(),
-        };
+        let job_execution = JobExecution::new(self, start, BatchStatus::Completed, step_executions);
+        info!(
+            "End of job: {}, id: {}, read: {}, written: {}, duration: {:?}",
+            self.name,
+            self.id,
+            job_execution.read_count,
+            job_execution.write_count,
+            job_execution.duration
+        );
 
         Ok(job_execution)
     }
@@ -60,6 +334,13 @@ impl<'a> Job for JobInstance<'a> {
 pub struct JobBuilder<'a> {
     name: Option<String>,
     steps: Vec<&'a dyn Step>,
+    transitions: Vec<Transition>,
+    current: usize,
+    condition: Option<Condition>,
+    naming: NamingStrategy<'a>,
+    parameters: JobParameters,
+    repository: Option<&'a dyn JobRepository>,
+    dry_run: bool,
 }
 
 impl<'a> JobBuilder<'a> {
@@ -68,33 +349,174 @@ impl<'a> JobBuilder<'a> {
         Self {
             name: None,
             steps: Vec::new(),
+            transitions: Vec::new(),
+            current: 0,
+            condition: None,
+            naming: NamingStrategy::Random,
+            parameters: JobParameters::new(),
+            repository: None,
+            dry_run: false,
         }
     }
 
-    /// Sets the name of the job.
-    pub fn name(mut self, name: String) -> JobBuilder<'a> {
-        self.name = Some(name);
+    /// Sets the name of the job, reported in the logs and in its `JobExecution`.
+    ///
+    /// Defaults to a name generated by the naming strategy.
+    pub fn name(mut self, name: impl Into<String>) -> JobBuilder<'a> {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the strategy naming the job and the steps which have not been explicitly named.
+    ///
+    /// Defaults to `NamingStrategy::Random`.
+    pub fn naming(mut self, naming: NamingStrategy<'a>) -> JobBuilder<'a> {
+        self.naming = naming;
+        self
+    }
+
+    /// Adds a parameter to the job, replacing any parameter with the same name.
+    ///
+    /// The parameters are passed to the steps. The name of the job and its parameters
+    /// identify the job instance in the repository.
+    pub fn parameter(mut self, name: &str, value: impl Into<Parameter>) -> JobBuilder<'a> {
+        self.parameters.insert(name, value);
+        self
+    }
+
+    /// Sets all the parameters of the job.
+    pub fn parameters(mut self, parameters: JobParameters) -> JobBuilder<'a> {
+        self.parameters = parameters;
+        self
+    }
+
+    /// Sets the repository making the job restartable.
+    ///
+    /// Each step execution is saved as the step ends, and a rerun of the same job
+    /// instance skips the steps already completed. The job and its steps must then
+    /// have stable names: set them explicitly or use `NamingStrategy::Sequence`.
+    /// Skipped steps are not part of the returned `JobExecution`.
+    pub fn repository(mut self, repository: &'a dyn JobRepository) -> JobBuilder<'a> {
+        self.repository = Some(repository);
+        self
+    }
+
+    /// Runs all the steps of the job without writing, as set by `StepBuilder::dry_run`.
+    ///
+    /// The job reports the items read and processed by its steps, while their writers are
+    /// never opened. The step executions are not saved in the repository.
+    pub fn dry_run(mut self, dry_run: bool) -> JobBuilder<'a> {
+        self.dry_run = dry_run;
         self
     }
 
     /// Sets the first step of the job.
     pub fn start(mut self, step: &'a dyn Step) -> JobBuilder<'a> {
-        self.steps.push(step);
+        self.current = self.index_of(step);
+        self
+    }
+
+    /// Adds a step to the job, run when the current step succeeds.
+    pub fn next(self, step: &'a dyn Step) -> JobBuilder<'a> {
+        self.on(StepStatus::Success).to(step)
+    }
+
+    /// Sets the status of the current step for which the transition declared by `to` is followed.
+    pub fn on(mut self, status: StepStatus) -> JobBuilder<'a> {
+        self.condition = Some(Condition::Status(status));
+        self
+    }
+
+    /// Declares that the transition declared by `to` is followed when the current step fails,
+    /// whatever its error status.
+    pub fn on_failure(mut self) -> JobBuilder<'a> {
+        self.condition = Some(Condition::Failure);
+        self
+    }
+
+    /// Declares a transition from the current step to `step`, followed when the current step
+    /// ends with the status set by `on` or `on_failure` (`StepStatus::Success` by default).
+    ///
+    /// `step` becomes the current step. Transitions of a step are evaluated in the order
+    /// they have been declared. When a failed step has no matching transition, the job fails.
+    pub fn to(mut self, step: &'a dyn Step) -> JobBuilder<'a> {
+        let to = self.index_of(step);
+        let on = self
+            .condition
+            .take()
+            .unwrap_or(Condition::Status(StepStatus::Success));
+
+        self.transitions.push(Transition {
+            from: self.current,
+            on,
+            to,
+        });
+        self.current = to;
         self
     }
 
-    /// Adds a step to the job.
-    pub fn next(mut self, step: &'a dyn Step) -> JobBuilder<'a> {
-        self.steps.push(step);
+    /// Sets the current step, to declare other transitions from a step already added.
+    pub fn from(mut self, step: &'a dyn Step) -> JobBuilder<'a> {
+        self.current = self.index_of(step);
         self
     }
 
+    /// Gets the index of a step, adding it to the steps of the job if needed.
+    fn index_of(&mut self, step: &'a dyn Step) -> usize {
+        let position = self
+            .steps
+            .iter()
+            .position(|known| std::ptr::addr_eq(*known, step));
+
+        position.unwrap_or_else(|| {
+            self.steps.push(step);
+            self.steps.len() - 1
+        })
+    }
+
+    /// Checks that no transition leads back to a step already run, which would loop forever.
+    fn has_cycle(&self) -> bool {
+        // Depth-first search, with the steps on the current path
+        fn visit(from: usize, transitions: &[Transition], path: &mut Vec<usize>) -> bool {
+            if path.contains(&from) {
+                return true;
+            }
+            path.push(from);
+            let cycle = transitions
+                .iter()
+                .filter(|transition| transition.from == from)
+                .any(|transition| visit(transition.to, transitions, path));
+            path.pop();
+            cycle
+        }
+
+        (0..self.steps.len()).any(|index| visit(index, &self.transitions, &mut Vec::new()))
+    }
+
     /// Builds and returns a `JobInstance` based on the configured parameters.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the transitions form a cycle, such as a step followed by itself.
     pub fn build(self) -> JobInstance<'a> {
+        assert!(
+            !self.has_cycle(),
+            "Transitions of the job must not form a cycle"
+        );
+
+        for (index, step) in self.steps.iter().enumerate() {
+            step.set_default_name(self.naming.name("step", index + 1));
+            step.set_parameters(&self.parameters);
+        }
+
         JobInstance {
             id: Uuid::new_v4(),
-            name: self.name.unwrap_or(build_name()),
+            name: self.name.unwrap_or_else(|| self.naming.name("job", 0)),
             steps: self.steps,
+            transitions: self.transitions,
+            parameters: self.parameters,
+            repository: self.repository,
+            dry_run: self.dry_run,
         }
     }
 }
@@ -107,17 +529,22 @@ mod tests {
         env::{self, temp_dir},
         fs::File,
         path::Path,
+        time::UNIX_EPOCH,
     };
 
     use serde::{Deserialize, Serialize};
 
     use crate::{
-        core::step::{StepBuilder, StepInstance},
+        core::{
+            item::{ItemWriter, ItemWriterResult, VecItemWriter},
+            repository::{InMemoryJobRepository, JobInstanceKey, JobRepository},
+            step::{Step, StepBuilder, StepInstance},
+        },
         item::csv::csv_writer::CsvItemWriterBuilder,
         item::json::json_reader::JsonItemReaderBuilder,
     };
 
-    use super::{Job, JobBuilder};
+    use super::{BatchStatus, Job, JobBuilder, JobExecution, NamingStrategy, StepStatus};
 
     #[derive(Serialize, Deserialize, Clone)]
     pub struct Person {
@@ -156,4 +583,420 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn unnamed_steps_should_be_named_by_strategy() {
+        let reader = JsonItemReaderBuilder::<Person>::new().from_reader("[]".as_bytes());
+        let writer = CsvItemWriterBuilder::new().from_writer(vec![]);
+
+        let first: StepInstance<Person, Person> =
+            StepBuilder::new().reader(&reader).writer(&writer).build();
+        let second: StepInstance<Person, Person> = StepBuilder::new()
+            .name("load".to_string())
+            .reader(&reader)
+            .writer(&writer)
+            .build();
+        let third: StepInstance<Person, Person> =
+            StepBuilder::new().reader(&reader).writer(&writer).build();
+
+        let job = JobBuilder::new()
+            .naming(NamingStrategy::Sequence)
+            .start(&first)
+            .next(&second)
+            .next(&third)
+            .build();
+
+        assert_eq!(job.name, "job");
+        assert_eq!(first.get_name(), "step-1");
+        assert_eq!(second.get_name(), "load");
+        assert_eq!(third.get_name(), "step-3");
+    }
+
+    #[test]
+    fn custom_strategy_should_be_used() {
+        let reader = JsonItemReaderBuilder::<Person>::new().from_reader("[]".as_bytes());
+        let writer = CsvItemWriterBuilder::new().from_writer(vec![]);
+
+        let step: StepInstance<Person, Person> =
+            StepBuilder::new().reader(&reader).writer(&writer).build();
+
+        let job = JobBuilder::new()
+            .naming(NamingStrategy::Custom(Box::new(|kind, position| {
+                format!("import-{}-{}", kind, position)
+            })))
+            .start(&step)
+            .build();
+
+        assert_eq!(job.name, "import-job-0");
+        assert_eq!(step.get_name(), "import-step-1");
+    }
+
+    #[test]
+    fn job_execution_should_aggregate_step_executions() {
+        let reader = JsonItemReaderBuilder::<Person>::new()
+            .from_reader(File::open("examples/data/persons.json").unwrap());
+        let empty_reader = JsonItemReaderBuilder::<Person>::new().from_reader("[]".as_bytes());
+        let writer = CsvItemWriterBuilder::new().from_writer(vec![]);
+
+        let first: StepInstance<Person, Person> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(3)
+            .build();
+        let second: StepInstance<Person, Person> = StepBuilder::new()
+            .reader(&empty_reader)
+            .writer(&writer)
+            .build();
+
+        let job = JobBuilder::new().start(&first).next(&second).build();
+        let execution = job.run().unwrap();
+
+        assert_eq!(execution.status, BatchStatus::Completed);
+        assert_eq!(execution.step_executions.len(), 2);
+        assert_eq!(execution.read_count, first.get_read_count());
+        assert_eq!(execution.write_count, first.get_write_count());
+        assert_eq!(execution.read_error_count, 0);
+    }
+
+    #[test]
+    fn job_execution_should_identify_the_job_instance() {
+        let reader = JsonItemReaderBuilder::<Person>::new().from_reader("[]".as_bytes());
+        let writer = CsvItemWriterBuilder::new().from_writer(vec![]);
+
+        let step: StepInstance<Person, Person> =
+            StepBuilder::new().reader(&reader).writer(&writer).build();
+
+        let first = JobBuilder::new().name("nightly-load").start(&step).build();
+        let second = JobBuilder::new().name("nightly-load").start(&step).build();
+
+        let first_execution = first.run().unwrap();
+        let second_execution = second.run().unwrap();
+
+        assert_eq!(first.get_name(), "nightly-load");
+        assert_eq!(first_execution.job_name, "nightly-load");
+        assert_eq!(first_execution.job_instance_id, first.get_id());
+        assert_eq!(second_execution.job_instance_id, second.get_id());
+        assert_ne!(first.get_id(), second.get_id());
+    }
+
+    #[test]
+    fn failed_job_should_return_partial_execution() {
+        let reader = JsonItemReaderBuilder::<Person>::new().from_reader("[]".as_bytes());
+        let failing_reader =
+            JsonItemReaderBuilder::<Person>::new().from_reader(r#"[{"first_name": 1}]"#.as_bytes());
+        let writer = CsvItemWriterBuilder::new().from_writer(vec![]);
+
+        let first: StepInstance<Person, Person> =
+            StepBuilder::new().reader(&reader).writer(&writer).build();
+        let second: StepInstance<Person, Person> = StepBuilder::new()
+            .name("failing".to_string())
+            .reader(&failing_reader)
+            .writer(&writer)
+            .build();
+        let third: StepInstance<Person, Person> =
+            StepBuilder::new().reader(&reader).writer(&writer).build();
+
+        let job = JobBuilder::new()
+            .start(&first)
+            .next(&second)
+            .next(&third)
+            .build();
+        let error = job.run().unwrap_err();
+
+        assert_eq!(error.to_string(), "Error occurred in the step: failing");
+        assert_eq!(error.execution.job_instance_id, job.get_id());
+        assert_eq!(error.execution.status, BatchStatus::Failed);
+        assert_eq!(error.execution.step_executions.len(), 2);
+        assert_eq!(error.execution.read_error_count, 1);
+    }
+
+    #[test]
+    fn failed_step_should_follow_recovery_transition() {
+        let failing_reader =
+            JsonItemReaderBuilder::<Person>::new().from_reader(r#"[{"first_name": 1}]"#.as_bytes());
+        let reader = JsonItemReaderBuilder::<Person>::new()
+            .from_reader(File::open("examples/data/persons.json").unwrap());
+        let empty_reader = JsonItemReaderBuilder::<Person>::new().from_reader("[]".as_bytes());
+        let writer = CsvItemWriterBuilder::new().from_writer(vec![]);
+
+        let load: StepInstance<Person, Person> = StepBuilder::new()
+            .reader(&failing_reader)
+            .writer(&writer)
+            .build();
+        let report: StepInstance<Person, Person> = StepBuilder::new()
+            .reader(&empty_reader)
+            .writer(&writer)
+            .build();
+        let recovery: StepInstance<Person, Person> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .build();
+
+        let job = JobBuilder::new()
+            .start(&load)
+            .on(StepStatus::Success)
+            .to(&report)
+            .from(&load)
+            .on_failure()
+            .to(&recovery)
+            .build();
+
+        let execution = job.run().unwrap();
+
+        assert_eq!(execution.status, BatchStatus::Completed);
+        assert_eq!(execution.step_executions.len(), 2);
+        assert_eq!(load.get_status(), StepStatus::ReadError);
+        assert_eq!(report.get_status(), StepStatus::Starting);
+        assert_eq!(recovery.get_status(), StepStatus::Success);
+    }
+
+    #[test]
+    fn transition_should_match_exact_status() {
+        let failing_reader =
+            JsonItemReaderBuilder::<Person>::new().from_reader(r#"[{"first_name": 1}]"#.as_bytes());
+        let empty_reader = JsonItemReaderBuilder::<Person>::new().from_reader("[]".as_bytes());
+        let writer = CsvItemWriterBuilder::new().from_writer(vec![]);
+
+        let load: StepInstance<Person, Person> = StepBuilder::new()
+            .reader(&failing_reader)
+            .writer(&writer)
+            .build();
+        let recovery: StepInstance<Person, Person> = StepBuilder::new()
+            .reader(&empty_reader)
+            .writer(&writer)
+            .build();
+
+        let job = JobBuilder::new()
+            .start(&load)
+            .on(StepStatus::WriteError)
+            .to(&recovery)
+            .build();
+
+        let error = job.run().unwrap_err();
+
+        assert_eq!(error.execution.status, BatchStatus::Failed);
+        assert_eq!(recovery.get_status(), StepStatus::Starting);
+    }
+
+    #[test]
+    fn restarted_job_should_skip_completed_steps() {
+        let repository = InMemoryJobRepository::new();
+        let empty_reader = JsonItemReaderBuilder::<Person>::new().from_reader("[]".as_bytes());
+        let failing_reader =
+            JsonItemReaderBuilder::<Person>::new().from_reader(r#"[{"first_name": 1}]"#.as_bytes());
+        let writer = CsvItemWriterBuilder::new().from_writer(vec![]);
+
+        let run = |reader| {
+            let extract: StepInstance<Person, Person> = StepBuilder::new()
+                .reader(&empty_reader)
+                .writer(&writer)
+                .build();
+            let load: StepInstance<Person, Person> =
+                StepBuilder::new().reader(reader).writer(&writer).build();
+
+            let job = JobBuilder::new()
+                .name("import".to_string())
+                .naming(NamingStrategy::Sequence)
+                .parameter("date", "2024-01-01")
+                .repository(&repository)
+                .start(&extract)
+                .next(&load)
+                .build();
+
+            (
+                job.run()
+                    .map_err(|error| error.execution.step_executions.len()),
+                extract.get_status(),
+            )
+        };
+
+        let (first, extract_status) = run(&failing_reader);
+        assert_eq!(first.unwrap_err(), 2);
+        assert_eq!(extract_status, StepStatus::Success);
+
+        let (second, extract_status) = run(&empty_reader);
+        assert_eq!(second.unwrap().step_executions.len(), 1);
+        assert_eq!(extract_status, StepStatus::Starting);
+    }
+
+    #[test]
+    fn steps_should_expose_job_parameters() {
+        let reader = JsonItemReaderBuilder::<Person>::new().from_reader("[]".as_bytes());
+        let writer = CsvItemWriterBuilder::new().from_writer(vec![]);
+
+        let step: StepInstance<Person, Person> =
+            StepBuilder::new().reader(&reader).writer(&writer).build();
+
+        let job = JobBuilder::new()
+            .parameter("input", "data/orders.csv")
+            .parameter("run", 7_i64)
+            .start(&step)
+            .build();
+        let execution = job.run().unwrap();
+
+        let parameters = &execution.step_executions[0].parameters;
+        assert_eq!(parameters.get_string("input"), Some("data/orders.csv"));
+        assert_eq!(parameters.get_long("run"), Some(7));
+    }
+
+    #[test]
+    fn job_execution_should_round_trip_through_json() -> Result<()> {
+        let reader = JsonItemReaderBuilder::<Person>::new()
+            .from_reader(File::open("examples/data/persons.json")?);
+        let writer = VecItemWriter::new();
+
+        let step: StepInstance<Person, Person> = StepBuilder::new()
+            .name("load".to_string())
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(3)
+            .build();
+
+        let job = JobBuilder::new()
+            .name("import".to_string())
+            .parameter("run", 7)
+            .start(&step)
+            .build();
+
+        let execution = job.run().unwrap();
+        let report = serde_json::to_value(&execution)?;
+
+        assert_eq!(report["job_name"], "import");
+        assert_eq!(report["job_instance_id"], job.get_id().to_string());
+        assert_eq!(report["status"], "Completed");
+        assert!(report["started_at"].as_u64().unwrap() > 0);
+        assert!(report["duration_ms"].is_f64());
+        assert_eq!(report["step_executions"][0]["parameters"]["run"]["Long"], 7);
+
+        let read: JobExecution = serde_json::from_value(report)?;
+
+        assert_eq!(read.job_instance_id, execution.job_instance_id);
+        assert_eq!(read.status, BatchStatus::Completed);
+        assert_eq!(read.write_count, execution.write_count);
+        assert_eq!(
+            read.step_executions[0].parameters,
+            execution.step_executions[0].parameters
+        );
+        assert_eq!(
+            read.started_at.duration_since(UNIX_EPOCH)?.as_millis(),
+            execution.started_at.duration_since(UNIX_EPOCH)?.as_millis()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn dry_run_should_count_items_without_writing() {
+        struct Unreachable;
+
+        impl ItemWriter<Person> for Unreachable {
+            fn write(&self, _items: &[Person]) -> ItemWriterResult {
+                panic!("a dry run must not write");
+            }
+
+            fn open(&self) -> ItemWriterResult {
+                panic!("a dry run must not open the writer");
+            }
+
+            fn close(&self) -> ItemWriterResult {
+                panic!("a dry run must not close the writer");
+            }
+        }
+
+        let repository = InMemoryJobRepository::new();
+        let reader = JsonItemReaderBuilder::<Person>::new()
+            .from_reader(File::open("examples/data/persons.json").unwrap());
+        let writer = Unreachable;
+
+        let step: StepInstance<Person, Person> = StepBuilder::new()
+            .name("load".to_string())
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(3)
+            .build();
+
+        let job = JobBuilder::new()
+            .name("import".to_string())
+            .repository(&repository)
+            .dry_run(true)
+            .start(&step)
+            .build();
+
+        let execution = job.run().unwrap();
+
+        assert!(execution.read_count > 0);
+        assert_eq!(execution.process_count, execution.read_count);
+        assert_eq!(execution.write_count, 0);
+        assert_eq!(step.get_status(), StepStatus::Success);
+        assert!(repository
+            .load_step_execution(&JobInstanceKey::new("import", Default::default()), "load")
+            .unwrap()
+            .is_none());
+        assert!(!step.is_dry_run());
+    }
+
+    #[test]
+    #[should_panic(expected = "Transitions of the job must not form a cycle")]
+    fn a_step_followed_by_itself_should_not_build() {
+        let reader = JsonItemReaderBuilder::<Person>::new()
+            .from_reader(File::open("examples/data/persons.json").unwrap());
+        let writer = VecItemWriter::new();
+
+        let step: StepInstance<Person, Person> =
+            StepBuilder::new().reader(&reader).writer(&writer).build();
+
+        JobBuilder::new().start(&step).next(&step).build();
+    }
+
+    #[test]
+    #[should_panic(expected = "Transitions of the job must not form a cycle")]
+    fn transitions_leading_back_should_not_build() {
+        let reader = JsonItemReaderBuilder::<Person>::new()
+            .from_reader(File::open("examples/data/persons.json").unwrap());
+        let writer = VecItemWriter::new();
+
+        let load: StepInstance<Person, Person> =
+            StepBuilder::new().reader(&reader).writer(&writer).build();
+        let cleanup: StepInstance<Person, Person> =
+            StepBuilder::new().reader(&reader).writer(&writer).build();
+
+        JobBuilder::new()
+            .start(&load)
+            .on_failure()
+            .to(&cleanup)
+            .next(&load)
+            .build();
+    }
+
+    #[test]
+    fn dry_run_should_not_outlive_the_job() {
+        let reader = JsonItemReaderBuilder::<Person>::new()
+            .from_reader(File::open("examples/data/persons.json").unwrap());
+        let writer = VecItemWriter::new();
+
+        let step: StepInstance<Person, Person> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(3)
+            .dry_run(true)
+            .build();
+
+        let job = JobBuilder::new().dry_run(true).start(&step).build();
+        job.run().unwrap();
+        assert!(step.is_dry_run());
+
+        let step: StepInstance<Person, Person> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(3)
+            .build();
+
+        let dry_job = JobBuilder::new().dry_run(true).start(&step).build();
+        let job = JobBuilder::new().start(&step).build();
+        dry_job.run().unwrap();
+        assert!(!step.is_dry_run());
+        job.run().unwrap();
+        assert!(!step.is_dry_run());
+    }
 }
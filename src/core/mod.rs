@@ -1,9 +1,19 @@
 use rand::distributions::{Alphanumeric, DistString};
 
+pub mod async_step;
+
 pub mod item;
 
 pub mod job;
 
+pub mod parameters;
+
+pub mod repository;
+
+pub mod resilience;
+
+mod serde_millis;
+
 pub mod step;
 
 /// Generates a random name consisting of alphanumeric characters.
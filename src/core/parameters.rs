@@ -0,0 +1,255 @@
+use std::{collections::BTreeMap, fmt};
+
+#[cfg(feature = "datetime")]
+use chrono::{DateTime, Utc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::BatchError;
+
+/// Represents the value of a job parameter.
+///
+/// It serializes with the name of its variant, such as `{"Long": 42}`, so that it is read
+/// back with the same type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Parameter {
+    /// A string value, such as a file path.
+    String(String),
+    /// An integer value, such as a run id.
+    Long(i64),
+    /// A floating point value.
+    Double(f64),
+    /// A boolean value.
+    Boolean(bool),
+    /// A date and time value, such as a cutoff date.
+    #[cfg(feature = "datetime")]
+    Date(DateTime<Utc>),
+}
+
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Parameter::String(value) => write!(f, "{}", value),
+            Parameter::Long(value) => write!(f, "{}", value),
+            Parameter::Double(value) => write!(f, "{}", value),
+            Parameter::Boolean(value) => write!(f, "{}", value),
+            #[cfg(feature = "datetime")]
+            Parameter::Date(value) => write!(f, "{}", value.to_rfc3339()),
+        }
+    }
+}
+
+impl Parameter {
+    /// Returns the name of the type of the parameter, such as `Long`.
+    fn type_name(&self) -> &'static str {
+        match self {
+            Parameter::String(_) => "String",
+            Parameter::Long(_) => "Long",
+            Parameter::Double(_) => "Double",
+            Parameter::Boolean(_) => "Boolean",
+            #[cfg(feature = "datetime")]
+            Parameter::Date(_) => "Date",
+        }
+    }
+}
+
+impl From<&str> for Parameter {
+    fn from(value: &str) -> Self {
+        Parameter::String(value.to_string())
+    }
+}
+
+impl From<String> for Parameter {
+    fn from(value: String) -> Self {
+        Parameter::String(value)
+    }
+}
+
+impl From<i64> for Parameter {
+    fn from(value: i64) -> Self {
+        Parameter::Long(value)
+    }
+}
+
+impl From<f64> for Parameter {
+    fn from(value: f64) -> Self {
+        Parameter::Double(value)
+    }
+}
+
+impl From<bool> for Parameter {
+    fn from(value: bool) -> Self {
+        Parameter::Boolean(value)
+    }
+}
+
+#[cfg(feature = "datetime")]
+impl From<DateTime<Utc>> for Parameter {
+    fn from(value: DateTime<Utc>) -> Self {
+        Parameter::Date(value)
+    }
+}
+
+/// The parameters a job is launched with, such as input paths, run ids or cutoff dates.
+///
+/// The job passes them to its steps, which expose them in their `StepExecution`.
+/// Processors and writers needing them can be given a clone when they are built.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct JobParameters {
+    parameters: BTreeMap<String, Parameter>,
+}
+
+impl JobParameters {
+    /// Creates an empty `JobParameters`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a parameter, replacing any parameter with the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the parameter.
+    /// * `value` - The value of the parameter.
+    pub fn insert(&mut self, name: &str, value: impl Into<Parameter>) {
+        self.parameters.insert(name.to_string(), value.into());
+    }
+
+    /// Gets a parameter by name.
+    pub fn get(&self, name: &str) -> Option<&Parameter> {
+        self.parameters.get(name)
+    }
+
+    /// Gets a parameter by name, failing with a `BatchError::Configuration` if it is missing.
+    pub fn get_required(&self, name: &str) -> Result<&Parameter, BatchError> {
+        self.get(name)
+            .ok_or_else(|| BatchError::Configuration(format!("missing job parameter: {}", name)))
+    }
+
+    /// Gets a string parameter, or `None` if it is missing or not a string.
+    pub fn get_string(&self, name: &str) -> Option<&str> {
+        match self.get(name) {
+            Some(Parameter::String(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Gets an integer parameter, or `None` if it is missing or not an integer.
+    pub fn get_long(&self, name: &str) -> Option<i64> {
+        match self.get(name) {
+            Some(Parameter::Long(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Gets a floating point parameter, or `None` if it is missing or not a floating point.
+    pub fn get_double(&self, name: &str) -> Option<f64> {
+        match self.get(name) {
+            Some(Parameter::Double(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Gets a boolean parameter, or `None` if it is missing or not a boolean.
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        match self.get(name) {
+            Some(Parameter::Boolean(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Gets a date parameter, or `None` if it is missing or not a date.
+    #[cfg(feature = "datetime")]
+    pub fn get_date(&self, name: &str) -> Option<DateTime<Utc>> {
+        match self.get(name) {
+            Some(Parameter::Date(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if there is no parameter.
+    pub fn is_empty(&self) -> bool {
+        self.parameters.is_empty()
+    }
+
+    /// Returns the parameters formatted as strings, sorted by name.
+    pub fn to_strings(&self) -> BTreeMap<String, String> {
+        self.parameters
+            .iter()
+            .map(|(name, value)| (name.clone(), value.to_string()))
+            .collect()
+    }
+
+    /// Returns the parameters formatted as strings prefixed with their type, such as
+    /// `Long:42`, sorted by name, so that values of different types never match.
+    pub fn to_typed_strings(&self) -> BTreeMap<String, String> {
+        self.parameters
+            .iter()
+            .map(|(name, value)| (name.clone(), format!("{}:{}", value.type_name(), value)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BatchError;
+
+    use super::{JobParameters, Parameter};
+
+    #[test]
+    fn typed_accessors_should_match_the_parameter_type() {
+        let mut parameters = JobParameters::new();
+        parameters.insert("input", "data/orders.csv");
+        parameters.insert("run", 42_i64);
+        parameters.insert("ratio", 0.5);
+        parameters.insert("dry_run", true);
+
+        assert_eq!(parameters.get_string("input"), Some("data/orders.csv"));
+        assert_eq!(parameters.get_long("run"), Some(42));
+        assert_eq!(parameters.get_double("ratio"), Some(0.5));
+        assert_eq!(parameters.get_bool("dry_run"), Some(true));
+        assert_eq!(parameters.get_long("input"), None);
+        assert_eq!(parameters.get("run"), Some(&Parameter::Long(42)));
+    }
+
+    #[test]
+    fn typed_strings_should_tell_types_apart() {
+        let mut string = JobParameters::new();
+        string.insert("run", "1");
+        let mut long = JobParameters::new();
+        long.insert("run", 1_i64);
+
+        assert_eq!(string.to_strings(), long.to_strings());
+        assert_eq!(string.to_typed_strings()["run"], "String:1");
+        assert_eq!(long.to_typed_strings()["run"], "Long:1");
+    }
+
+    #[test]
+    fn missing_required_parameter_should_fail() {
+        let parameters = JobParameters::new();
+
+        assert_eq!(
+            parameters.get_required("input"),
+            Err(BatchError::Configuration(
+                "missing job parameter: input".to_string()
+            ))
+        );
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn date_parameter_should_be_formatted_as_rfc3339() {
+        use chrono::{TimeZone, Utc};
+
+        let cutoff = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut parameters = JobParameters::new();
+        parameters.insert("cutoff", cutoff);
+
+        assert_eq!(parameters.get_date("cutoff"), Some(cutoff));
+        assert_eq!(
+            parameters.to_strings()["cutoff"],
+            "2024-01-01T00:00:00+00:00"
+        );
+    }
+}
@@ -0,0 +1,371 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    fs::{self, File},
+    io::{BufReader, BufWriter, ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::BatchError;
+
+use super::step::{StepExecution, StepStatus};
+
+type RepositoryResult<T> = Result<T, BatchError>;
+
+/// Identifies a job instance: a job name and the parameters it is run with.
+///
+/// Two runs with the same name and the same parameters are runs of the same instance,
+/// so a rerun after a failure restarts it. Changing a parameter starts a new instance.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct JobInstanceKey {
+    /// The name of the job.
+    pub name: String,
+    /// The parameters of the job, sorted by name, as formatted by
+    /// `JobParameters::to_typed_strings`.
+    pub parameters: BTreeMap<String, String>,
+}
+
+impl JobInstanceKey {
+    /// Creates the key of a job instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the job.
+    /// * `parameters` - The parameters of the job.
+    pub fn new(name: &str, parameters: BTreeMap<String, String>) -> Self {
+        Self {
+            name: name.to_string(),
+            parameters,
+        }
+    }
+
+    /// Returns the key as a string, such as `["import",{"date":"String:2024-01-01"}]`.
+    ///
+    /// It is the JSON of the name and the parameters, so that separators within the
+    /// names or the values cannot make two instances share a key.
+    pub fn id(&self) -> String {
+        serde_json::to_string(&(&self.name, &self.parameters))
+            .expect("Strings are always serializable")
+    }
+}
+
+/// The persisted state of a step execution.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepRecord {
+    /// The name of the step.
+    pub step_name: String,
+    /// The status the step ended with.
+    pub status: StepStatus,
+    /// The number of items read.
+    pub read_count: usize,
+    /// The number of items written.
+    pub write_count: usize,
+    /// The number of items filtered by the processor.
+    pub filter_count: usize,
+    /// The number of read errors.
+    pub read_error_count: usize,
+    /// The number of process errors.
+    pub process_error_count: usize,
+    /// The number of write errors.
+    pub write_error_count: usize,
+}
+
+impl StepRecord {
+    /// Builds the record of a step execution.
+    ///
+    /// # Arguments
+    ///
+    /// * `step_name` - The name of the step.
+    /// * `status` - The status the step ended with.
+    /// * `execution` - The execution details of the step.
+    pub fn new(step_name: &str, status: StepStatus, execution: &StepExecution) -> Self {
+        Self {
+            step_name: step_name.to_string(),
+            status,
+            read_count: execution.read_count,
+            write_count: execution.write_count,
+            filter_count: execution.filter_count,
+            read_error_count: execution.read_error_count,
+            process_error_count: execution.process_error_count,
+            write_error_count: execution.write_error_count,
+        }
+    }
+}
+
+/// Stores the step executions of job instances so that a job can be restarted.
+///
+/// A job with a repository saves each step execution as the step ends, and skips
+/// the steps already recorded with `StepStatus::Success` for the same job instance.
+/// Steps are matched by name, so the steps of a restartable job must have stable names.
+pub trait JobRepository {
+    /// Saves the execution of a step, replacing any previous execution of the same step.
+    fn save_step_execution(
+        &self,
+        instance: &JobInstanceKey,
+        record: &StepRecord,
+    ) -> RepositoryResult<()>;
+
+    /// Loads the last execution of a step, if any.
+    fn load_step_execution(
+        &self,
+        instance: &JobInstanceKey,
+        step_name: &str,
+    ) -> RepositoryResult<Option<StepRecord>>;
+
+    /// Looks up a job instance.
+    ///
+    /// Returns the step executions recorded for the instance, or `None` if it never ran.
+    fn job_instance(&self, instance: &JobInstanceKey) -> RepositoryResult<Option<Vec<StepRecord>>>;
+}
+
+/// Replaces the record of the same step, or appends it.
+fn upsert(records: &mut Vec<StepRecord>, record: &StepRecord) {
+    match records
+        .iter_mut()
+        .find(|known| known.step_name == record.step_name)
+    {
+        Some(known) => *known = record.clone(),
+        None => records.push(record.clone()),
+    }
+}
+
+/// A `JobRepository` keeping the step executions in memory.
+///
+/// It only allows to restart a job within the same process.
+#[derive(Default)]
+pub struct InMemoryJobRepository {
+    instances: RefCell<HashMap<String, Vec<StepRecord>>>,
+}
+
+impl InMemoryJobRepository {
+    /// Creates an empty `InMemoryJobRepository`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl JobRepository for InMemoryJobRepository {
+    fn save_step_execution(
+        &self,
+        instance: &JobInstanceKey,
+        record: &StepRecord,
+    ) -> RepositoryResult<()> {
+        let mut instances = self.instances.borrow_mut();
+        upsert(instances.entry(instance.id()).or_default(), record);
+        Ok(())
+    }
+
+    fn load_step_execution(
+        &self,
+        instance: &JobInstanceKey,
+        step_name: &str,
+    ) -> RepositoryResult<Option<StepRecord>> {
+        let instances = self.instances.borrow();
+        let record = instances
+            .get(&instance.id())
+            .and_then(|records| records.iter().find(|record| record.step_name == step_name));
+
+        Ok(record.cloned())
+    }
+
+    fn job_instance(&self, instance: &JobInstanceKey) -> RepositoryResult<Option<Vec<StepRecord>>> {
+        Ok(self.instances.borrow().get(&instance.id()).cloned())
+    }
+}
+
+/// A `JobRepository` persisting the step executions in a JSON file.
+///
+/// The file maps the id of each job instance to its step executions. It is read on
+/// each lookup and rewritten on each save, so it survives a crash of the process.
+pub struct JsonFileJobRepository {
+    path: PathBuf,
+}
+
+impl JsonFileJobRepository {
+    /// Creates a repository stored in the given file, created on the first save.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the JSON file.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Reads all the job instances from the file.
+    fn load(&self) -> RepositoryResult<BTreeMap<String, Vec<StepRecord>>> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(error) => return Err(BatchError::Repository(error.to_string())),
+        };
+
+        serde_json::from_reader(BufReader::new(file))
+            .map_err(|error| BatchError::Repository(error.to_string()))
+    }
+
+    /// Writes all the job instances to the file.
+    ///
+    /// The content is written to a temporary file first, then renamed over the
+    /// repository file so that a crash never leaves it half written.
+    fn store(&self, instances: &BTreeMap<String, Vec<StepRecord>>) -> RepositoryResult<()> {
+        let temp_path = self.path.with_extension("tmp");
+
+        let file =
+            File::create(&temp_path).map_err(|error| BatchError::Repository(error.to_string()))?;
+        let mut writer = BufWriter::new(file);
+
+        serde_json::to_writer_pretty(&mut writer, instances)
+            .map_err(|error| BatchError::Repository(error.to_string()))?;
+        writer
+            .flush()
+            .map_err(|error| BatchError::Repository(error.to_string()))?;
+
+        fs::rename(&temp_path, &self.path)
+            .map_err(|error| BatchError::Repository(error.to_string()))
+    }
+}
+
+impl JobRepository for JsonFileJobRepository {
+    fn save_step_execution(
+        &self,
+        instance: &JobInstanceKey,
+        record: &StepRecord,
+    ) -> RepositoryResult<()> {
+        let mut instances = self.load()?;
+        upsert(instances.entry(instance.id()).or_default(), record);
+        self.store(&instances)
+    }
+
+    fn load_step_execution(
+        &self,
+        instance: &JobInstanceKey,
+        step_name: &str,
+    ) -> RepositoryResult<Option<StepRecord>> {
+        let instances = self.load()?;
+        let record = instances
+            .get(&instance.id())
+            .and_then(|records| records.iter().find(|record| record.step_name == step_name));
+
+        Ok(record.cloned())
+    }
+
+    fn job_instance(&self, instance: &JobInstanceKey) -> RepositoryResult<Option<Vec<StepRecord>>> {
+        Ok(self.load()?.remove(&instance.id()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use tempfile::tempdir;
+
+    use crate::core::step::StepStatus;
+
+    use super::{
+        InMemoryJobRepository, JobInstanceKey, JobRepository, JsonFileJobRepository, StepRecord,
+    };
+
+    fn record(step_name: &str, status: StepStatus) -> StepRecord {
+        StepRecord {
+            step_name: step_name.to_string(),
+            status,
+            read_count: 3,
+            write_count: 2,
+            filter_count: 0,
+            read_error_count: 0,
+            process_error_count: 1,
+            write_error_count: 0,
+        }
+    }
+
+    fn check_repository(repository: &dyn JobRepository) {
+        let instance = JobInstanceKey::new(
+            "import",
+            BTreeMap::from([("date".to_string(), "2024-01-01".to_string())]),
+        );
+        let other = JobInstanceKey::new("import", BTreeMap::new());
+
+        assert_eq!(repository.job_instance(&instance).unwrap(), None);
+
+        repository
+            .save_step_execution(&instance, &record("load", StepStatus::WriteError))
+            .unwrap();
+        repository
+            .save_step_execution(&instance, &record("load", StepStatus::Success))
+            .unwrap();
+
+        assert_eq!(
+            repository.load_step_execution(&instance, "load").unwrap(),
+            Some(record("load", StepStatus::Success))
+        );
+        assert_eq!(
+            repository.job_instance(&instance).unwrap().unwrap().len(),
+            1
+        );
+        assert_eq!(
+            repository.load_step_execution(&other, "load").unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn job_instance_id_should_include_sorted_parameters() {
+        let instance = JobInstanceKey::new(
+            "import",
+            BTreeMap::from([
+                ("region".to_string(), "String:eu".to_string()),
+                ("date".to_string(), "String:2024-01-01".to_string()),
+            ]),
+        );
+
+        assert_eq!(
+            instance.id(),
+            r#"["import",{"date":"String:2024-01-01","region":"String:eu"}]"#
+        );
+    }
+
+    #[test]
+    fn job_instance_ids_should_not_collide_on_separators() {
+        let joined = JobInstanceKey::new(
+            "import",
+            BTreeMap::from([("a".to_string(), "1,b=2".to_string())]),
+        );
+        let split = JobInstanceKey::new(
+            "import",
+            BTreeMap::from([
+                ("a".to_string(), "1".to_string()),
+                ("b".to_string(), "2".to_string()),
+            ]),
+        );
+
+        assert_ne!(joined.id(), split.id());
+    }
+
+    #[test]
+    fn in_memory_repository_should_replace_step_executions() {
+        check_repository(&InMemoryJobRepository::new());
+    }
+
+    #[test]
+    fn json_file_repository_should_persist_step_executions() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("repository.json");
+
+        check_repository(&JsonFileJobRepository::new(&path));
+
+        let reopened = JsonFileJobRepository::new(&path);
+        let instance = JobInstanceKey::new(
+            "import",
+            BTreeMap::from([("date".to_string(), "2024-01-01".to_string())]),
+        );
+        assert!(reopened
+            .load_step_execution(&instance, "load")
+            .unwrap()
+            .is_some());
+    }
+}
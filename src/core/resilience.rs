@@ -0,0 +1,190 @@
+use std::{thread, time::Duration};
+
+use log::warn;
+
+use crate::BatchError;
+
+/// Defines how long to wait between two attempts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Backoff {
+    /// Retries immediately.
+    None,
+    /// Waits the same delay between every attempt.
+    Fixed(Duration),
+    /// Waits the given delay after the first failure and doubles it after each new failure.
+    Exponential(Duration),
+}
+
+impl Backoff {
+    /// Returns the delay to wait after the given failed attempt (starting at 1).
+    pub fn delay(&self, attempt: usize) -> Duration {
+        match self {
+            Backoff::None => Duration::ZERO,
+            Backoff::Fixed(delay) => *delay,
+            Backoff::Exponential(initial) => {
+                let exponent = attempt.saturating_sub(1).min(31) as u32;
+                initial.saturating_mul(2u32.pow(exponent))
+            }
+        }
+    }
+}
+
+/// Describes how many times and how often an operation is attempted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one.
+    pub attempts: usize,
+    /// The delay between two attempts.
+    pub backoff: Backoff,
+}
+
+impl RetryPolicy {
+    /// Creates a new `RetryPolicy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempts` - The maximum number of attempts, including the first one.
+    /// * `backoff` - The delay between two attempts.
+    pub fn new(attempts: usize, backoff: Backoff) -> Self {
+        Self { attempts, backoff }
+    }
+
+    /// Runs the operation according to the policy.
+    ///
+    /// See [`retry`].
+    pub fn run<T, F>(&self, operation: F) -> Result<T, BatchError>
+    where
+        F: FnMut() -> Result<T, BatchError>,
+    {
+        retry(self.attempts, self.backoff, operation)
+    }
+}
+
+impl Default for RetryPolicy {
+    /// A policy attempting the operation only once.
+    fn default() -> Self {
+        Self::new(1, Backoff::None)
+    }
+}
+
+/// Runs an operation until it succeeds or the number of attempts is exhausted.
+///
+/// An `attempts` value of 0 is treated as 1: the operation always runs at least once.
+///
+/// # Arguments
+///
+/// * `attempts` - The maximum number of attempts, including the first one.
+/// * `backoff` - The delay between two attempts.
+/// * `operation` - The operation to run.
+///
+/// # Returns
+///
+/// The result of the first successful attempt, or the error of the last attempt.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::{core::resilience::{retry, Backoff}, BatchError};
+///
+/// let mut calls = 0;
+/// let result = retry(3, Backoff::None, || {
+///     calls += 1;
+///     if calls < 3 {
+///         Err(BatchError::Step("transient error".to_string()))
+///     } else {
+///         Ok(calls)
+///     }
+/// });
+///
+/// assert_eq!(result.unwrap(), 3);
+/// ```
+pub fn retry<T, F>(attempts: usize, backoff: Backoff, mut operation: F) -> Result<T, BatchError>
+where
+    F: FnMut() -> Result<T, BatchError>,
+{
+    let attempts = attempts.max(1);
+    let mut attempt = 1;
+
+    loop {
+        match operation() {
+            Ok(value) => return Ok(value),
+            Err(error) if attempt < attempts => {
+                let delay = backoff.delay(attempt);
+                warn!(
+                    "Attempt {}/{} failed: {}, retrying in {:?}",
+                    attempt, attempts, error, delay
+                );
+                if !delay.is_zero() {
+                    thread::sleep(delay);
+                }
+                attempt += 1;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{retry, Backoff, RetryPolicy};
+    use crate::BatchError;
+
+    fn fail_twice(calls: &mut usize) -> Result<usize, BatchError> {
+        *calls += 1;
+        if *calls <= 2 {
+            Err(BatchError::Step(format!("failure {}", calls)))
+        } else {
+            Ok(*calls)
+        }
+    }
+
+    #[test]
+    fn operation_should_succeed_after_two_failures() {
+        let mut calls = 0;
+
+        let result = retry(3, Backoff::Fixed(Duration::from_millis(1)), || {
+            fail_twice(&mut calls)
+        });
+
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn last_error_should_be_returned_when_attempts_are_exhausted() {
+        let mut calls = 0;
+
+        let result = RetryPolicy::new(2, Backoff::None).run(|| fail_twice(&mut calls));
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Error occurred in the step: failure 2"
+        );
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn operation_should_run_once_with_zero_attempts() {
+        let mut calls = 0;
+
+        let result = retry(0, Backoff::None, || fail_twice(&mut calls));
+
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn backoff_should_compute_delays() {
+        let delay = Duration::from_millis(10);
+
+        assert_eq!(Backoff::None.delay(3), Duration::ZERO);
+        assert_eq!(Backoff::Fixed(delay).delay(3), delay);
+        assert_eq!(Backoff::Exponential(delay).delay(1), delay);
+        assert_eq!(
+            Backoff::Exponential(delay).delay(3),
+            Duration::from_millis(40)
+        );
+    }
+}
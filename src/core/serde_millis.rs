@@ -0,0 +1,107 @@
+//! Serde helpers writing durations and system times as milliseconds, for the
+//! executions of jobs and steps.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::BatchError;
+
+/// Writes a `Duration` as a number of milliseconds, with a fractional part.
+pub(crate) mod duration {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        duration: &Duration,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Duration, D::Error> {
+        let millis = f64::deserialize(deserializer)?;
+        Duration::try_from_secs_f64(millis / 1000.0).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Writes a list of `Duration`s as numbers of milliseconds.
+pub(crate) mod durations {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        durations: &[Duration],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        durations
+            .iter()
+            .map(|duration| duration.as_secs_f64() * 1000.0)
+            .collect::<Vec<_>>()
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Duration>, D::Error> {
+        Vec::<f64>::deserialize(deserializer)?
+            .into_iter()
+            .map(|millis| {
+                Duration::try_from_secs_f64(millis / 1000.0).map_err(serde::de::Error::custom)
+            })
+            .collect()
+    }
+}
+
+/// Writes a `SystemTime` as a number of milliseconds since the Unix epoch.
+pub(crate) mod system_time {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        time: &SystemTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        serializer.serialize_u64(u64::try_from(since_epoch.as_millis()).unwrap_or(u64::MAX))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<SystemTime, D::Error> {
+        let millis = u64::deserialize(deserializer)?;
+        Ok(UNIX_EPOCH + Duration::from_millis(millis))
+    }
+}
+
+/// Writes errors as their messages. They are not read back, as errors cannot be rebuilt
+/// from their messages.
+pub(crate) fn messages<S: Serializer>(
+    errors: &[BatchError],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .serialize(serializer)
+}
+
+/// Writes an optional error as its message.
+pub(crate) fn message<S: Serializer>(
+    error: &Option<BatchError>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    error
+        .as_ref()
+        .map(ToString::to_string)
+        .serialize(serializer)
+}
+
+/// Computes the wall-clock times at which an execution which lasted `duration` started
+/// and ended, the end being now.
+pub(crate) fn wall_clock(duration: Duration) -> (SystemTime, SystemTime) {
+    let ended_at = SystemTime::now();
+    let started_at = ended_at.checked_sub(duration).unwrap_or(UNIX_EPOCH);
+
+    (started_at, ended_at)
+}
@@ -1,14 +1,19 @@
 use crate::BatchError;
-use log::{debug, info, warn};
+use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
 use std::{
-    cell::Cell,
-    time::{Duration, Instant},
+    cell::{Cell, OnceCell, RefCell},
+    time::{Duration, Instant, SystemTime},
 };
+use thiserror::Error;
 use uuid::Uuid;
 
 use super::{
     build_name,
-    item::{DefaultProcessor, ItemProcessor, ItemReader, ItemWriter},
+    item::{DefaultProcessor, ItemProcessor, ItemReader, ItemReaderResult, ItemWriter},
+    parameters::JobParameters,
+    resilience::{Backoff, RetryPolicy},
+    serde_millis,
 };
 
 type StepResult<T> = Result<T, T>;
@@ -20,6 +25,8 @@ pub trait Step {
     ///
     /// Returns a `StepResult` containing the execution details if the step is successful,
     /// or an error if the step fails.
+    // The execution details are returned in both cases, so boxing the error would not help.
+    #[allow(clippy::result_large_err)]
     fn execute(&self) -> StepResult<StepExecution>;
 
     /// Gets the status of the step.
@@ -32,6 +39,27 @@ pub trait Step {
     /// Returns a reference to the name of the step.
     fn get_name(&self) -> &String;
 
+    /// Sets the name used when the step has not been explicitly named.
+    ///
+    /// It has no effect if the step already has a name. The job calls it
+    /// with the name generated by its `NamingStrategy`.
+    fn set_default_name(&self, _name: String) {}
+
+    /// Sets the parameters of the job running the step.
+    ///
+    /// The job calls it before running its steps. The step exposes them in its `StepExecution`.
+    fn set_parameters(&self, _parameters: &JobParameters) {}
+
+    /// Makes the step run without writing, as set by `StepBuilder::dry_run`.
+    ///
+    /// The job calls it around each run of its steps when it is built with `JobBuilder::dry_run`.
+    fn set_dry_run(&self, _dry_run: bool) {}
+
+    /// Tells whether the step runs without writing.
+    fn is_dry_run(&self) -> bool {
+        false
+    }
+
     /// Gets the ID of the step.
     ///
     /// Returns the UUID representing the ID of the step.
@@ -56,6 +84,73 @@ pub trait Step {
     ///
     /// Returns the count of write errors encountered by the step.
     fn get_write_error_count(&self) -> usize;
+
+    /// Gets the number of items filtered by the processor.
+    ///
+    /// Returns the count of items for which the processor returned `None`,
+    /// or 0 if the step does not filter items.
+    fn get_filter_count(&self) -> usize {
+        0
+    }
+
+    /// Gets the number of retried operations.
+    ///
+    /// Returns the number of reader, processor and writer calls that have been retried,
+    /// or 0 if the step does not retry them.
+    fn get_retry_count(&self) -> usize {
+        0
+    }
+
+    /// Runs the step on its own, as it would run in a one-step job.
+    ///
+    /// Returns the execution of the step, or a `StepError` holding a `BatchError::Step`
+    /// and the partial execution if the step fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spring_batch_rs::core::item::{ItemProcessor, ItemReader, VecItemWriter};
+    /// use spring_batch_rs::core::step::{Step, StepBuilder, StepInstance};
+    /// use spring_batch_rs::BatchError;
+    /// # use std::cell::RefCell;
+    /// # struct Numbers(RefCell<Vec<u32>>);
+    /// # impl ItemReader<u32> for Numbers {
+    /// #     fn read(&self) -> Result<Option<u32>, BatchError> {
+    /// #         Ok(self.0.borrow_mut().pop())
+    /// #     }
+    /// # }
+    ///
+    /// let reader = Numbers(RefCell::new(vec![1, 2, 3]));
+    /// let writer = VecItemWriter::new();
+    ///
+    /// let step: StepInstance<u32, u32> = StepBuilder::new()
+    ///     .reader(&reader)
+    ///     .writer(&writer)
+    ///     .chunk(2)
+    ///     .build();
+    ///
+    /// let execution = step.run().unwrap();
+    /// assert_eq!(execution.write_count, 3);
+    /// ```
+    fn run(&self) -> Result<StepExecution, StepError> {
+        self.execute().map_err(|execution| {
+            error!("Step: {} failed", self.get_name());
+            StepError {
+                error: BatchError::Step(self.get_name().to_owned()),
+                execution: Box::new(execution),
+            }
+        })
+    }
+}
+
+/// Error returned when a step run on its own fails.
+#[derive(Error, Debug)]
+#[error("{error}")]
+pub struct StepError {
+    /// The error which made the step fail.
+    pub error: BatchError,
+    /// The partial execution of the step.
+    pub execution: Box<StepExecution>,
 }
 
 /// Represents the status of a chunk.
@@ -68,7 +163,7 @@ pub enum ChunkStatus {
 }
 
 /// Represents the status of a step.
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum StepStatus {
     /// The step executed successfully.
     Success,
@@ -78,100 +173,524 @@ pub enum StepStatus {
     ProcessorError,
     /// An error occurred during the write operation.
     WriteError,
+    /// The step ran longer than its timeout and was stopped between two chunks.
+    TimedOut,
     /// The step is starting.
     Starting,
 }
 
 /// Represents the execution details of a step.
-#[derive(Debug)]
+///
+/// It serializes with its wall-clock times and durations in milliseconds, and its
+/// warnings and first error as messages. The monotonic `start` and `end` instants are
+/// not serialized, and are set to the time of deserialization. The warnings and the
+/// first error are not read back, and are left empty.
+#[derive(Debug, Serialize, Deserialize)]
 pub struct StepExecution {
-    /// The start time of the step execution.
+    /// The start time of the step execution, to measure elapsed times.
+    #[serde(skip, default = "Instant::now")]
     pub start: Instant,
-    /// The end time of the step execution.
+    /// The end time of the step execution, to measure elapsed times.
+    #[serde(skip, default = "Instant::now")]
     pub end: Instant,
+    /// The wall-clock time at which the step started.
+    #[serde(with = "serde_millis::system_time")]
+    pub started_at: SystemTime,
+    /// The wall-clock time at which the step ended.
+    #[serde(with = "serde_millis::system_time")]
+    pub ended_at: SystemTime,
     /// The duration of the step execution.
+    #[serde(rename = "duration_ms", with = "serde_millis::duration")]
     pub duration: Duration,
+    /// The number of items read.
+    pub read_count: usize,
+    /// The number of items returned by the processor, to be written.
+    pub process_count: usize,
+    /// The number of items written.
+    pub write_count: usize,
+    /// The number of items filtered by the processor.
+    pub filter_count: usize,
+    /// The number of read errors.
+    pub read_error_count: usize,
+    /// The number of process errors.
+    pub process_error_count: usize,
+    /// The number of write errors.
+    pub write_error_count: usize,
+    /// The number of retried reader, processor and writer calls.
+    pub retry_count: usize,
+    /// The non-fatal errors which did not stop the step, such as writer open, flush or close failures.
+    #[serde(serialize_with = "serde_millis::messages", skip_deserializing)]
+    pub warnings: Vec<BatchError>,
+    /// The first error which made the step fail, if it failed on a read, process or write error.
+    #[serde(serialize_with = "serde_millis::message", skip_deserializing)]
+    pub first_error: Option<BatchError>,
+    /// The parameters of the job running the step.
+    pub parameters: JobParameters,
+    /// The time spent processing and writing the chunks.
+    pub chunk_statistics: ChunkStatistics,
+    /// The total number of items expected, as set by `StepBuilder::total_hint` or
+    /// reported by the reader, if known.
+    pub total_hint: Option<usize>,
+}
+
+impl StepExecution {
+    /// Returns the percentage of the expected items which have been read, between 0 and 100,
+    /// or `None` if the total is unknown.
+    pub fn percentage(&self) -> Option<f64> {
+        self.total_hint.map(|total| match total {
+            0 => 100.0,
+            total => (self.read_count as f64 * 100.0 / total as f64).min(100.0),
+        })
+    }
+}
+
+/// Statistics on the time spent processing and writing each chunk of a step.
+///
+/// Empty chunks, such as the last one when the item count is a multiple of the
+/// chunk size, are not counted.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChunkStatistics {
+    /// The number of timed chunks.
+    pub count: usize,
+    /// The total time spent on the chunks.
+    #[serde(rename = "total_ms", with = "serde_millis::duration")]
+    pub total: Duration,
+    /// The duration of the fastest chunk.
+    #[serde(rename = "min_ms", with = "serde_millis::duration")]
+    pub min: Duration,
+    /// The duration of the slowest chunk.
+    #[serde(rename = "max_ms", with = "serde_millis::duration")]
+    pub max: Duration,
+    /// The duration of every chunk, in order. Only filled when the step records them,
+    /// to keep memory bounded on long steps.
+    #[serde(rename = "durations_ms", with = "serde_millis::durations")]
+    pub durations: Vec<Duration>,
+}
+
+impl ChunkStatistics {
+    /// Records the duration of a chunk.
+    pub(crate) fn record(&mut self, duration: Duration, keep_duration: bool) {
+        self.min = if self.count == 0 {
+            duration
+        } else {
+            self.min.min(duration)
+        };
+        self.max = self.max.max(duration);
+        self.total += duration;
+        self.count += 1;
+
+        if keep_duration {
+            self.durations.push(duration);
+        }
+    }
+
+    /// Returns the mean duration of the chunks.
+    pub fn mean(&self) -> Duration {
+        if self.count == 0 {
+            return Duration::ZERO;
+        }
+
+        self.total.div_f64(self.count as f64)
+    }
+
+    /// Returns the duration under which the given percentage of chunks ran, such as
+    /// 50.0 for the median or 99.0 for the 99th percentile.
+    ///
+    /// Returns `None` when the durations of the chunks have not been recorded.
+    pub fn percentile(&self, percentage: f64) -> Option<Duration> {
+        if self.durations.is_empty() {
+            return None;
+        }
+
+        let mut durations = self.durations.clone();
+        durations.sort();
+
+        let rank = (percentage.clamp(0.0, 100.0) / 100.0 * durations.len() as f64).ceil();
+        let index = (rank as usize).clamp(1, durations.len()) - 1;
+
+        Some(durations[index])
+    }
+}
+
+/// Adapts the chunk size between chunks to keep the write latency near a target.
+///
+/// The chunk size follows an additive-increase/multiplicative-decrease rule: it grows
+/// by one item after each chunk written faster than the target, and is halved after each
+/// chunk written slower than the target. It always stays within `min` and `max`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveChunkPolicy {
+    /// The target duration to write and flush a chunk.
+    pub target: Duration,
+    /// The minimum chunk size.
+    pub min: usize,
+    /// The maximum chunk size.
+    pub max: usize,
+}
+
+impl AdaptiveChunkPolicy {
+    /// Computes the size of the next chunk from the size and the write latency of the last one.
+    pub fn next_size(&self, size: usize, latency: Duration) -> usize {
+        let size = if latency > self.target {
+            size / 2
+        } else {
+            size + 1
+        };
+
+        self.clamp(size)
+    }
+
+    /// Bounds a chunk size within `min` and `max`.
+    fn clamp(&self, size: usize) -> usize {
+        size.clamp(self.min.max(1), self.max.max(self.min).max(1))
+    }
+}
+
+/// Listener of the lifecycle of a step and of its chunks.
+///
+/// All methods do nothing by default, so a listener only implements the events it needs,
+/// for example to report per-chunk metrics.
+pub trait StepListener {
+    /// Called before the writer of the step is opened.
+    fn before_step(&self, _execution: &StepExecution) {}
+
+    /// Called after the writer of the step is closed, with the final execution of the step.
+    fn after_step(&self, _execution: &StepExecution) {}
+
+    /// Called before a chunk is read.
+    fn before_chunk(&self, _execution: &StepExecution) {}
+
+    /// Called after a chunk has gone through the write stage, whether the write succeeded or not.
+    fn after_chunk(&self, _execution: &StepExecution) {}
+
+    /// Called when a read, process or write error is skipped because the skip limit
+    /// has not been reached.
+    fn on_skip(&self, _error: &BatchError) {}
+}
+
+/// Decides whether a step skips an error or fails.
+///
+/// The policy is consulted for every read, process and write error, after the retries
+/// of the processor and the writer. A policy can for example skip malformed records while
+/// failing on the first connection error.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::{core::step::SkipPolicy, BatchError};
+///
+/// /// Skips up to 100 malformed records, and fails on any other error.
+/// struct SkipMalformedRecords;
+///
+/// impl SkipPolicy for SkipMalformedRecords {
+///     fn should_skip(&self, error: &BatchError, error_count: usize) -> bool {
+///         matches!(error, BatchError::Deserialization { .. }) && error_count <= 100
+///     }
+/// }
+/// ```
+pub trait SkipPolicy {
+    /// Returns `true` if the step must skip the error and go on.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error to skip or not.
+    /// * `error_count` - The number of read, process and write errors of the step,
+    ///   including this one. A failed write counts one error per item of the chunk.
+    fn should_skip(&self, error: &BatchError, error_count: usize) -> bool;
+}
+
+/// Hook invoked by a step on each processed chunk, just before it is written.
+///
+/// It suits final, chunk-aware changes depending on the state of the writer, such as
+/// assigning sequence numbers, which keeps the processor pure. It is not invoked for empty
+/// chunks nor in dry runs, and it is invoked once per chunk even if the write is retried.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use spring_batch_rs::core::step::WriteInterceptor;
+///
+/// /// Numbers the rows across chunks.
+/// struct Sequencer {
+///     next: Cell<u64>,
+/// }
+///
+/// impl WriteInterceptor<(u64, String)> for Sequencer {
+///     fn before_write(&self, items: &mut Vec<(u64, String)>) {
+///         for (sequence, _) in items.iter_mut() {
+///             *sequence = self.next.replace(self.next.get() + 1);
+///         }
+///     }
+/// }
+/// ```
+pub trait WriteInterceptor<W> {
+    /// Changes the processed items of a chunk before they are written.
+    fn before_write(&self, items: &mut Vec<W>);
+}
+
+/// The default skip policy of a step, skipping any error up to a number of errors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LimitSkipPolicy {
+    skip_limit: usize,
+}
+
+impl LimitSkipPolicy {
+    /// Creates a `LimitSkipPolicy` skipping up to `skip_limit` errors.
+    pub fn new(skip_limit: usize) -> Self {
+        Self { skip_limit }
+    }
+}
+
+impl SkipPolicy for LimitSkipPolicy {
+    fn should_skip(&self, _error: &BatchError, error_count: usize) -> bool {
+        error_count <= self.skip_limit
+    }
+}
+
+/// Callback invoked with the running step execution after each committed chunk.
+type ChunkCommitCallback<'a> = dyn Fn(&StepExecution) + 'a;
+
+/// Callback invoked after each chunk with the items read and the expected total, if known.
+type ProgressCallback<'a> = dyn Fn(usize, Option<usize>) + 'a;
+
+/// Callback invoked with an item the processor still fails on after its retries.
+type RetryExhaustedCallback<'a, R> = dyn Fn(&R, &BatchError) + 'a;
+
+/// An item skipped by a step, as written to its skip writer.
+///
+/// It serializes as an `error` field and a nested `item` field, which suits writers
+/// of structured formats such as JSON.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SkippedItem<I> {
+    /// The error which caused the item to be skipped.
+    pub error: String,
+    /// The item the processor failed on, or `None` for a read error, as the reader
+    /// produced no item.
+    pub item: Option<I>,
+}
+
+/// The writer receiving the skipped items of a step, with the function cloning them.
+struct SkipWriter<'a, R> {
+    writer: &'a dyn ItemWriter<SkippedItem<R>>,
+    clone: fn(&R) -> R,
 }
 
 /// Represents an instance of a step in a batch job.
 pub struct StepInstance<'a, R, W> {
     id: Uuid,
-    name: String,
+    name: OnceCell<String>,
     status: Cell<StepStatus>,
     reader: &'a dyn ItemReader<R>,
     processor: &'a dyn ItemProcessor<R, W>,
     writer: &'a dyn ItemWriter<W>,
-    chunk_size: usize,
+    chunk_size: Cell<usize>,
+    adaptive_chunk: Option<AdaptiveChunkPolicy>,
     skip_limit: usize,
+    skip_policy: Option<&'a dyn SkipPolicy>,
+    write_interceptor: Option<&'a dyn WriteInterceptor<W>>,
+    retry_policy: RetryPolicy,
+    retry_count: Cell<usize>,
     read_count: Cell<usize>,
+    process_count: Cell<usize>,
     write_count: Cell<usize>,
     read_error_count: Cell<usize>,
     process_error_count: Cell<usize>,
     write_error_count: Cell<usize>,
+    filter_count: Cell<usize>,
+    warnings: RefCell<Vec<BatchError>>,
+    first_error: RefCell<Option<BatchError>>,
+    on_chunk_commit: Option<Box<ChunkCommitCallback<'a>>>,
+    on_retry_exhausted: Option<Box<RetryExhaustedCallback<'a, R>>>,
+    progress: Option<Box<ProgressCallback<'a>>>,
+    total_hint: Option<usize>,
+    listeners: Vec<&'a dyn StepListener>,
+    skip_writer: Option<SkipWriter<'a, R>>,
+    timeout: Option<Duration>,
+    flush_interval: Option<usize>,
+    fail_on_open_error: bool,
+    fail_on_close_error: bool,
+    unflushed: Cell<usize>,
+    uncommitted: Cell<bool>,
+    dry_run: Cell<bool>,
+    parameters: RefCell<JobParameters>,
+    chunk_statistics: RefCell<ChunkStatistics>,
+    record_chunk_durations: bool,
+    start: Cell<Instant>,
 }
 
 impl<'a, R, W> Step for StepInstance<'a, R, W> {
     fn execute(&self) -> StepResult<StepExecution> {
         // Start the timer
         let start = Instant::now();
+        self.start.set(start);
+        self.reset();
 
         // Log the start of the step
-        info!("Start of step: {}, id: {}", self.name, self.id);
+        info!("Start of step: {}, id: {}", self.get_name(), self.id);
+        #[cfg(feature = "tracing")]
+        let step_span = tracing::info_span!(
+            "step",
+            name = %self.get_name(),
+            id = %self.id,
+            read_count = tracing::field::Empty,
+            write_count = tracing::field::Empty,
+        )
+        .entered();
+        self.notify(|listener, execution| listener.before_step(execution));
+
+        // Open the writer and handle any errors, unless nothing is written
+        let writer_opened = self.dry_run.get() || self.open_writer();
+        if !self.dry_run.get() {
+            if let Some(skip_writer) = &self.skip_writer {
+                self.manage_error(skip_writer.writer.open());
+            }
+        }
 
-        // Open the writer and handle any errors
-        Self::manage_error(self.writer.open());
+        // Open the reader, without which the step cannot read anything
+        let reader_opened = writer_opened
+            && match self.reader.open() {
+                Ok(()) => true,
+                Err(error) => {
+                    error!(
+                        "Unable to open reader of step {}: {}",
+                        self.get_name(),
+                        error
+                    );
+                    self.inc_read_error_count();
+                    self.set_status(StepStatus::ReadError);
+                    self.record_failure(error);
+                    false
+                }
+            };
 
         // Create a vector to store the read items
-        let mut read_items: Vec<R> = Vec::with_capacity(self.chunk_size);
+        let mut read_items: Vec<R> = Vec::with_capacity(self.chunk_size.get());
 
         // Loop until the chunk is finished or an error occurs
-        loop {
-            // Read a chunk of items
-            let read_chunk_result = self.read_chunk(&mut read_items);
+        if reader_opened {
+            loop {
+                #[cfg(feature = "tracing")]
+                let chunk_span = tracing::debug_span!(
+                    "chunk",
+                    read_count = tracing::field::Empty,
+                    write_count = tracing::field::Empty,
+                )
+                .entered();
+                #[cfg(feature = "tracing")]
+                let (read_count, write_count) = (self.read_count.get(), self.write_count.get());
+
+                self.notify(|listener, execution| listener.before_chunk(execution));
+
+                // Read a chunk of items
+                let read_chunk_result = self.read_chunk(&mut read_items);
+
+                // Handle read errors
+                if read_chunk_result.is_err() {
+                    self.set_status(StepStatus::ReadError);
+                    break;
+                }
 
-            // Handle read errors
-            if read_chunk_result.is_err() {
-                self.set_status(StepStatus::ReadError);
-                break;
-            }
+                // Process the chunk of items
+                let chunk_start = Instant::now();
+                let processor_chunk_result = self.process_chunk(&read_items);
 
-            // Process the chunk of items
-            let processor_chunk_result = self.process_chunk(&read_items);
+                // Handle processing errors
+                if processor_chunk_result.is_err() {
+                    self.set_status(StepStatus::ProcessorError);
+                    break;
+                }
 
-            // Handle processing errors
-            if processor_chunk_result.is_err() {
-                self.set_status(StepStatus::ProcessorError);
-                break;
-            }
+                // Write the processed items
+                let write_chunk_result = self.write_chunk(&mut processor_chunk_result.unwrap());
+                if !read_items.is_empty() {
+                    self.chunk_statistics
+                        .borrow_mut()
+                        .record(chunk_start.elapsed(), self.record_chunk_durations);
+                }
+                self.notify(|listener, execution| listener.after_chunk(execution));
+                if let Some(progress) = &self.progress {
+                    let total_hint = self.total_hint.or_else(|| self.reader.total_hint());
+                    progress(self.read_count.get(), total_hint);
+                }
+                #[cfg(feature = "tracing")]
+                {
+                    chunk_span.record("read_count", self.read_count.get() - read_count);
+                    chunk_span.record("write_count", self.write_count.get() - write_count);
+                }
 
-            // Write the processed items
-            let write_chunk_result = self.write_chunk(&processor_chunk_result.unwrap());
+                // Handle write errors
+                if write_chunk_result.is_err() {
+                    self.set_status(StepStatus::WriteError);
+                    break;
+                }
 
-            // Handle write errors
-            if write_chunk_result.is_err() {
-                self.set_status(StepStatus::WriteError);
-                break;
-            }
+                // Stop cleanly if the destination no longer accepts items
+                if !self.dry_run.get() && self.writer.is_closed() {
+                    info!(
+                        "Destination of step {} is closed, stopping",
+                        self.get_name()
+                    );
+                    self.set_status(StepStatus::Success);
+                    break;
+                }
+
+                // Check if the chunk is finished
+                if read_chunk_result.unwrap() == ChunkStatus::Finished {
+                    self.set_status(StepStatus::Success);
+                    break;
+                }
 
-            // Check if the chunk is finished
-            if read_chunk_result.unwrap() == ChunkStatus::Finished {
-                self.set_status(StepStatus::Success);
-                break;
+                // Stop if the step has run for too long
+                if self
+                    .timeout
+                    .is_some_and(|timeout| start.elapsed() > timeout)
+                {
+                    warn!(
+                        "Step {} timed out after {:?}",
+                        self.get_name(),
+                        start.elapsed()
+                    );
+                    self.set_status(StepStatus::TimedOut);
+                    break;
+                }
             }
         }
 
-        // Close the writer and handle any errors
-        Self::manage_error(self.writer.close());
+        // Flush the items written since the last flush, when flushes are spaced out
+        if self.unflushed.get() > 0
+            && self.flush_writer().is_err()
+            && self.status.get() == StepStatus::Success
+        {
+            self.set_status(StepStatus::WriteError);
+        }
+
+        // Close the reader and the writer and handle any errors
+        if reader_opened {
+            self.manage_error(self.reader.close());
+        }
+        if !self.dry_run.get() {
+            if writer_opened {
+                self.close_writer();
+            }
+            if let Some(skip_writer) = &self.skip_writer {
+                self.manage_error(skip_writer.writer.close());
+            }
+        }
 
         // Log the end of the step
-        info!("End of step: {}, id: {}", self.name, self.id);
+        info!("End of step: {}, id: {}", self.get_name(), self.id);
+        #[cfg(feature = "tracing")]
+        {
+            step_span.record("read_count", self.read_count.get());
+            step_span.record("write_count", self.write_count.get());
+        }
 
         // Calculate the step execution details
-        let step_execution = StepExecution {
-            start,
-            end: Instant::now(),
-            duration: start.elapsed(),
-        };
+        let step_execution = self.execution();
+        for listener in &self.listeners {
+            listener.after_step(&step_execution);
+        }
 
         // Return the step execution details if the step is successful,
         // or an error if the step failed
@@ -187,7 +706,24 @@ impl<'a, R, W> Step for StepInstance<'a, R, W> {
     }
 
     fn get_name(&self) -> &String {
-        &self.name
+        self.name.get_or_init(build_name)
+    }
+
+    fn set_default_name(&self, name: String) {
+        // Ignored when the step is already named
+        let _ = self.name.set(name);
+    }
+
+    fn set_parameters(&self, parameters: &JobParameters) {
+        self.parameters.replace(parameters.clone());
+    }
+
+    fn set_dry_run(&self, dry_run: bool) {
+        self.dry_run.set(dry_run);
+    }
+
+    fn is_dry_run(&self) -> bool {
+        self.dry_run.get()
     }
 
     fn get_id(&self) -> Uuid {
@@ -209,6 +745,14 @@ impl<'a, R, W> Step for StepInstance<'a, R, W> {
     fn get_write_error_count(&self) -> usize {
         self.write_error_count.get()
     }
+
+    fn get_filter_count(&self) -> usize {
+        self.filter_count.get()
+    }
+
+    fn get_retry_count(&self) -> usize {
+        self.retry_count.get()
+    }
 }
 
 /// Represents an instance of a step in a batch job.
@@ -222,12 +766,145 @@ impl<'a, R, W> StepInstance<'a, R, W> {
         self.status.set(status);
     }
 
-    /// Checks if the skip limit for the step instance has been reached.
+    /// Resets the status and the counters of the step, which may run several times.
+    fn reset(&self) {
+        self.set_status(StepStatus::Starting);
+        for count in [
+            &self.read_count,
+            &self.process_count,
+            &self.write_count,
+            &self.filter_count,
+            &self.read_error_count,
+            &self.process_error_count,
+            &self.write_error_count,
+            &self.retry_count,
+            &self.unflushed,
+        ] {
+            count.set(0);
+        }
+        self.uncommitted.set(false);
+        self.warnings.borrow_mut().clear();
+        self.first_error.replace(None);
+        self.chunk_statistics.replace(ChunkStatistics::default());
+    }
+
+    /// Builds the execution details of the step from its current state.
+    fn execution(&self) -> StepExecution {
+        let start = self.start.get();
+        let duration = start.elapsed();
+        let (started_at, ended_at) = serde_millis::wall_clock(duration);
+
+        StepExecution {
+            start,
+            end: Instant::now(),
+            started_at,
+            ended_at,
+            duration,
+            read_count: self.read_count.get(),
+            process_count: self.process_count.get(),
+            write_count: self.write_count.get(),
+            filter_count: self.filter_count.get(),
+            read_error_count: self.read_error_count.get(),
+            process_error_count: self.process_error_count.get(),
+            write_error_count: self.write_error_count.get(),
+            retry_count: self.retry_count.get(),
+            warnings: self.warnings.borrow().clone(),
+            first_error: self.first_error.borrow().clone(),
+            parameters: self.parameters.borrow().clone(),
+            chunk_statistics: self.chunk_statistics.borrow().clone(),
+            total_hint: self.total_hint.or_else(|| self.reader.total_hint()),
+        }
+    }
+
+    /// Opens the writer.
+    ///
+    /// Returns `false` if the writer failed to open and the step must fail, as configured
+    /// by `fail_on_open_error`. Otherwise the error is collected in the warnings.
+    fn open_writer(&self) -> bool {
+        match self.writer.open() {
+            Ok(()) => true,
+            Err(error) if self.fail_on_open_error => {
+                error!(
+                    "Unable to open writer of step {}: {}",
+                    self.get_name(),
+                    error
+                );
+                self.set_status(StepStatus::WriteError);
+                self.record_failure(error);
+                false
+            }
+            Err(error) => {
+                self.manage_error(Err(error));
+                true
+            }
+        }
+    }
+
+    /// Closes the writer.
+    ///
+    /// A failure fails the step with `StepStatus::WriteError` when configured by
+    /// `fail_on_close_error`. Otherwise the error is collected in the warnings.
+    fn close_writer(&self) {
+        match self.writer.close() {
+            Err(error) if self.fail_on_close_error => {
+                error!(
+                    "Unable to close writer of step {}: {}",
+                    self.get_name(),
+                    error
+                );
+                if self.status.get() == StepStatus::Success {
+                    self.set_status(StepStatus::WriteError);
+                }
+                self.record_failure(error);
+            }
+            result => self.manage_error(result),
+        }
+    }
+
+    /// Checks if the skip policy of the step refuses to skip an error.
+    ///
+    /// Returns `true` if the step must fail, `false` if the error is skipped.
+    fn is_skip_limit_reached(&self, error: &BatchError) -> bool {
+        let error_count = self.read_error_count.get()
+            + self.write_error_count.get()
+            + self.process_error_count.get();
+
+        let should_skip = match self.skip_policy {
+            Some(policy) => policy.should_skip(error, error_count),
+            None => LimitSkipPolicy::new(self.skip_limit).should_skip(error, error_count),
+        };
+
+        !should_skip
+    }
+
+    /// Runs an operation, retrying it according to the retry policy while it fails.
     ///
-    /// Returns `true` if the skip limit has been reached, `false` otherwise.
-    fn is_skip_limit_reached(&self) -> bool {
-        self.read_error_count.get() + self.write_error_count.get() + self.process_error_count.get()
-            > self.skip_limit
+    /// Only the result of the last attempt is returned, so a failure is counted once
+    /// against the skip limit.
+    fn retry<T>(
+        &self,
+        mut operation: impl FnMut() -> Result<T, BatchError>,
+    ) -> Result<T, BatchError> {
+        let mut attempts = 0;
+        let result = self.retry_policy.run(|| {
+            attempts += 1;
+            operation()
+        });
+
+        self.retry_count.set(self.retry_count.get() + attempts - 1);
+        result
+    }
+
+    /// Reads the next item, retrying the failed reads according to the retry policy.
+    ///
+    /// Deserialization errors are not retried, as the reader has moved past the
+    /// malformed record.
+    fn read_item(&self) -> ItemReaderResult<R> {
+        self.retry(|| match self.reader.read() {
+            Err(error @ BatchError::Deserialization { .. }) => Ok(Err(error)),
+            result => result.map(Ok),
+        })
+        .and_then(|result| result)
     }
 
     /// Reads a chunk of items from the reader.
@@ -242,7 +919,7 @@ impl<'a, R, W> StepInstance<'a, R, W> {
         read_items.clear();
 
         loop {
-            let read_result = self.reader.read();
+            let read_result = self.read_item();
 
             match read_result {
                 Ok(item) => {
@@ -258,7 +935,7 @@ impl<'a, R, W> StepInstance<'a, R, W> {
                         }
                     };
 
-                    if read_items.len() == self.chunk_size {
+                    if read_items.len() >= self.chunk_size.get() {
                         // The chunk is full, we can process and write items
                         debug!("End reading chunk: FULL");
                         return Ok(ChunkStatus::Full);
@@ -266,10 +943,13 @@ impl<'a, R, W> StepInstance<'a, R, W> {
                 }
                 Err(err) => {
                     self.inc_read_error_count();
-                    if self.is_skip_limit_reached() {
+                    if self.is_skip_limit_reached(&err) {
+                        self.record_failure(err);
                         return Err(BatchError::ItemReader("error limit reached".to_string()));
                     } else {
                         warn!("Error occurred during read item: {}", err);
+                        self.notify_skip(&err);
+                        self.write_skipped_item(None, &err);
                     }
                 }
             }
@@ -284,23 +964,43 @@ impl<'a, R, W> StepInstance<'a, R, W> {
     ///
     /// Returns a `Result` containing a vector of processed items or a `BatchError` if an error occurred.
     fn process_chunk(&self, read_items: &Vec<R>) -> Result<Vec<W>, BatchError> {
+        if let Some(result) = self.processor.process_chunk(read_items) {
+            return self.process_whole_chunk(read_items, result);
+        }
+
         let mut processed_items = Vec::with_capacity(read_items.len());
 
         debug!("Start processing chunk");
         for item in read_items {
-            let result = self.processor.process(item);
+            let result = self.retry(|| self.processor.process(item));
 
             match result {
-                Ok(item) => {
+                Ok(Some(item)) => {
                     debug!("Processing item");
+                    self.process_count.set(self.process_count.get() + 1);
                     processed_items.push(item)
                 }
+                Ok(None) => {
+                    debug!("Item filtered");
+                    self.inc_filter_count();
+                }
                 Err(err) => {
+                    // A failed call has used all its attempts
+                    if self.retry_policy.attempts > 1 {
+                        if let Some(on_retry_exhausted) = &self.on_retry_exhausted {
+                            on_retry_exhausted(item, &err);
+                        }
+                    }
+
                     self.inc_process_error_count(1);
-                    if self.is_skip_limit_reached() {
-                        return Err(BatchError::ItemProcessor(err.to_string()));
+                    if self.is_skip_limit_reached(&err) {
+                        let error = BatchError::ItemProcessor(err.to_string());
+                        self.record_failure(err);
+                        return Err(error);
                     } else {
-                        warn!("ItemProcessor error: {}", err.to_string());
+                        warn!("ItemProcessor error: {}", err);
+                        self.notify_skip(&err);
+                        self.write_skipped_item(Some(item), &err);
                     }
                 }
             };
@@ -310,49 +1010,202 @@ impl<'a, R, W> StepInstance<'a, R, W> {
         Ok(processed_items)
     }
 
+    /// Handles the result of a processor processing a whole chunk at once.
+    ///
+    /// A failure counts as a process error for each item of the chunk, which are all skipped
+    /// unless the skip policy makes the step fail.
+    fn process_whole_chunk(
+        &self,
+        read_items: &[R],
+        result: Result<Vec<W>, BatchError>,
+    ) -> Result<Vec<W>, BatchError> {
+        match result {
+            Ok(processed_items) => {
+                debug!("Chunk processed into {} items", processed_items.len());
+                self.process_count
+                    .set(self.process_count.get() + processed_items.len());
+                Ok(processed_items)
+            }
+            Err(err) => {
+                self.inc_process_error_count(read_items.len());
+                if self.is_skip_limit_reached(&err) {
+                    let error = BatchError::ItemProcessor(err.to_string());
+                    self.record_failure(err);
+                    return Err(error);
+                }
+
+                warn!("ItemProcessor error: {}", err);
+                self.notify_skip(&err);
+                for item in read_items {
+                    self.write_skipped_item(Some(item), &err);
+                }
+                Ok(Vec::new())
+            }
+        }
+    }
+
     /// Writes a chunk of processed items using the writer.
     ///
     /// # Arguments
     ///
-    /// * `processed_items` - A slice containing the processed items to write.
+    /// * `processed_items` - A vector containing the processed items to write, passed to the
+    ///   write interceptor first.
     ///
     /// Returns a `Result` indicating the success of the write operation or a `BatchError` if an error occurred.
-    fn write_chunk(&self, processed_items: &[W]) -> Result<(), BatchError> {
+    fn write_chunk(&self, processed_items: &mut Vec<W>) -> Result<(), BatchError> {
+        if self.dry_run.get() {
+            debug!(
+                "Dry run: chunk of {} items not written",
+                processed_items.len()
+            );
+            return Ok(());
+        }
+
+        if let Some(write_interceptor) = self.write_interceptor {
+            if !processed_items.is_empty() {
+                write_interceptor.before_write(processed_items);
+            }
+        }
+
         debug!("Start writing chunk");
+        let start = Instant::now();
 
-        let result = self.writer.write(processed_items);
+        let processed_items = &*processed_items;
+        // A partial write is not retried, as the written items would be written twice
+        let result = self
+            .retry(|| match self.writer.write(processed_items) {
+                Err(error @ BatchError::PartialWrite { .. }) => Ok(Err(error)),
+                result => result.map(Ok),
+            })
+            .and_then(|result| result);
+        let written = match &result {
+            Ok(()) => processed_items.len(),
+            Err(BatchError::PartialWrite { written, .. }) => *written,
+            Err(_) => 0,
+        };
         match result {
             Ok(()) => {
                 debug!("ItemWriter success")
             }
             Err(err) => {
-                self.inc_write_error_count(processed_items.len());
-                if self.is_skip_limit_reached() {
-                    return Err(BatchError::ItemWriter(err.to_string()));
+                self.inc_write_error_count(processed_items.len() - written);
+                if self.is_skip_limit_reached(&err) {
+                    let error = BatchError::ItemWriter(err.to_string());
+                    self.record_failure(err);
+                    return Err(error);
                 } else {
                     warn!("Error occurred during write item: {}", err);
+                    self.notify_skip(&err);
                 }
             }
         }
 
+        // Skipped items count as write errors only, not as items to flush
+        if written > 0 {
+            self.unflushed.set(self.unflushed.get() + written);
+            self.uncommitted.set(true);
+        }
+
+        let flush_due = self
+            .flush_interval
+            .is_none_or(|interval| self.unflushed.get() >= interval);
+        let flush_result = if flush_due {
+            self.flush_writer()
+        } else {
+            Ok(())
+        };
+        self.adapt_chunk_size(start.elapsed());
+
+        debug!("End writing chunk");
+        flush_result
+    }
+
+    /// Flushes the writer, and counts the items written since the last flush.
+    ///
+    /// Returns a `BatchError` if the flush failed and the error must not be skipped.
+    fn flush_writer(&self) -> Result<(), BatchError> {
+        let items = self.unflushed.replace(0);
+        let uncommitted = self.uncommitted.replace(false);
+
         match self.writer.flush() {
             Ok(()) => {
-                self.inc_write_count(processed_items.len());
-                debug!("End writing chunk");
+                self.inc_write_count(items);
+
+                if uncommitted {
+                    if let Some(on_chunk_commit) = &self.on_chunk_commit {
+                        on_chunk_commit(&self.execution());
+                    }
+                }
+
                 Ok(())
             }
             Err(err) => {
-                self.inc_write_error_count(processed_items.len());
-                if self.is_skip_limit_reached() {
-                    Err(BatchError::ItemWriter(err.to_string()))
+                self.inc_write_error_count(items);
+                if self.is_skip_limit_reached(&err) {
+                    let error = BatchError::ItemWriter(err.to_string());
+                    self.record_failure(err);
+                    Err(error)
                 } else {
                     warn!("Error occurred during flush item: {}", err);
+                    self.notify_skip(&err);
+                    self.warnings.borrow_mut().push(err);
                     Ok(())
                 }
             }
         }
     }
 
+    /// Notifies the listeners with the running execution of the step.
+    fn notify(&self, event: impl Fn(&dyn StepListener, &StepExecution)) {
+        if self.listeners.is_empty() {
+            return;
+        }
+
+        let execution = self.execution();
+        for listener in &self.listeners {
+            event(*listener, &execution);
+        }
+    }
+
+    /// Notifies the listeners of a skipped error.
+    fn notify_skip(&self, error: &BatchError) {
+        for listener in &self.listeners {
+            listener.on_skip(error);
+        }
+    }
+
+    /// Writes a skipped item to the skip writer, if any.
+    ///
+    /// A failure of the skip writer does not stop the step: it is collected in the warnings.
+    fn write_skipped_item(&self, item: Option<&R>, error: &BatchError) {
+        if self.dry_run.get() {
+            return;
+        }
+
+        if let Some(skip_writer) = &self.skip_writer {
+            let skipped_item = SkippedItem {
+                error: error.to_string(),
+                item: item.map(skip_writer.clone),
+            };
+
+            let result = skip_writer
+                .writer
+                .write(&[skipped_item])
+                .and_then(|()| skip_writer.writer.flush());
+            self.manage_error(result);
+        }
+    }
+
+    /// Adapts the size of the next chunk to the write latency of the last one,
+    /// when an adaptive chunk policy is configured.
+    fn adapt_chunk_size(&self, latency: Duration) {
+        if let Some(policy) = &self.adaptive_chunk {
+            let size = policy.next_size(self.chunk_size.get(), latency);
+            debug!("Write latency {:?}, next chunk size: {}", latency, size);
+            self.chunk_size.set(size);
+        }
+    }
+
     /// Increments the read count by 1.
     fn inc_read_count(&self) {
         self.read_count.set(self.read_count.get() + 1);
@@ -363,6 +1216,11 @@ impl<'a, R, W> StepInstance<'a, R, W> {
         self.read_error_count.set(self.read_error_count.get() + 1);
     }
 
+    /// Increments the filter count by 1.
+    fn inc_filter_count(&self) {
+        self.filter_count.set(self.filter_count.get() + 1);
+    }
+
     /// Increments the write count by the specified amount.
     ///
     /// # Arguments
@@ -392,18 +1250,24 @@ impl<'a, R, W> StepInstance<'a, R, W> {
             .set(self.process_error_count.get() + write_count);
     }
 
+    /// Records the error which makes the step fail, unless one has already been recorded.
+    fn record_failure(&self, error: BatchError) {
+        self.first_error.borrow_mut().get_or_insert(error);
+    }
+
     /// Manages the error returned by a step instance operation.
     ///
+    /// The error does not stop the step: it is logged and collected in the warnings
+    /// of the step execution.
+    ///
     /// # Arguments
     ///
     /// * `result` - The result of the step instance operation.
-    fn manage_error(result: Result<(), BatchError>) {
-        match result {
-            Ok(()) => {}
-            Err(error) => {
-                panic!("{}", error.to_string());
-            }
-        };
+    fn manage_error(&self, result: Result<(), BatchError>) {
+        if let Err(error) = result {
+            warn!("Non-fatal error occurred: {}", error);
+            self.warnings.borrow_mut().push(error);
+        }
     }
 }
 
@@ -414,7 +1278,23 @@ pub struct StepBuilder<'a, R, W> {
     processor: Option<&'a dyn ItemProcessor<R, W>>,
     writer: Option<&'a dyn ItemWriter<W>>,
     chunk_size: usize,
+    adaptive_chunk: Option<AdaptiveChunkPolicy>,
     skip_limit: usize,
+    skip_policy: Option<&'a dyn SkipPolicy>,
+    write_interceptor: Option<&'a dyn WriteInterceptor<W>>,
+    retry_policy: RetryPolicy,
+    on_chunk_commit: Option<Box<ChunkCommitCallback<'a>>>,
+    on_retry_exhausted: Option<Box<RetryExhaustedCallback<'a, R>>>,
+    progress: Option<Box<ProgressCallback<'a>>>,
+    total_hint: Option<usize>,
+    listeners: Vec<&'a dyn StepListener>,
+    skip_writer: Option<SkipWriter<'a, R>>,
+    timeout: Option<Duration>,
+    flush_interval: Option<usize>,
+    fail_on_open_error: bool,
+    fail_on_close_error: bool,
+    dry_run: bool,
+    record_chunk_durations: bool,
 }
 
 impl<'a, R: 'static, W: 'static + Clone> StepBuilder<'a, R, W> {
@@ -425,7 +1305,23 @@ impl<'a, R: 'static, W: 'static + Clone> StepBuilder<'a, R, W> {
             processor: None,
             writer: None,
             chunk_size: 1,
+            adaptive_chunk: None,
             skip_limit: 0,
+            skip_policy: None,
+            write_interceptor: None,
+            retry_policy: RetryPolicy::default(),
+            on_chunk_commit: None,
+            on_retry_exhausted: None,
+            progress: None,
+            total_hint: None,
+            listeners: Vec::new(),
+            skip_writer: None,
+            timeout: None,
+            flush_interval: None,
+            fail_on_open_error: false,
+            fail_on_close_error: false,
+            dry_run: false,
+            record_chunk_durations: false,
         }
     }
 
@@ -449,55 +1345,354 @@ impl<'a, R: 'static, W: 'static + Clone> StepBuilder<'a, R, W> {
         self
     }
 
+    /// Sets the number of items read before processing and writing them. Defaults to 1.
+    ///
+    /// The chunk size must be positive: building a step with a chunk size of 0 fails.
     pub fn chunk(mut self, chunk_size: usize) -> StepBuilder<'a, R, W> {
         self.chunk_size = chunk_size;
         self
     }
 
+    /// Adapts the chunk size between chunks to keep the write latency near `target`.
+    ///
+    /// The size configured with `chunk` is used for the first chunk, bounded by `min` and `max`.
+    /// See [`AdaptiveChunkPolicy`].
+    pub fn adaptive_chunk(
+        mut self,
+        target: Duration,
+        min: usize,
+        max: usize,
+    ) -> StepBuilder<'a, R, W> {
+        self.adaptive_chunk = Some(AdaptiveChunkPolicy { target, min, max });
+        self
+    }
+
     pub fn skip_limit(mut self, skip_limit: usize) -> StepBuilder<'a, R, W> {
         self.skip_limit = skip_limit;
         self
     }
 
-    pub fn build(self) -> StepInstance<'a, R, W> {
-        let default_processor = &DefaultProcessor;
+    /// Decides which errors are skipped with a custom policy, instead of skipping any
+    /// error up to the `skip_limit`.
+    ///
+    /// The `skip_limit` is ignored when a skip policy is set.
+    pub fn skip_policy(mut self, skip_policy: &'a dyn SkipPolicy) -> StepBuilder<'a, R, W> {
+        self.skip_policy = Some(skip_policy);
+        self
+    }
 
-        StepInstance {
-            id: Uuid::new_v4(),
-            name: self.name.unwrap_or(build_name()),
-            status: Cell::new(StepStatus::Starting),
-            reader: self.reader.unwrap(),
-            processor: self.processor.unwrap_or(default_processor),
-            writer: self.writer.unwrap(),
-            chunk_size: self.chunk_size,
-            skip_limit: self.skip_limit,
-            write_error_count: Cell::new(0),
-            process_error_count: Cell::new(0),
-            read_error_count: Cell::new(0),
-            write_count: Cell::new(0),
-            read_count: Cell::new(0),
-        }
+    /// Sets a hook changing the processed items of each chunk just before they are written.
+    pub fn write_interceptor(
+        mut self,
+        write_interceptor: &'a dyn WriteInterceptor<W>,
+    ) -> StepBuilder<'a, R, W> {
+        self.write_interceptor = Some(write_interceptor);
+        self
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use anyhow::Result;
-    use mockall::mock;
-    use serde::{Deserialize, Serialize};
+    /// Retries a failing reader, processor or writer call up to `retry_limit` times
+    /// before the error is counted against the skip limit.
+    ///
+    /// Deserialization errors of the reader are not retried, as the reader has moved
+    /// past the malformed record: a retry would read the next one in its place.
+    pub fn retry_limit(mut self, retry_limit: u16) -> StepBuilder<'a, R, W> {
+        self.retry_policy.attempts = usize::from(retry_limit) + 1;
+        self
+    }
+
+    /// Sets the duration to wait before each retry.
+    pub fn retry_backoff(mut self, retry_backoff: Duration) -> StepBuilder<'a, R, W> {
+        self.retry_policy.backoff = Backoff::Fixed(retry_backoff);
+        self
+    }
+
+    /// Sets how many times and how often failing calls are retried, for instance with an
+    /// exponential backoff, instead of `retry_limit` and `retry_backoff`.
+    ///
+    /// The attempts of the policy include the first call: a policy of 3 attempts retries
+    /// up to 2 times.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> StepBuilder<'a, R, W> {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets a callback invoked after each chunk has been successfully written and flushed.
+    ///
+    /// The callback receives the running execution of the step, with the counts
+    /// at the time of the commit. It is not invoked for chunks whose write or flush failed,
+    /// which makes it suitable to persist a checkpoint or to report progress.
+    pub fn on_chunk_commit(
+        mut self,
+        on_chunk_commit: impl Fn(&StepExecution) + 'a,
+    ) -> StepBuilder<'a, R, W> {
+        self.on_chunk_commit = Some(Box::new(on_chunk_commit));
+        self
+    }
+
+    /// Sets a callback invoked with each item the processor still fails on once its
+    /// retries are exhausted, with the last error, for example to push the item to a
+    /// dead-letter queue or to raise an alert.
+    ///
+    /// It is invoked once per failed item, only if retries are enabled, and before the
+    /// skip policy is applied: the item counts as a process error against the skip limit
+    /// as usual, and the step fails if the error is not skippable. Items of a processor
+    /// processing whole chunks are not reported to it, as chunks are not retried, nor
+    /// are failed chunk writes, as they hold processed items: they are reported to the
+    /// `on_skip` listeners.
+    pub fn on_retry_exhausted(
+        mut self,
+        on_retry_exhausted: impl Fn(&R, &BatchError) + 'a,
+    ) -> StepBuilder<'a, R, W> {
+        self.on_retry_exhausted = Some(Box::new(on_retry_exhausted));
+        self
+    }
+
+    /// Registers a listener of the lifecycle of the step and of its chunks.
+    ///
+    /// Several listeners can be registered; they are called in registration order.
+    pub fn listener(mut self, listener: &'a dyn StepListener) -> StepBuilder<'a, R, W> {
+        self.listeners.push(listener);
+        self
+    }
+
+    /// Sets a writer receiving the items skipped on read or process errors, to quarantine them.
+    ///
+    /// Each skipped error is written as a [`SkippedItem`], holding the item the processor
+    /// failed on, or no item for a read error. Items skipped on write errors are not
+    /// written, as the whole chunk failed. The writer is opened and closed with the step.
+    pub fn skip_writer(
+        mut self,
+        skip_writer: &'a dyn ItemWriter<SkippedItem<R>>,
+    ) -> StepBuilder<'a, R, W>
+    where
+        R: Clone,
+    {
+        self.skip_writer = Some(SkipWriter {
+            writer: skip_writer,
+            clone: R::clone,
+        });
+        self
+    }
+
+    /// Stops the step with `StepStatus::TimedOut` once it has run longer than `timeout`.
+    ///
+    /// The timeout is checked between chunks: the current chunk is completed and the writer
+    /// is closed before the step returns its error. A read blocked in a system call is not
+    /// interrupted, so readers of network sources should also set their own I/O timeouts.
+    pub fn timeout(mut self, timeout: Duration) -> StepBuilder<'a, R, W> {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Flushes the writer once at least `items` items have been written since the last
+    /// flush, instead of after each chunk.
+    ///
+    /// The writer is always flushed at the end of the step. Fewer flushes speed up writers
+    /// with an expensive flush, such as an `fsync` or a network round trip, with small
+    /// chunks, at the cost of durability: a failure loses all the items written since the
+    /// last flush, not only the current chunk. Items are counted as written, and
+    /// `on_chunk_commit` is invoked, when they are flushed.
+    pub fn flush_interval(mut self, items: usize) -> StepBuilder<'a, R, W> {
+        self.flush_interval = Some(items);
+        self
+    }
+
+    /// Fails the step with `StepStatus::WriteError` when the writer cannot be opened, before
+    /// anything is read.
+    ///
+    /// By default, a writer failing to open does not stop the step: the error is collected
+    /// in the warnings of the step execution, and the step goes on. The skip writer is not
+    /// concerned: its failures never stop the step.
+    pub fn fail_on_open_error(mut self, yes: bool) -> StepBuilder<'a, R, W> {
+        self.fail_on_open_error = yes;
+        self
+    }
+
+    /// Fails the step with `StepStatus::WriteError` when the writer cannot be closed, such
+    /// as a database writer failing to commit its transaction on close.
+    ///
+    /// By default, a writer failing to close does not fail the step: the error is collected
+    /// in the warnings of the step execution, which can hide lost items. The items are still
+    /// counted as written, as they were handed to the writer.
+    pub fn fail_on_close_error(mut self, yes: bool) -> StepBuilder<'a, R, W> {
+        self.fail_on_close_error = yes;
+        self
+    }
+
+    /// Sets a callback invoked after each chunk with the number of items read so far and
+    /// the total number of items expected, to render a progress bar.
+    ///
+    /// The total is the one set by `total_hint`, or else the one reported by
+    /// `ItemReader::total_hint`, and `None` when neither is known. The callback is also
+    /// invoked for chunks which failed, before the step stops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spring_batch_rs::core::item::{ItemReader, VecItemWriter};
+    /// # use spring_batch_rs::core::step::{Step, StepBuilder, StepInstance};
+    /// # use spring_batch_rs::BatchError;
+    /// # use std::cell::RefCell;
+    /// # struct Numbers(RefCell<Vec<u32>>);
+    /// # impl ItemReader<u32> for Numbers {
+    /// #     fn read(&self) -> Result<Option<u32>, BatchError> {
+    /// #         Ok(self.0.borrow_mut().pop())
+    /// #     }
+    /// # }
+    /// let reader = Numbers(RefCell::new(vec![1, 2, 3, 4]));
+    /// let writer = VecItemWriter::new();
+    ///
+    /// let step: StepInstance<u32, u32> = StepBuilder::new()
+    ///     .reader(&reader)
+    ///     .writer(&writer)
+    ///     .chunk(2)
+    ///     .total_hint(4)
+    ///     .progress(|read, total| println!("{read}/{}", total.unwrap_or_default()))
+    ///     .build();
+    ///
+    /// let execution = step.run().unwrap();
+    /// assert_eq!(execution.percentage(), Some(100.0));
+    /// ```
+    pub fn progress(
+        mut self,
+        progress: impl Fn(usize, Option<usize>) + 'a,
+    ) -> StepBuilder<'a, R, W> {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// Sets the total number of items the step is expected to read, when the reader
+    /// cannot report it.
+    pub fn total_hint(mut self, total_hint: usize) -> StepBuilder<'a, R, W> {
+        self.total_hint = Some(total_hint);
+        self
+    }
+
+    /// Runs the step without writing, to count the items a real run would process.
+    ///
+    /// Items are read and processed as usual, so that reader and processor errors are
+    /// reported, but the writer and the skip writer are never opened nor called: the
+    /// `write_count` of the step stays 0, and `process_count` holds the items that would
+    /// have been written.
+    pub fn dry_run(mut self, dry_run: bool) -> StepBuilder<'a, R, W> {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Keeps the duration of every chunk in `ChunkStatistics::durations`, to compute percentiles.
+    ///
+    /// Disabled by default, as the memory used grows with the number of chunks.
+    pub fn record_chunk_durations(mut self, yes: bool) -> StepBuilder<'a, R, W> {
+        self.record_chunk_durations = yes;
+        self
+    }
+
+    /// Builds the step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the configuration is invalid, as reported by `try_build`.
+    pub fn build(self) -> StepInstance<'a, R, W> {
+        self.try_build().unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Builds the step, checking its configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BatchError::Configuration` if the reader or the writer is not set,
+    /// or if the chunk size is 0.
+    pub fn try_build(self) -> Result<StepInstance<'a, R, W>, BatchError> {
+        let default_processor = &DefaultProcessor;
+
+        let reader = self
+            .reader
+            .ok_or_else(|| BatchError::Configuration("a step requires a reader".to_string()))?;
+        let writer = self
+            .writer
+            .ok_or_else(|| BatchError::Configuration("a step requires a writer".to_string()))?;
+        if self.chunk_size == 0 {
+            return Err(BatchError::Configuration(
+                "the chunk size of a step must be positive".to_string(),
+            ));
+        }
+
+        Ok(StepInstance {
+            id: Uuid::new_v4(),
+            name: self.name.map(OnceCell::from).unwrap_or_default(),
+            status: Cell::new(StepStatus::Starting),
+            reader,
+            processor: self.processor.unwrap_or(default_processor),
+            writer,
+            chunk_size: Cell::new(
+                self.adaptive_chunk
+                    .map_or(self.chunk_size, |policy| policy.clamp(self.chunk_size)),
+            ),
+            adaptive_chunk: self.adaptive_chunk,
+            skip_limit: self.skip_limit,
+            skip_policy: self.skip_policy,
+            write_interceptor: self.write_interceptor,
+            retry_policy: self.retry_policy,
+            retry_count: Cell::new(0),
+            write_error_count: Cell::new(0),
+            process_error_count: Cell::new(0),
+            read_error_count: Cell::new(0),
+            write_count: Cell::new(0),
+            read_count: Cell::new(0),
+            process_count: Cell::new(0),
+            filter_count: Cell::new(0),
+            warnings: RefCell::new(Vec::new()),
+            first_error: RefCell::new(None),
+            on_chunk_commit: self.on_chunk_commit,
+            on_retry_exhausted: self.on_retry_exhausted,
+            progress: self.progress,
+            total_hint: self.total_hint,
+            listeners: self.listeners,
+            skip_writer: self.skip_writer,
+            timeout: self.timeout,
+            flush_interval: self.flush_interval,
+            fail_on_open_error: self.fail_on_open_error,
+            fail_on_close_error: self.fail_on_close_error,
+            unflushed: Cell::new(0),
+            uncommitted: Cell::new(false),
+            dry_run: Cell::new(self.dry_run),
+            parameters: RefCell::new(JobParameters::new()),
+            chunk_statistics: RefCell::new(ChunkStatistics::default()),
+            record_chunk_durations: self.record_chunk_durations,
+            start: Cell::new(Instant::now()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        thread,
+        time::Duration,
+    };
+
+    use anyhow::Result;
+    use mockall::mock;
+    use serde::{Deserialize, Serialize};
 
     use crate::{
         core::{
             item::{
-                ItemProcessor, ItemProcessorResult, ItemReader, ItemReaderResult, ItemWriter,
-                ItemWriterResult,
+                ClosureItemReader, ClosureItemWriter, ItemProcessor, ItemProcessorResult,
+                ItemReader, ItemReaderResult, ItemWriter, ItemWriterResult, IterItemReader,
+                VecItemWriter,
             },
+            resilience::{Backoff, RetryPolicy},
             step::StepStatus,
         },
         BatchError,
     };
 
-    use super::{Step, StepBuilder, StepInstance};
+    use super::{
+        AdaptiveChunkPolicy, ChunkStatistics, SkipPolicy, Step, StepBuilder, StepExecution,
+        StepInstance, StepListener, WriteInterceptor,
+    };
 
     mock! {
         pub TestItemReader {}
@@ -557,7 +1752,7 @@ mod tests {
             model: "model".to_owned(),
             description: "description".to_owned(),
         };
-        Ok(car)
+        Ok(Some(car))
     }
 
     #[test]
@@ -619,6 +1814,10 @@ mod tests {
 
         assert!(result.is_err());
         assert_eq!(step.get_status(), StepStatus::ProcessorError);
+        assert_eq!(
+            result.unwrap_err().first_error,
+            Some(BatchError::ItemProcessor("mock process error".to_string()))
+        );
 
         Ok(())
     }
@@ -652,6 +1851,10 @@ mod tests {
 
         assert!(result.is_err());
         assert_eq!(step.get_status(), StepStatus::WriteError);
+        assert_eq!(
+            result.unwrap_err().first_error,
+            Some(BatchError::ItemWriter("mock write error".to_string()))
+        );
 
         Ok(())
     }
@@ -685,6 +1888,7 @@ mod tests {
 
         assert!(result.is_ok());
         assert_eq!(step.get_status(), StepStatus::Success);
+        assert_eq!(result.unwrap().first_error, None);
 
         Ok(())
     }
@@ -721,4 +1925,1309 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn step_should_call_on_chunk_commit_after_each_written_chunk() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 4));
+
+        let mut writer = MockTestItemWriter::default();
+        writer.expect_write().times(2).returning(|_| Ok(()));
+
+        let commits = RefCell::new(Vec::new());
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(3)
+            .on_chunk_commit(|execution| {
+                commits
+                    .borrow_mut()
+                    .push((execution.read_count, execution.write_count))
+            })
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().write_count, 4);
+        drop(step);
+        assert_eq!(commits.into_inner(), vec![(3, 3), (4, 4)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn step_should_not_call_on_chunk_commit_for_failed_chunk() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 2));
+
+        let mut writer = MockTestItemWriter::default();
+        writer
+            .expect_write()
+            .returning(|_| Err(BatchError::ItemWriter("mock write error".to_string())));
+
+        let commits = RefCell::new(0);
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(3)
+            .skip_limit(5)
+            .on_chunk_commit(|_| *commits.borrow_mut() += 1)
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_ok());
+        drop(step);
+        assert_eq!(commits.into_inner(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn step_should_not_write_filtered_items() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 4));
+
+        let mut j: u16 = 0;
+        let mut processor = MockTestProcessor::default();
+        processor.expect_process().returning(move |item| {
+            j += 1;
+            Ok(j.is_multiple_of(2).then(|| item.clone()))
+        });
+
+        let mut writer = MockTestItemWriter::default();
+        writer.expect_write().returning(|_| Ok(()));
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .processor(&processor)
+            .writer(&writer)
+            .chunk(4)
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_ok());
+        assert_eq!(step.get_read_count(), 4);
+        assert_eq!(step.get_filter_count(), 2);
+        assert_eq!(step.get_write_count(), 2);
+        assert_eq!(result.unwrap().filter_count, 2);
+
+        Ok(())
+    }
+
+    struct FailingFlushWriter;
+
+    impl ItemWriter<Car> for FailingFlushWriter {
+        fn write(&self, _items: &[Car]) -> ItemWriterResult {
+            Ok(())
+        }
+
+        fn flush(&self) -> ItemWriterResult {
+            Err(BatchError::ItemWriter("mock flush error".to_string()))
+        }
+
+        fn close(&self) -> ItemWriterResult {
+            Err(BatchError::ItemWriter("mock close error".to_string()))
+        }
+    }
+
+    #[test]
+    fn step_should_collect_non_fatal_errors_as_warnings() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 2));
+
+        let writer = FailingFlushWriter;
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .skip_limit(10)
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap().warnings,
+            vec![
+                BatchError::ItemWriter("mock flush error".to_string()),
+                BatchError::ItemWriter("mock flush error".to_string()),
+                BatchError::ItemWriter("mock close error".to_string()),
+            ]
+        );
+
+        // Unless a close error is configured to fail the step
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 2));
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .skip_limit(10)
+            .fail_on_close_error(true)
+            .build();
+
+        let execution = step.execute().unwrap_err();
+
+        assert_eq!(step.get_status(), StepStatus::WriteError);
+        assert_eq!(execution.warnings.len(), 2);
+        assert_eq!(
+            execution.first_error,
+            Some(BatchError::ItemWriter("mock close error".to_string()))
+        );
+
+        Ok(())
+    }
+
+    /// A writer counting its flushes, and the items written before each of them.
+    #[derive(Default)]
+    struct FlushCountingWriter {
+        written: Cell<usize>,
+        flushed: RefCell<Vec<usize>>,
+    }
+
+    impl ItemWriter<Car> for FlushCountingWriter {
+        fn write(&self, items: &[Car]) -> ItemWriterResult {
+            self.written.set(self.written.get() + items.len());
+            Ok(())
+        }
+
+        fn flush(&self) -> ItemWriterResult {
+            self.flushed.borrow_mut().push(self.written.get());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn step_should_flush_the_writer_at_the_flush_interval() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 10));
+        let writer = FlushCountingWriter::default();
+        let commits = Cell::new(0);
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .flush_interval(4)
+            .on_chunk_commit(|_| commits.set(commits.get() + 1))
+            .build();
+
+        let execution = step.execute().unwrap();
+
+        // Every other chunk, and once at the end for the last one
+        assert_eq!(*writer.flushed.borrow(), vec![4, 8, 10]);
+        assert_eq!(commits.get(), 3);
+        assert_eq!(execution.write_count, 10);
+
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 10));
+        let writer = FlushCountingWriter::default();
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .build();
+
+        step.execute().unwrap();
+
+        // After each chunk, including the last, empty one
+        assert_eq!(*writer.flushed.borrow(), vec![2, 4, 6, 8, 10, 10]);
+
+        Ok(())
+    }
+
+    /// A writer which cannot be opened.
+    struct UnopenableWriter {
+        written: Cell<usize>,
+        closed: Cell<bool>,
+    }
+
+    impl ItemWriter<Car> for UnopenableWriter {
+        fn write(&self, items: &[Car]) -> ItemWriterResult {
+            self.written.set(self.written.get() + items.len());
+            Ok(())
+        }
+
+        fn open(&self) -> ItemWriterResult {
+            Err(BatchError::ItemWriter("mock open error".to_string()))
+        }
+
+        fn close(&self) -> ItemWriterResult {
+            self.closed.set(true);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn writer_open_error_should_fail_the_step_only_if_configured() -> Result<()> {
+        for fail_on_open_error in [false, true] {
+            let mut i = 0;
+            let mut reader = MockTestItemReader::default();
+            reader
+                .expect_read()
+                .returning(move || mock_read(&mut i, 0, 2));
+            let writer = UnopenableWriter {
+                written: Cell::new(0),
+                closed: Cell::new(false),
+            };
+
+            let step: StepInstance<Car, Car> = StepBuilder::new()
+                .reader(&reader)
+                .writer(&writer)
+                .chunk(2)
+                .fail_on_open_error(fail_on_open_error)
+                .build();
+
+            let result = step.execute();
+            let open_error = BatchError::ItemWriter("mock open error".to_string());
+
+            if fail_on_open_error {
+                let execution = result.unwrap_err();
+                assert_eq!(step.get_status(), StepStatus::WriteError);
+                assert_eq!(execution.first_error, Some(open_error));
+                assert_eq!(execution.read_count, 0);
+                assert_eq!(writer.written.get(), 0);
+                assert!(!writer.closed.get());
+            } else {
+                let execution = result.unwrap();
+                assert_eq!(step.get_status(), StepStatus::Success);
+                assert_eq!(execution.warnings, vec![open_error]);
+                assert_eq!(writer.written.get(), 2);
+                assert!(writer.closed.get());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// A writer failing a given number of times before succeeding.
+    struct FlakyWriter {
+        failures: Cell<usize>,
+    }
+
+    impl ItemWriter<Car> for FlakyWriter {
+        fn write(&self, _items: &[Car]) -> ItemWriterResult {
+            if self.failures.get() > 0 {
+                self.failures.set(self.failures.get() - 1);
+                return Err(BatchError::ItemWriter("mock transient error".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn step_should_retry_failed_writes() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 4));
+
+        let writer = FlakyWriter {
+            failures: Cell::new(2),
+        };
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(4)
+            .retry_limit(2)
+            .retry_backoff(Duration::from_millis(1))
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_ok());
+        assert_eq!(step.get_write_count(), 4);
+        assert_eq!(step.get_write_error_count(), 0);
+        assert_eq!(result.unwrap().retry_count, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn step_should_retry_failed_reads_but_not_malformed_records() -> Result<()> {
+        let calls = Cell::new(0);
+        let reader = ClosureItemReader::new(|| {
+            calls.set(calls.get() + 1);
+            match calls.get() {
+                2 => Err(BatchError::ItemReader("connection reset".to_string())),
+                4 => Err(BatchError::deserialization(Some(3), "malformed")),
+                call if call <= 6 => Ok(Some(call)),
+                _ => Ok(None),
+            }
+        });
+        let writer = VecItemWriter::new();
+
+        let step: StepInstance<usize, usize> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(10)
+            .retry_limit(1)
+            .skip_limit(1)
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_ok());
+        assert_eq!(writer.items(), vec![1, 3, 5, 6]);
+        assert_eq!(step.get_read_error_count(), 1);
+        assert_eq!(step.get_retry_count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn step_should_retry_failed_writes_with_a_retry_policy() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 4));
+
+        let writer = FlakyWriter {
+            failures: Cell::new(2),
+        };
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(4)
+            .retry_policy(RetryPolicy::new(
+                3,
+                Backoff::Exponential(Duration::from_millis(1)),
+            ))
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_ok());
+        assert_eq!(step.get_write_count(), 4);
+        assert_eq!(step.get_retry_count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn skipped_writes_should_not_count_as_written() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 4));
+
+        let writer = FlakyWriter {
+            failures: Cell::new(1),
+        };
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .skip_limit(2)
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_ok());
+        assert_eq!(step.get_write_error_count(), 2);
+        assert_eq!(step.get_write_count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partial_writes_should_count_the_items_written() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 4));
+
+        let mut writer = MockTestItemWriter::default();
+        writer.expect_write().returning(|items| match items.len() {
+            0 => Ok(()),
+            count => Err(BatchError::PartialWrite {
+                written: count - 1,
+                message: "duplicate key".to_string(),
+            }),
+        });
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .skip_limit(2)
+            .retry_limit(3)
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_ok());
+        assert_eq!(step.get_write_count(), 2);
+        assert_eq!(step.get_write_error_count(), 2);
+        assert_eq!(step.get_retry_count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn step_should_reset_its_counters_when_run_again() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader.expect_read().returning(move || {
+            let result = mock_read(&mut i, 0, 3);
+            // Starts over for the next run
+            if matches!(result, Ok(None)) {
+                i = 0;
+            }
+            result
+        });
+        let writer = FlakyWriter {
+            failures: Cell::new(1),
+        };
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(3)
+            .skip_limit(3)
+            .build();
+
+        let first = step.execute().unwrap();
+        assert!(first.write_error_count > 0);
+
+        let second = step.execute().unwrap();
+        assert_eq!(second.read_count, 3);
+        assert_eq!(second.write_count, 3);
+        assert_eq!(second.write_error_count, 0);
+        assert!(second.first_error.is_none());
+        assert_eq!(step.get_status(), StepStatus::Success);
+
+        Ok(())
+    }
+
+    #[test]
+    fn step_should_count_exhausted_retries_once() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 4));
+
+        let writer = FlakyWriter {
+            failures: Cell::new(3),
+        };
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(4)
+            .retry_limit(2)
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_err());
+        assert_eq!(step.get_status(), StepStatus::WriteError);
+        assert_eq!(step.get_write_error_count(), 4);
+        assert_eq!(step.get_retry_count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn step_should_hand_items_failing_after_retries_to_the_callback() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 3));
+
+        let mut processor = MockTestProcessor::default();
+        processor
+            .expect_process()
+            .returning(|_| Err(BatchError::ItemProcessor("mock process error".to_string())));
+
+        let writer = VecItemWriter::new();
+        let dead_letters = RefCell::new(Vec::new());
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .processor(&processor)
+            .writer(&writer)
+            .chunk(3)
+            .retry_limit(1)
+            .skip_limit(2)
+            .on_retry_exhausted(|item, error| {
+                dead_letters
+                    .borrow_mut()
+                    .push((item.year, error.to_string()))
+            })
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_err());
+        assert_eq!(step.get_status(), StepStatus::ProcessorError);
+        assert_eq!(step.get_retry_count(), 3);
+        assert_eq!(
+            *dead_letters.borrow(),
+            vec![
+                (
+                    1979,
+                    "Error occurred in the ItemProcessor: mock process error".to_string()
+                );
+                3
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn items_should_not_be_handed_to_the_callback_without_retries() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 3));
+
+        let mut processor = MockTestProcessor::default();
+        processor
+            .expect_process()
+            .returning(|_| Err(BatchError::ItemProcessor("mock process error".to_string())));
+
+        let writer = VecItemWriter::new();
+        let dead_letters = Cell::new(0);
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .processor(&processor)
+            .writer(&writer)
+            .chunk(3)
+            .skip_limit(3)
+            .on_retry_exhausted(|_, _| dead_letters.set(dead_letters.get() + 1))
+            .build();
+
+        let execution = step.execute().unwrap();
+
+        assert_eq!(execution.process_error_count, 3);
+        assert_eq!(dead_letters.get(), 0);
+
+        Ok(())
+    }
+
+    #[derive(Default)]
+    struct RecordingListener {
+        events: RefCell<Vec<String>>,
+    }
+
+    impl StepListener for RecordingListener {
+        fn before_step(&self, _execution: &StepExecution) {
+            self.events.borrow_mut().push("before_step".to_string());
+        }
+
+        fn after_step(&self, execution: &StepExecution) {
+            self.events
+                .borrow_mut()
+                .push(format!("after_step:{}", execution.write_count));
+        }
+
+        fn before_chunk(&self, execution: &StepExecution) {
+            self.events
+                .borrow_mut()
+                .push(format!("before_chunk:{}", execution.read_count));
+        }
+
+        fn after_chunk(&self, execution: &StepExecution) {
+            self.events
+                .borrow_mut()
+                .push(format!("after_chunk:{}", execution.write_count));
+        }
+
+        fn on_skip(&self, error: &BatchError) {
+            self.events.borrow_mut().push(format!("skip:{}", error));
+        }
+    }
+
+    #[test]
+    fn filter_and_retry_counts_should_default_to_zero() {
+        struct CountingStep;
+
+        impl Step for CountingStep {
+            fn execute(&self) -> super::StepResult<StepExecution> {
+                unimplemented!()
+            }
+
+            fn get_status(&self) -> StepStatus {
+                StepStatus::Starting
+            }
+
+            fn get_name(&self) -> &String {
+                unimplemented!()
+            }
+
+            fn get_id(&self) -> uuid::Uuid {
+                uuid::Uuid::nil()
+            }
+
+            fn get_read_count(&self) -> usize {
+                3
+            }
+
+            fn get_write_count(&self) -> usize {
+                3
+            }
+
+            fn get_read_error_count(&self) -> usize {
+                0
+            }
+
+            fn get_write_error_count(&self) -> usize {
+                0
+            }
+        }
+
+        assert_eq!(CountingStep.get_filter_count(), 0);
+        assert_eq!(CountingStep.get_retry_count(), 0);
+    }
+
+    #[test]
+    fn step_should_notify_listeners() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 3));
+
+        let mut j = 0;
+        let mut processor = MockTestProcessor::default();
+        processor
+            .expect_process()
+            .returning(move |_| mock_process(&mut j, &[2]));
+
+        let mut writer = MockTestItemWriter::default();
+        writer.expect_write().returning(|_| Ok(()));
+
+        let listener = RecordingListener::default();
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .processor(&processor)
+            .writer(&writer)
+            .chunk(2)
+            .skip_limit(1)
+            .listener(&listener)
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_ok());
+        assert_eq!(
+            *listener.events.borrow(),
+            vec![
+                "before_step",
+                "before_chunk:0",
+                "skip:Error occurred in the ItemProcessor: mock process error",
+                "after_chunk:1",
+                "before_chunk:2",
+                "after_chunk:2",
+                "after_step:2",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn step_should_write_skipped_items_to_skip_writer() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader.expect_read().returning(move || {
+            i += 1;
+            match i {
+                2 => Err(BatchError::ItemReader("mock read error".to_string())),
+                5 => Ok(None),
+                _ => Ok(Some(Car {
+                    year: 1978 + i,
+                    make: "make".to_owned(),
+                    model: "model".to_owned(),
+                    description: "description".to_owned(),
+                })),
+            }
+        });
+
+        let mut processor = MockTestProcessor::default();
+        processor.expect_process().returning(|car| match car.year {
+            1981 => Err(BatchError::ItemProcessor("mock process error".to_string())),
+            _ => Ok(Some(car.clone())),
+        });
+
+        let mut writer = MockTestItemWriter::default();
+        writer.expect_write().returning(|_| Ok(()));
+
+        let skip_writer = VecItemWriter::new();
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .processor(&processor)
+            .writer(&writer)
+            .chunk(2)
+            .skip_limit(2)
+            .skip_writer(&skip_writer)
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_ok());
+
+        let skipped_items = skip_writer.items();
+        assert_eq!(skipped_items.len(), 2);
+        assert_eq!(
+            skipped_items[0].error,
+            "Error occurred in the ItemReader: mock read error"
+        );
+        assert!(skipped_items[0].item.is_none());
+        assert_eq!(
+            skipped_items[1].error,
+            "Error occurred in the ItemProcessor: mock process error"
+        );
+        assert_eq!(
+            skipped_items[1].item.as_ref().map(|car| car.year),
+            Some(1981)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn step_should_stop_between_chunks_after_its_timeout() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 100));
+
+        let mut writer = MockTestItemWriter::default();
+        writer.expect_write().returning(|_| {
+            thread::sleep(Duration::from_millis(10));
+            Ok(())
+        });
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(5)
+            .timeout(Duration::from_millis(25))
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_err());
+        assert_eq!(step.get_status(), StepStatus::TimedOut);
+        assert_eq!(step.get_write_count() % 5, 0);
+        assert!(step.get_write_count() < 100);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn step_should_emit_a_span_with_a_child_span_per_chunk() -> Result<()> {
+        use std::sync::{Arc, Mutex};
+
+        use tracing::{
+            field::{Field, Visit},
+            span::{Attributes, Id, Record},
+            Event, Metadata, Subscriber,
+        };
+
+        #[derive(Debug, PartialEq)]
+        struct RecordedSpan {
+            name: String,
+            read_count: Option<u64>,
+            write_count: Option<u64>,
+        }
+
+        /// Records the name of each span and the counts recorded on it.
+        #[derive(Clone, Default)]
+        struct SpanRecorder {
+            spans: Arc<Mutex<Vec<RecordedSpan>>>,
+        }
+
+        struct Counts(Option<u64>, Option<u64>);
+
+        impl Visit for Counts {
+            fn record_u64(&mut self, field: &Field, value: u64) {
+                match field.name() {
+                    "read_count" => self.0 = Some(value),
+                    "write_count" => self.1 = Some(value),
+                    _ => {}
+                }
+            }
+
+            fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+        }
+
+        impl Subscriber for SpanRecorder {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut spans = self.spans.lock().unwrap();
+                spans.push(RecordedSpan {
+                    name: span.metadata().name().to_string(),
+                    read_count: None,
+                    write_count: None,
+                });
+                Id::from_u64(spans.len() as u64)
+            }
+
+            fn record(&self, span: &Id, values: &Record<'_>) {
+                let mut spans = self.spans.lock().unwrap();
+                let recorded = &mut spans[span.into_u64() as usize - 1];
+                let mut counts = Counts(recorded.read_count, recorded.write_count);
+                values.record(&mut counts);
+                recorded.read_count = counts.0;
+                recorded.write_count = counts.1;
+            }
+
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+            fn event(&self, _event: &Event<'_>) {}
+            fn enter(&self, _span: &Id) {}
+            fn exit(&self, _span: &Id) {}
+        }
+
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 3));
+
+        let mut writer = MockTestItemWriter::default();
+        writer.expect_write().returning(|_| Ok(()));
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .name("traced".to_string())
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .build();
+
+        let recorder = SpanRecorder::default();
+        let succeeded =
+            tracing::subscriber::with_default(recorder.clone(), || step.execute().is_ok());
+
+        assert!(succeeded);
+
+        let spans = recorder.spans.lock().unwrap();
+        assert_eq!(
+            *spans,
+            vec![
+                RecordedSpan {
+                    name: "step".to_string(),
+                    read_count: Some(3),
+                    write_count: Some(3)
+                },
+                RecordedSpan {
+                    name: "chunk".to_string(),
+                    read_count: Some(2),
+                    write_count: Some(2)
+                },
+                RecordedSpan {
+                    name: "chunk".to_string(),
+                    read_count: Some(1),
+                    write_count: Some(1)
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn skip_policy_should_decide_which_errors_are_skipped() -> Result<()> {
+        struct SkipMalformedRecords;
+
+        impl SkipPolicy for SkipMalformedRecords {
+            fn should_skip(&self, error: &BatchError, _error_count: usize) -> bool {
+                matches!(error, BatchError::Deserialization { .. })
+            }
+        }
+
+        let mut results = vec![
+            Ok(None),
+            Err(BatchError::ItemReader("connection lost".to_string())),
+            mock_read(&mut 0, 0, 0),
+            Err(BatchError::deserialization(Some(2), "malformed")),
+            Err(BatchError::deserialization(Some(1), "malformed")),
+            mock_read(&mut 0, 0, 0),
+        ];
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || results.pop().unwrap());
+
+        let writer = VecItemWriter::new();
+        let policy = SkipMalformedRecords;
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(10)
+            .skip_policy(&policy)
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_err());
+        assert_eq!(step.get_status(), StepStatus::ReadError);
+        assert_eq!(step.get_read_error_count(), 3);
+        assert_eq!(step.get_read_count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_interceptor_should_change_each_chunk_before_it_is_written() -> Result<()> {
+        #[derive(Default)]
+        struct Sequencer {
+            next: Cell<u32>,
+            chunks: RefCell<Vec<usize>>,
+        }
+
+        impl WriteInterceptor<u32> for Sequencer {
+            fn before_write(&self, items: &mut Vec<u32>) {
+                self.chunks.borrow_mut().push(items.len());
+                for item in items.iter_mut() {
+                    *item += self.next.replace(self.next.get() + 1);
+                }
+            }
+        }
+
+        let reader = IterItemReader::new([10, 20, 30, 40, 50]);
+        let writer = VecItemWriter::new();
+        let sequencer = Sequencer::default();
+
+        let step: StepInstance<u32, u32> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .write_interceptor(&sequencer)
+            .build();
+
+        let result = step.execute();
+
+        assert!(result.is_ok());
+        assert_eq!(writer.items(), vec![10, 21, 32, 43, 54]);
+        assert_eq!(*sequencer.chunks.borrow(), vec![2, 2, 1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn progress_should_be_reported_after_each_chunk() -> Result<()> {
+        struct CountedReader(Cell<u16>);
+
+        impl ItemReader<Car> for CountedReader {
+            fn read(&self) -> ItemReaderResult<Car> {
+                let mut i = self.0.get();
+                let result = mock_read(&mut i, 0, 5);
+                self.0.set(i);
+                result
+            }
+
+            fn total_hint(&self) -> Option<usize> {
+                Some(5)
+            }
+        }
+
+        let reader = CountedReader(Cell::new(0));
+        let writer = VecItemWriter::new();
+        let progress = RefCell::new(Vec::new());
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .progress(|read, total| progress.borrow_mut().push((read, total)))
+            .build();
+
+        let execution = step.run()?;
+
+        assert_eq!(
+            progress.borrow().as_slice(),
+            [(2, Some(5)), (4, Some(5)), (5, Some(5))]
+        );
+        assert_eq!(execution.total_hint, Some(5));
+        assert_eq!(execution.percentage(), Some(100.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn reader_should_be_opened_before_reading_and_closed_after() -> Result<()> {
+        struct Source {
+            events: RefCell<Vec<&'static str>>,
+            fail_open: bool,
+        }
+
+        impl ItemReader<Car> for Source {
+            fn read(&self) -> ItemReaderResult<Car> {
+                self.events.borrow_mut().push("read");
+                Ok(None)
+            }
+
+            fn open(&self) -> Result<(), BatchError> {
+                self.events.borrow_mut().push("open");
+                if self.fail_open {
+                    return Err(BatchError::ItemReader("connection refused".to_string()));
+                }
+                Ok(())
+            }
+
+            fn close(&self) -> Result<(), BatchError> {
+                self.events.borrow_mut().push("close");
+                Ok(())
+            }
+        }
+
+        let writer = VecItemWriter::new();
+
+        let reader = Source {
+            events: RefCell::new(Vec::new()),
+            fail_open: false,
+        };
+        let step: StepInstance<Car, Car> =
+            StepBuilder::new().reader(&reader).writer(&writer).build();
+
+        assert!(step.run().is_ok());
+        assert_eq!(*reader.events.borrow(), vec!["open", "read", "close"]);
+
+        let reader = Source {
+            events: RefCell::new(Vec::new()),
+            fail_open: true,
+        };
+        let step: StepInstance<Car, Car> =
+            StepBuilder::new().reader(&reader).writer(&writer).build();
+
+        let error = step.run().unwrap_err();
+        assert_eq!(step.get_status(), StepStatus::ReadError);
+        assert_eq!(error.execution.read_error_count, 1);
+        assert_eq!(*reader.events.borrow(), vec!["open"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_chunk_size_should_be_rejected() {
+        let reader = MockTestItemReader::default();
+        let writer = VecItemWriter::new();
+
+        let result: Result<StepInstance<Car, Car>, BatchError> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(0)
+            .try_build();
+
+        assert_eq!(
+            result.err(),
+            Some(BatchError::Configuration(
+                "the chunk size of a step must be positive".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn chunk_size_above_u16_should_be_accepted() -> Result<()> {
+        let mut count = 0u32;
+        let reader = ClosureItemReader::new(move || {
+            count += 1;
+            Ok((count <= 70_001).then_some(count))
+        });
+        let written = Cell::new(Vec::new());
+        let writer = ClosureItemWriter::new(|items: &[u32]| {
+            let mut sizes = written.take();
+            sizes.push(items.len());
+            written.set(sizes);
+            Ok(())
+        });
+
+        let step: StepInstance<u32, u32> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(70_000)
+            .try_build()?;
+
+        assert!(step.run().is_ok());
+        assert_eq!(written.take(), vec![70_000, 1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_should_return_the_execution_or_a_step_error() -> Result<()> {
+        let mut results = vec![
+            Err(BatchError::ItemReader("connection lost".to_string())),
+            mock_read(&mut 0, 0, 0),
+        ];
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || results.pop().unwrap());
+
+        let writer = VecItemWriter::new();
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .name("import".to_string())
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(10)
+            .build();
+
+        let error = step.run().unwrap_err();
+
+        assert_eq!(error.error, BatchError::Step("import".to_string()));
+        assert_eq!(error.execution.read_count, 1);
+        assert_eq!(error.execution.read_error_count, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn adaptive_chunk_size_should_converge_to_target_latency() {
+        let policy = AdaptiveChunkPolicy {
+            target: Duration::from_millis(20),
+            min: 1,
+            max: 1000,
+        };
+
+        // Simulated latency: 1ms per item
+        let mut size = 1;
+        let mut sizes = Vec::new();
+        for _ in 0..200 {
+            size = policy.next_size(size, Duration::from_millis(size as u64));
+            sizes.push(size);
+        }
+
+        assert!(sizes[50..].iter().all(|size| (10..=21).contains(size)));
+    }
+
+    #[test]
+    fn adaptive_chunk_size_should_stay_within_bounds() {
+        let policy = AdaptiveChunkPolicy {
+            target: Duration::from_millis(20),
+            min: 5,
+            max: 8,
+        };
+
+        assert_eq!(policy.next_size(5, Duration::from_secs(1)), 5);
+        assert_eq!(policy.next_size(8, Duration::ZERO), 8);
+    }
+
+    #[test]
+    fn step_should_grow_chunks_when_writes_are_fast() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 10));
+
+        let sizes = RefCell::new(Vec::new());
+        let mut writer = MockTestItemWriter::default();
+        writer.expect_write().returning(|_| Ok(()));
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(1)
+            .adaptive_chunk(Duration::from_secs(60), 1, 3)
+            .on_chunk_commit(|execution| sizes.borrow_mut().push(execution.write_count))
+            .build();
+
+        assert!(step.execute().is_ok());
+        drop(step);
+
+        // Chunks of 1, 2, 3, 3 then the last item
+        assert_eq!(sizes.into_inner(), vec![1, 3, 6, 9, 10]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn chunk_statistics_should_summarize_durations() {
+        let mut statistics = ChunkStatistics::default();
+        assert_eq!(statistics.mean(), Duration::ZERO);
+        assert_eq!(statistics.percentile(50.0), None);
+
+        for millis in [30, 10, 20, 40] {
+            statistics.record(Duration::from_millis(millis), true);
+        }
+
+        assert_eq!(statistics.count, 4);
+        assert_eq!(statistics.min, Duration::from_millis(10));
+        assert_eq!(statistics.max, Duration::from_millis(40));
+        assert_eq!(statistics.mean(), Duration::from_millis(25));
+        assert_eq!(statistics.percentile(50.0), Some(Duration::from_millis(20)));
+        assert_eq!(statistics.percentile(99.0), Some(Duration::from_millis(40)));
+    }
+
+    #[test]
+    fn step_should_time_non_empty_chunks() -> Result<()> {
+        let mut i = 0;
+        let mut reader = MockTestItemReader::default();
+        reader
+            .expect_read()
+            .returning(move || mock_read(&mut i, 0, 4));
+
+        let mut writer = MockTestItemWriter::default();
+        writer.expect_write().returning(|_| Ok(()));
+
+        let step: StepInstance<Car, Car> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(2)
+            .record_chunk_durations(true)
+            .build();
+
+        let statistics = step.execute().unwrap().chunk_statistics;
+
+        // Two full chunks, the last empty chunk is not timed
+        assert_eq!(statistics.count, 2);
+        assert_eq!(statistics.durations.len(), 2);
+        assert!(statistics.min <= statistics.max);
+
+        Ok(())
+    }
 }
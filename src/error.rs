@@ -1,12 +1,24 @@
+use std::{error::Error, fmt, sync::Arc};
+
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone, PartialEq)]
 /// Represents the possible errors that can occur during batch processing.
 pub enum BatchError {
     #[error("Error occurred in the ItemWriter: {0}")]
     /// Error occurred in the ItemWriter.
     ItemWriter(String),
 
+    #[error("Error occurred in the ItemWriter after writing {written} items: {message}")]
+    /// The ItemWriter wrote some of the items, or wrote them to some of its destinations only,
+    /// and failed on the others. It is not retried, as the items would be written twice.
+    PartialWrite {
+        /// The number of items written to all their destinations.
+        written: usize,
+        /// The error raised for the items not written.
+        message: String,
+    },
+
     #[error("Error occurred in the ItemProcessor: {0}")]
     /// Error occurred in the ItemProcessor.
     ItemProcessor(String),
@@ -15,7 +27,101 @@ pub enum BatchError {
     /// Error occurred in the ItemReader.
     ItemReader(String),
 
+    #[error("Error occurred while deserializing{}: {source}", record_label(.record))]
+    /// A record read from the input is malformed or does not match the item.
+    Deserialization {
+        /// The number of the record in the input, starting at 1, when known.
+        record: Option<u64>,
+        /// The error raised while parsing or deserializing the record.
+        source: SourceError,
+    },
+
+    #[error("Error occurred while serializing{}: {source}", record_label(.record))]
+    /// An item cannot be serialized into the output format.
+    Serialization {
+        /// The number of the record in the output, starting at 1, when known.
+        record: Option<u64>,
+        /// The error raised while serializing the item.
+        source: SourceError,
+    },
+
     #[error("Error occurred in the step: {0}")]
     /// Error occurred in the step.
     Step(String),
+
+    #[error("Error occurred in the job repository: {0}")]
+    /// Error occurred in the job repository.
+    Repository(String),
+
+    #[error("Error occurred in the configuration: {0}")]
+    /// Error occurred in the configuration of the job or of a step.
+    Configuration(String),
+}
+
+impl BatchError {
+    /// Creates a `BatchError::Deserialization` from the error of a record.
+    pub fn deserialization(
+        record: Option<u64>,
+        error: impl Into<Box<dyn Error + Send + Sync>>,
+    ) -> Self {
+        BatchError::Deserialization {
+            record,
+            source: SourceError::new(error),
+        }
+    }
+
+    /// Creates a `BatchError::Serialization` from the error of an item.
+    pub fn serialization(
+        record: Option<u64>,
+        error: impl Into<Box<dyn Error + Send + Sync>>,
+    ) -> Self {
+        BatchError::Serialization {
+            record,
+            source: SourceError::new(error),
+        }
+    }
+}
+
+/// Formats the number of a record, if known, for error messages.
+fn record_label(record: &Option<u64>) -> String {
+    record
+        .map(|record| format!(" record {}", record))
+        .unwrap_or_default()
+}
+
+/// The error at the origin of a `BatchError`, such as a `serde_json::Error`.
+///
+/// It is shared so that `BatchError` can be cloned, and compares by message.
+/// Its `Display` and `source` are those of the original error.
+#[derive(Debug, Clone)]
+pub struct SourceError(Arc<dyn Error + Send + Sync>);
+
+impl SourceError {
+    /// Wraps an error, or a message converted into an error.
+    pub fn new(error: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        SourceError(Arc::from(error.into()))
+    }
+
+    /// Returns the original error.
+    pub fn get_ref(&self) -> &(dyn Error + Send + Sync + 'static) {
+        self.0.as_ref()
+    }
+}
+
+impl fmt::Display for SourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Error for SourceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+impl PartialEq for SourceError {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
 }
@@ -0,0 +1,224 @@
+use std::{
+    cell::{Cell, RefCell},
+    fs::File,
+    io::Read,
+    path::Path,
+};
+
+use apache_avro::{from_value, Reader, Schema};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    core::item::{ItemReader, ItemReaderResult},
+    BatchError,
+};
+
+/// A reader streaming records from an Avro Object Container File.
+///
+/// Records are decoded with the schema embedded in the file, optionally resolved
+/// against a reader schema, and deserialized into serde structs.
+pub struct AvroItemReader<'a, R> {
+    reader: RefCell<Reader<'a, R>>,
+    record: Cell<u64>,
+}
+
+impl<'a, R: Read, T: DeserializeOwned> ItemReader<T> for AvroItemReader<'a, R> {
+    /// Reads the next record from the file.
+    ///
+    /// Returns `Ok(Some(record))` if a record is successfully read,
+    /// `Ok(None)` if there are no more records to read, and
+    /// `Err(BatchError::Deserialization { .. })` with the number of the record if it cannot
+    /// be decoded or deserialized.
+    fn read(&self) -> ItemReaderResult<T> {
+        let next = self.reader.borrow_mut().next();
+        if next.is_some() {
+            self.record.set(self.record.get() + 1);
+        }
+
+        match next {
+            Some(Ok(value)) => from_value(&value)
+                .map(Some)
+                .map_err(|error| BatchError::deserialization(Some(self.record.get()), error)),
+            Some(Err(error)) => Err(BatchError::deserialization(Some(self.record.get()), error)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// A builder for configuring Avro item reading.
+#[derive(Default)]
+pub struct AvroItemReaderBuilder<'a> {
+    reader_schema: Option<&'a Schema>,
+}
+
+impl<'a> AvroItemReaderBuilder<'a> {
+    /// Creates a new `AvroItemReaderBuilder`.
+    pub fn new() -> Self {
+        Self {
+            reader_schema: None,
+        }
+    }
+
+    /// Sets the schema the records are resolved against, when it differs from the writer schema.
+    pub fn reader_schema(mut self, reader_schema: &'a Schema) -> Self {
+        self.reader_schema = Some(reader_schema);
+        self
+    }
+
+    /// Creates an `AvroItemReader` from a reader.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the header of the file cannot be read.
+    pub fn from_reader<R: Read>(self, rdr: R) -> AvroItemReader<'a, R> {
+        let reader = Reader::builder(rdr)
+            .maybe_reader_schema(self.reader_schema)
+            .build()
+            .expect("Invalid Avro file");
+
+        AvroItemReader {
+            reader: RefCell::new(reader),
+            record: Cell::new(0),
+        }
+    }
+
+    /// Creates an `AvroItemReader` from a file path.
+    pub fn from_path<P: AsRef<Path>>(self, path: P) -> AvroItemReader<'a, File> {
+        let file = File::open(path).expect("Unable to open file");
+
+        self.from_reader(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use apache_avro::{AvroSchema, Writer};
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        core::{
+            item::{ItemReader, ItemReaderResult, VecItemWriter},
+            step::{Step, StepBuilder, StepInstance},
+        },
+        BatchError,
+    };
+
+    use super::AvroItemReaderBuilder;
+
+    #[derive(Serialize, Deserialize, AvroSchema, Debug, PartialEq)]
+    struct Car {
+        year: i32,
+        make: String,
+    }
+
+    #[test]
+    fn records_should_be_deserialized() {
+        let schema = Car::get_schema();
+        let mut writer = Writer::new(&schema, Vec::new()).unwrap();
+        writer
+            .append_ser(Car {
+                year: 1948,
+                make: "Porsche".to_string(),
+            })
+            .unwrap();
+        let bytes = writer.into_inner().unwrap();
+
+        let reader = AvroItemReaderBuilder::new().from_reader(bytes.as_slice());
+
+        let car: Car = reader.read().unwrap().unwrap();
+        assert_eq!(car.make, "Porsche");
+
+        let result: ItemReaderResult<Car> = reader.read();
+        assert!(result.unwrap().is_none());
+    }
+
+    #[test]
+    fn incompatible_record_should_fail() {
+        #[derive(Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Other {
+            color: String,
+        }
+
+        let schema = Car::get_schema();
+        let mut writer = Writer::new(&schema, Vec::new()).unwrap();
+        writer
+            .append_ser(Car {
+                year: 1948,
+                make: "Porsche".to_string(),
+            })
+            .unwrap();
+        let bytes = writer.into_inner().unwrap();
+
+        let reader = AvroItemReaderBuilder::new().from_reader(bytes.as_slice());
+
+        let result: ItemReaderResult<Other> = reader.read();
+        assert!(matches!(
+            result,
+            Err(BatchError::Deserialization {
+                record: Some(1),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn malformed_records_should_be_skipped_but_not_retried() {
+        #[derive(Deserialize, Clone, Debug, PartialEq)]
+        #[serde(try_from = "i32")]
+        struct Year(i32);
+
+        impl TryFrom<i32> for Year {
+            type Error = String;
+
+            fn try_from(year: i32) -> Result<Self, Self::Error> {
+                if year > 0 {
+                    Ok(Year(year))
+                } else {
+                    Err(format!("invalid year: {}", year))
+                }
+            }
+        }
+
+        #[derive(Deserialize, Clone, Debug, PartialEq)]
+        struct DatedCar {
+            year: Year,
+        }
+
+        let schema = Car::get_schema();
+        let mut writer = Writer::new(&schema, Vec::new()).unwrap();
+        for year in [1948, -1, 2012] {
+            writer
+                .append_ser(Car {
+                    year,
+                    make: "Porsche".to_string(),
+                })
+                .unwrap();
+        }
+        let bytes = writer.into_inner().unwrap();
+
+        for skip_limit in [0, 1] {
+            let reader = AvroItemReaderBuilder::new().from_reader(bytes.as_slice());
+            let writer = VecItemWriter::new();
+
+            let step: StepInstance<DatedCar, DatedCar> = StepBuilder::new()
+                .reader(&reader)
+                .writer(&writer)
+                .retry_limit(1)
+                .skip_limit(skip_limit)
+                .build();
+
+            let result = step.execute();
+
+            assert_eq!(result.is_ok(), skip_limit == 1);
+            assert_eq!(step.get_read_error_count(), 1);
+            assert_eq!(step.get_retry_count(), 0);
+            if skip_limit == 1 {
+                assert_eq!(
+                    writer.items(),
+                    vec![DatedCar { year: Year(1948) }, DatedCar { year: Year(2012) }]
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use std::{cell::RefCell, fs::File, io::Write, path::Path};
+
+use apache_avro::{Codec, Schema, Writer};
+use serde::Serialize;
+
+use crate::{
+    core::item::{ItemWriter, ItemWriterResult},
+    BatchError,
+};
+
+/// A writer producing an Avro Object Container File with an embedded schema.
+///
+/// Records are buffered in blocks by the underlying Avro writer: `flush` writes the
+/// current block and `close` finalizes the file.
+pub struct AvroItemWriter<'a, W: Write> {
+    writer: RefCell<Option<Writer<'a, W>>>,
+}
+
+impl<'a, W: Write> AvroItemWriter<'a, W> {
+    fn closed() -> BatchError {
+        BatchError::ItemWriter("Avro writer is closed".to_string())
+    }
+}
+
+impl<'a, W: Write, T: Serialize> ItemWriter<T> for AvroItemWriter<'a, W> {
+    fn write(&self, items: &[T]) -> ItemWriterResult {
+        let mut writer = self.writer.borrow_mut();
+        let writer = writer.as_mut().ok_or_else(Self::closed)?;
+
+        for item in items {
+            writer
+                .append_ser(item)
+                .map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the buffered records as a new block.
+    fn flush(&self) -> ItemWriterResult {
+        let mut writer = self.writer.borrow_mut();
+        let writer = writer.as_mut().ok_or_else(Self::closed)?;
+
+        writer
+            .flush()
+            .map(|_| ())
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))
+    }
+
+    /// Writes the remaining records and flushes the underlying writer.
+    fn close(&self) -> ItemWriterResult {
+        if let Some(writer) = self.writer.borrow_mut().take() {
+            let mut inner = writer
+                .into_inner()
+                .map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+
+            inner
+                .flush()
+                .map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A builder for configuring Avro item writing.
+#[derive(Default)]
+pub struct AvroItemWriterBuilder<'a> {
+    schema: Option<&'a Schema>,
+    codec: Option<Codec>,
+}
+
+impl<'a> AvroItemWriterBuilder<'a> {
+    /// Creates a new `AvroItemWriterBuilder`.
+    pub fn new() -> Self {
+        Self {
+            schema: None,
+            codec: None,
+        }
+    }
+
+    /// Sets the schema of the records, embedded in the file.
+    pub fn schema(mut self, schema: &'a Schema) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Sets the compression codec of the blocks. Defaults to no compression.
+    pub fn codec(mut self, codec: Codec) -> Self {
+        self.codec = Some(codec);
+        self
+    }
+
+    /// Creates an `AvroItemWriter` writing to a writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use apache_avro::AvroSchema;
+    /// use serde::Serialize;
+    /// use spring_batch_rs::core::item::ItemWriter;
+    /// use spring_batch_rs::item::avro::avro_writer::AvroItemWriterBuilder;
+    ///
+    /// #[derive(Serialize, AvroSchema)]
+    /// struct Car {
+    ///     year: i32,
+    ///     make: String,
+    /// }
+    ///
+    /// let schema = Car::get_schema();
+    /// let writer = AvroItemWriterBuilder::new()
+    ///     .schema(&schema)
+    ///     .from_writer(Vec::new());
+    ///
+    /// writer.write(&[Car { year: 1948, make: "Porsche".to_string() }]).unwrap();
+    /// ItemWriter::<Car>::close(&writer).unwrap();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the schema is not set or is invalid.
+    pub fn from_writer<W: Write>(self, wtr: W) -> AvroItemWriter<'a, W> {
+        let schema = self.schema.expect("Schema is mandatory");
+
+        let writer = Writer::builder()
+            .schema(schema)
+            .writer(wtr)
+            .codec(self.codec.unwrap_or(Codec::Null))
+            .build()
+            .expect("Invalid Avro schema");
+
+        AvroItemWriter {
+            writer: RefCell::new(Some(writer)),
+        }
+    }
+
+    /// Creates an `AvroItemWriter` writing to a file.
+    pub fn from_path<P: AsRef<Path>>(self, path: P) -> AvroItemWriter<'a, File> {
+        let file = File::create(path).expect("Unable to create file");
+
+        self.from_writer(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use apache_avro::{AvroSchema, Reader};
+    use serde::{Deserialize, Serialize};
+    use tempfile::NamedTempFile;
+
+    use crate::{
+        core::item::{ItemReader, ItemWriter},
+        item::avro::{avro_reader::AvroItemReaderBuilder, avro_writer::AvroItemWriterBuilder},
+    };
+
+    #[derive(Serialize, Deserialize, AvroSchema, Debug, PartialEq, Clone)]
+    struct Car {
+        year: i32,
+        make: String,
+    }
+
+    #[test]
+    fn records_should_round_trip_through_a_file() {
+        let file = NamedTempFile::new().unwrap();
+        let cars = vec![
+            Car {
+                year: 1948,
+                make: "Porsche".to_string(),
+            },
+            Car {
+                year: 1967,
+                make: "Ford".to_string(),
+            },
+        ];
+
+        let schema = Car::get_schema();
+        let writer = AvroItemWriterBuilder::new()
+            .schema(&schema)
+            .from_path(file.path());
+
+        writer.write(&cars[..1]).unwrap();
+        ItemWriter::<Car>::flush(&writer).unwrap();
+        writer.write(&cars[1..]).unwrap();
+        ItemWriter::<Car>::close(&writer).unwrap();
+
+        let embedded = Reader::new(std::fs::File::open(file.path()).unwrap()).unwrap();
+        assert_eq!(embedded.writer_schema(), &schema);
+
+        let reader = AvroItemReaderBuilder::new().from_path(file.path());
+        let mut read: Vec<Car> = Vec::new();
+        while let Some(car) = reader.read().unwrap() {
+            read.push(car);
+        }
+
+        assert_eq!(read, cars);
+    }
+
+    #[test]
+    fn write_after_close_should_fail() {
+        let schema = Car::get_schema();
+        let writer = AvroItemWriterBuilder::new()
+            .schema(&schema)
+            .from_writer(Vec::new());
+
+        ItemWriter::<Car>::close(&writer).unwrap();
+
+        assert!(writer
+            .write(&[Car {
+                year: 1948,
+                make: "Porsche".to_string(),
+            }])
+            .is_err());
+    }
+}
@@ -0,0 +1,8 @@
+/// This module provides an Avro Object Container File (OCF) item reader.
+pub mod avro_reader;
+
+/// This module provides an Avro Object Container File (OCF) item writer.
+///
+/// The schema embedded in the file is either written explicitly or derived from the
+/// item type with `#[derive(AvroSchema)]` (`AvroSchema::get_schema()`).
+pub mod avro_writer;
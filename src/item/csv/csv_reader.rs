@@ -1,35 +1,153 @@
-use csv::{ReaderBuilder, StringRecordsIntoIter, Terminator, Trim};
+use csv::{Reader, ReaderBuilder, StringRecord, StringRecordsIntoIter, Terminator, Trim};
 use serde::de::DeserializeOwned;
-use std::{cell::RefCell, fs::File, io::Read, path::Path};
+use std::{
+    cell::{Cell, RefCell},
+    io::{stdin, Read, StdinLock},
+    path::Path,
+};
 
+#[cfg(feature = "datetime")]
+use crate::item::datetime::DateTimeFormat;
+#[cfg(feature = "http")]
+use crate::item::http::download::{open_url, ResponseBody};
+#[cfg(feature = "object-store")]
+use crate::item::object_store::download::{open_object, open_s3_object, ObjectBody};
 use crate::{
     core::item::{ItemReader, ItemReaderResult},
     error::BatchError,
+    item::file::{is_gzip_path, FileReader},
 };
 
+/// Defines how header names are normalized before being matched to struct fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NormalizeMode {
+    /// Trims and lowercases header names: `First Name` becomes `first name`.
+    Lowercase,
+    /// Lowercases header names and replaces every run of non-alphanumeric
+    /// characters by an underscore: `First Name` becomes `first_name`.
+    SnakeCase,
+    /// Lowercases header names and strips every non-alphanumeric character:
+    /// `First Name`, `first_name` and `FIRSTNAME` all become `firstname`.
+    Alphanumeric,
+}
+
+impl NormalizeMode {
+    /// Normalizes a header name according to the mode.
+    pub fn normalize(&self, header: &str) -> String {
+        let header = header.trim().to_lowercase();
+
+        match self {
+            NormalizeMode::Lowercase => header,
+            NormalizeMode::SnakeCase => header
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<&str>>()
+                .join("_"),
+            NormalizeMode::Alphanumeric => header.chars().filter(|c| c.is_alphanumeric()).collect(),
+        }
+    }
+}
+
 /// A CSV item reader that implements the `ItemReader` trait.
+///
+/// Records are delimited by the `csv` crate, following RFC 4180: a quoted field may
+/// contain delimiters and line breaks, and spans as many lines as needed. Fields are
+/// trimmed, quoted or not, so line breaks at the start or end of a field are dropped.
 pub struct CsvItemReader<R> {
     records: RefCell<StringRecordsIntoIter<R>>,
+    headers: Option<StringRecord>,
+    record: Cell<u64>,
+    #[cfg(feature = "datetime")]
+    datetime_columns: Vec<(usize, DateTimeFormat)>,
+}
+
+impl<R> CsvItemReader<R> {
+    /// Converts the configured datetime columns of a record to UTC.
+    #[cfg(feature = "datetime")]
+    fn convert_datetimes(&self, record: StringRecord) -> Result<StringRecord, BatchError> {
+        if self.datetime_columns.is_empty() {
+            return Ok(record);
+        }
+
+        record
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                match self
+                    .datetime_columns
+                    .iter()
+                    .find(|(column, _)| *column == index)
+                {
+                    Some((_, format)) => format.to_rfc3339(value).map_err(|error| {
+                        BatchError::deserialization(Some(self.record.get()), error)
+                    }),
+                    None => Ok(value.to_string()),
+                }
+            })
+            .collect()
+    }
+
+    /// Converts a deserialization error, naming the missing header when a field has no column.
+    fn deserialize_error(&self, error: csv::Error) -> BatchError {
+        if let (csv::ErrorKind::Deserialize { err, .. }, Some(headers)) =
+            (error.kind(), &self.headers)
+        {
+            if let csv::DeserializeErrorKind::Message(message) = err.kind() {
+                if let Some(field) = message.strip_prefix("missing field ") {
+                    let headers: Vec<&str> = headers.iter().collect();
+                    return BatchError::deserialization(
+                        Some(self.record.get()),
+                        format!(
+                            "missing header {} in CSV headers: {}",
+                            field,
+                            headers.join(",")
+                        ),
+                    );
+                }
+            }
+        }
+
+        self.record_error(error)
+    }
+
+    /// Converts an error of the CSV reader, I/O errors being distinguished from malformed records.
+    fn record_error(&self, error: csv::Error) -> BatchError {
+        if error.is_io_error() {
+            BatchError::ItemReader(error.to_string())
+        } else {
+            BatchError::deserialization(Some(self.record.get()), error)
+        }
+    }
+
+    #[cfg(not(feature = "datetime"))]
+    fn convert_datetimes(&self, record: StringRecord) -> Result<StringRecord, BatchError> {
+        Ok(record)
+    }
 }
 
 impl<R: Read, T: DeserializeOwned> ItemReader<T> for CsvItemReader<R> {
     /// Reads the next item from the CSV file.
     ///
     /// Returns `Ok(Some(record))` if a record is successfully read,
-    /// `Ok(None)` if there are no more records to read, and
-    /// `Err(BatchError::ItemReader(error))` if an error occurs during reading.
+    /// `Ok(None)` if there are no more records to read,
+    /// `Err(BatchError::Deserialization { .. })` with the number of the record, headers
+    /// excluded, if the record is malformed or cannot be deserialized, and
+    /// `Err(BatchError::ItemReader(error))` if the input cannot be read.
     fn read(&self) -> ItemReaderResult<T> {
         if let Some(result) = self.records.borrow_mut().next() {
+            self.record.set(self.record.get() + 1);
+
             match result {
                 Ok(string_record) => {
-                    let result: Result<T, _> = string_record.deserialize(None);
+                    let string_record = self.convert_datetimes(string_record)?;
+                    let result: Result<T, _> = string_record.deserialize(self.headers.as_ref());
 
                     match result {
                         Ok(record) => Ok(Some(record)),
-                        Err(error) => Err(BatchError::ItemReader(error.to_string())),
+                        Err(error) => Err(self.deserialize_error(error)),
                     }
                 }
-                Err(error) => Err(BatchError::ItemReader(error.to_string())),
+                Err(error) => Err(self.record_error(error)),
             }
         } else {
             Ok(None)
@@ -43,6 +161,14 @@ pub struct CsvItemReaderBuilder {
     delimiter: u8,
     terminator: Terminator,
     has_headers: bool,
+    by_header: bool,
+    flexible: bool,
+    normalize_headers: Option<NormalizeMode>,
+    comment: Option<u8>,
+    skip_lines: usize,
+    gzip: bool,
+    #[cfg(feature = "datetime")]
+    datetime_formats: Vec<DateTimeFormat>,
 }
 
 impl CsvItemReaderBuilder {
@@ -52,6 +178,14 @@ impl CsvItemReaderBuilder {
             delimiter: b',',
             terminator: Terminator::CRLF,
             has_headers: false,
+            by_header: false,
+            flexible: false,
+            normalize_headers: None,
+            comment: None,
+            skip_lines: 0,
+            gzip: false,
+            #[cfg(feature = "datetime")]
+            datetime_formats: Vec::new(),
         }
     }
 
@@ -73,37 +207,357 @@ impl CsvItemReaderBuilder {
         self
     }
 
+    /// Deserializes items by header name instead of by position.
+    ///
+    /// The columns can then be in any order, and a field without matching column fails
+    /// with an error naming the header. This option has no effect when the CSV file has
+    /// no headers.
+    pub fn by_header(mut self, yes: bool) -> Self {
+        self.by_header = yes;
+        self
+    }
+
+    /// Accepts records with a number of fields different from the headers or the first record.
+    ///
+    /// When items are deserialized by header, missing trailing fields can then be
+    /// deserialized into `Option` fields.
+    pub fn flexible(mut self, yes: bool) -> Self {
+        self.flexible = yes;
+        self
+    }
+
+    /// Ignores the lines starting with the `comment` character, such as `#` banners.
+    ///
+    /// Commented lines are skipped wherever they appear, including before the header.
+    pub fn comment(mut self, comment: u8) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    /// Drops a fixed number of preamble lines before the header (or the first record).
+    ///
+    /// Lines are counted as they are in the input, whatever their content.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spring_batch_rs::core::item::ItemReader;
+    /// use spring_batch_rs::item::csv::csv_reader::CsvItemReaderBuilder;
+    ///
+    /// let reader = CsvItemReaderBuilder::new()
+    ///     .has_headers(true)
+    ///     .skip_lines(2)
+    ///     .from_reader("Exported on 2024-01-01, by admin\n\nname\nAlice".as_bytes());
+    ///
+    /// let name: (String,) = reader.read().unwrap().unwrap();
+    /// assert_eq!(name.0, "Alice");
+    /// ```
+    pub fn skip_lines(mut self, lines: usize) -> Self {
+        self.skip_lines = lines;
+        self
+    }
+
+    /// Normalizes header names before matching them to struct fields.
+    ///
+    /// Items are then deserialized by header name instead of by position, so struct
+    /// fields must be named (or `#[serde(rename)]`d) after the normalized headers.
+    /// This option has no effect when the CSV file has no headers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde::Deserialize;
+    /// use spring_batch_rs::core::item::ItemReader;
+    /// use spring_batch_rs::item::csv::csv_reader::{CsvItemReaderBuilder, NormalizeMode};
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Person {
+    ///     #[serde(rename = "firstname")]
+    ///     first_name: String,
+    /// }
+    ///
+    /// let reader = CsvItemReaderBuilder::new()
+    ///     .has_headers(true)
+    ///     .normalize_headers(NormalizeMode::Alphanumeric)
+    ///     .from_reader("First Name\nAlice".as_bytes());
+    ///
+    /// let person: Person = reader.read().unwrap().unwrap();
+    /// assert_eq!(person.first_name, "Alice");
+    /// ```
+    pub fn normalize_headers(mut self, mode: NormalizeMode) -> Self {
+        self.normalize_headers = Some(mode);
+        self
+    }
+
+    /// Parses the named column with a `chrono` format, as a local datetime in the given timezone.
+    ///
+    /// Values are normalized to UTC, so the field can be deserialized into a `chrono::DateTime<Utc>`.
+    /// The column is looked up by header name (after normalization, if enabled), so this option
+    /// requires the CSV file to have headers.
+    ///
+    /// # Panics
+    ///
+    /// Creating the reader panics if `has_headers` is not set, or if the headers of the
+    /// file do not contain the column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{DateTime, Utc};
+    /// use chrono_tz::Europe::Paris;
+    /// use serde::Deserialize;
+    /// use spring_batch_rs::core::item::ItemReader;
+    /// use spring_batch_rs::item::csv::csv_reader::CsvItemReaderBuilder;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Event {
+    ///     name: String,
+    ///     date: DateTime<Utc>,
+    /// }
+    ///
+    /// let reader = CsvItemReaderBuilder::new()
+    ///     .has_headers(true)
+    ///     .datetime_format("date", "%d/%m/%Y %H:%M", Paris)
+    ///     .from_reader("name,date\nlaunch,15/07/2024 14:30".as_bytes());
+    ///
+    /// let event: Event = reader.read().unwrap().unwrap();
+    /// assert_eq!(event.date.to_rfc3339(), "2024-07-15T12:30:00+00:00");
+    /// ```
+    #[cfg(feature = "datetime")]
+    pub fn datetime_format(mut self, field: &str, format: &str, tz: chrono_tz::Tz) -> Self {
+        self.datetime_formats
+            .push(DateTimeFormat::new(field, format, tz));
+        self
+    }
+
     /// Creates a `CsvItemReader` from a reader.
-    pub fn from_reader<R: Read>(self, rdr: R) -> CsvItemReader<R> {
-        let rdr = ReaderBuilder::new()
-            .trim(Trim::All)
-            .delimiter(self.delimiter)
-            .terminator(self.terminator)
-            .has_headers(self.has_headers)
-            .flexible(false)
-            .from_reader(rdr);
+    pub fn from_reader<R: Read>(self, mut rdr: R) -> CsvItemReader<R> {
+        skip_lines(&mut rdr, self.skip_lines);
+        let rdr = self.reader_builder().from_reader(rdr);
 
-        let records = rdr.into_records();
+        self.build(rdr)
+    }
 
-        CsvItemReader {
-            records: RefCell::new(records),
-        }
+    /// Creates a `CsvItemReader` reading from the standard input, so that data can be piped
+    /// into a batch job (`cat data.csv | mytool`).
+    ///
+    /// The reader locks stdin for its whole lifetime: only one reader can own stdin,
+    /// and any other attempt to read from it blocks until the reader is dropped.
+    /// Reads block until data is available, and the reader ends when stdin is closed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use spring_batch_rs::item::csv::csv_reader::CsvItemReaderBuilder;
+    ///
+    /// let reader = CsvItemReaderBuilder::new()
+    ///     .has_headers(true)
+    ///     .from_stdin();
+    /// ```
+    pub fn from_stdin(self) -> CsvItemReader<StdinLock<'static>> {
+        self.from_reader(stdin().lock())
+    }
+
+    /// Creates a `CsvItemReader` reading the body of the response to a `GET` request to `url`.
+    ///
+    /// The body is streamed from the connection as items are read.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BatchError::ItemReader` if the request fails or the response status
+    /// is not `2xx`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use spring_batch_rs::item::csv::csv_reader::CsvItemReaderBuilder;
+    ///
+    /// let reader = CsvItemReaderBuilder::new()
+    ///     .has_headers(true)
+    ///     .from_url("https://example.com/data.csv")
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "http")]
+    pub fn from_url(self, url: &str) -> Result<CsvItemReader<ResponseBody>, BatchError> {
+        Ok(self.from_reader(open_url(url)?))
+    }
+
+    /// Creates a `CsvItemReader` reading the object `key` of the S3 `bucket`.
+    ///
+    /// The region, the endpoint and the credentials are read from the standard `AWS_*`
+    /// environment variables; use `from_object_store` to set them explicitly.
+    /// The object is streamed from the store as items are read.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BatchError::ItemReader` if the object cannot be fetched.
+    #[cfg(feature = "object-store")]
+    pub fn from_s3(self, bucket: &str, key: &str) -> Result<CsvItemReader<ObjectBody>, BatchError> {
+        Ok(self.from_reader(open_s3_object(bucket, key)?))
+    }
+
+    /// Creates a `CsvItemReader` reading the object at `location` in `store`.
+    ///
+    /// The object is streamed from the store as items are read.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BatchError::ItemReader` if the object cannot be fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    ///
+    /// use spring_batch_rs::item::csv::csv_reader::CsvItemReaderBuilder;
+    /// use spring_batch_rs::item::object_store::download::S3Builder;
+    ///
+    /// let store = S3Builder::new()
+    ///     .with_bucket_name("exports")
+    ///     .with_region("eu-west-3")
+    ///     .with_access_key_id("AKIA...")
+    ///     .with_secret_access_key("...")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let reader = CsvItemReaderBuilder::new()
+    ///     .has_headers(true)
+    ///     .from_object_store(Arc::new(store), "daily/persons.csv")
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "object-store")]
+    pub fn from_object_store(
+        self,
+        store: std::sync::Arc<dyn object_store::ObjectStore>,
+        location: &str,
+    ) -> Result<CsvItemReader<ObjectBody>, BatchError> {
+        Ok(self.from_reader(open_object(store, location)?))
+    }
+
+    /// Decompresses the file opened by `from_path` with gzip, whatever its extension.
+    #[cfg(feature = "gzip")]
+    pub fn gzip(mut self, yes: bool) -> Self {
+        self.gzip = yes;
+        self
     }
 
     /// Creates a `CsvItemReader` from a file path.
-    pub fn from_path<R: AsRef<Path>>(self, path: R) -> CsvItemReader<File> {
-        let rdr = ReaderBuilder::new()
+    ///
+    /// With the `gzip` feature, files with a `.gz` extension are decompressed while read.
+    pub fn from_path<R: AsRef<Path>>(self, path: R) -> CsvItemReader<FileReader> {
+        let gzip = self.gzip || is_gzip_path(&path);
+        let file = FileReader::open(path, gzip).expect("Unable to open file");
+
+        self.from_reader(file)
+    }
+
+    /// Creates a `CsvItemReader` reading the entry named `entry` of the zip archive at `path`.
+    ///
+    /// The entry is decompressed while read, without extracting the archive.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BatchError::ItemReader` if the archive cannot be opened, if it has no
+    /// such entry, or if the entry is encrypted or compressed with another method than deflate.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use spring_batch_rs::item::csv::csv_reader::CsvItemReaderBuilder;
+    ///
+    /// let reader = CsvItemReaderBuilder::new()
+    ///     .has_headers(true)
+    ///     .from_zip_entry("export.zip", "people.csv")
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "zip")]
+    pub fn from_zip_entry<P: AsRef<Path>>(
+        self,
+        path: P,
+        entry: &str,
+    ) -> Result<CsvItemReader<FileReader>, BatchError> {
+        let file = FileReader::open_zip_entry(path, entry)
+            .map_err(|error| BatchError::ItemReader(error.to_string()))?;
+
+        Ok(self.from_reader(file))
+    }
+
+    /// Creates the underlying CSV reader builder from the configuration.
+    fn reader_builder(&self) -> ReaderBuilder {
+        let mut builder = ReaderBuilder::new();
+
+        builder
             .trim(Trim::All)
             .delimiter(self.delimiter)
             .terminator(self.terminator)
             .has_headers(self.has_headers)
-            .flexible(false)
-            .from_path(path);
+            .flexible(self.flexible)
+            .comment(self.comment);
+
+        builder
+    }
+
+    /// Creates a `CsvItemReader` from a configured CSV reader.
+    fn build<R: Read>(self, mut rdr: Reader<R>) -> CsvItemReader<R> {
+        let headers = match self.normalize_headers {
+            _ if !self.has_headers => None,
+            Some(mode) => rdr.headers().ok().map(|headers| {
+                headers
+                    .iter()
+                    .map(|header| mode.normalize(header))
+                    .collect()
+            }),
+            None if self.by_header => rdr.headers().ok().cloned(),
+            None => None,
+        };
 
-        let records = rdr.unwrap().into_records();
+        #[cfg(feature = "datetime")]
+        let datetime_columns = {
+            assert!(
+                self.has_headers || self.datetime_formats.is_empty(),
+                "datetime_format requires has_headers(true)"
+            );
+            let columns = headers.clone().or_else(|| rdr.headers().ok().cloned());
+
+            // The headers of an empty or unreadable file are not checked: reading it fails
+            // or returns no record
+            self.datetime_formats
+                .into_iter()
+                .filter_map(|format| {
+                    let columns = columns.as_ref().filter(|columns| !columns.is_empty())?;
+                    let index = columns.iter().position(|column| column == format.field());
+                    let index = index.unwrap_or_else(|| {
+                        panic!("Unknown datetime_format column: {}", format.field())
+                    });
+                    Some((index, format))
+                })
+                .collect()
+        };
+
+        let records = rdr.into_records();
 
         CsvItemReader {
             records: RefCell::new(records),
+            headers,
+            record: Cell::new(0),
+            #[cfg(feature = "datetime")]
+            datetime_columns,
+        }
+    }
+}
+
+/// Consumes `lines` lines of the input, one byte at a time so that nothing past them is lost.
+///
+/// A read error stops the skipping: it is reported by the first read of an item.
+fn skip_lines<R: Read>(rdr: &mut R, lines: usize) {
+    let mut remaining = lines;
+    let mut byte = [0u8; 1];
+
+    while remaining > 0 {
+        match rdr.read(&mut byte) {
+            Ok(0) | Err(_) => break,
+            Ok(_) if byte[0] == b'\n' => remaining -= 1,
+            Ok(_) => {}
         }
     }
 }
@@ -113,8 +567,21 @@ mod tests {
     use std::error::Error;
 
     use csv::StringRecord;
+    use serde::Deserialize;
 
-    use crate::item::csv::csv_reader::CsvItemReaderBuilder;
+    use crate::{
+        core::item::ItemReader,
+        item::csv::csv_reader::{CsvItemReaderBuilder, NormalizeMode},
+        BatchError,
+    };
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Person {
+        #[serde(rename = "firstname")]
+        first_name: String,
+        #[serde(rename = "lastname")]
+        last_name: String,
+    }
 
     #[test]
     fn this_test_will_pass() -> Result<(), Box<dyn Error>> {
@@ -142,4 +609,320 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn normalize_mode_should_transform_headers() {
+        assert_eq!(
+            NormalizeMode::Lowercase.normalize(" First Name "),
+            "first name"
+        );
+        assert_eq!(
+            NormalizeMode::SnakeCase.normalize("First  Name"),
+            "first_name"
+        );
+        assert_eq!(
+            NormalizeMode::SnakeCase.normalize("first_name"),
+            "first_name"
+        );
+        assert_eq!(
+            NormalizeMode::Alphanumeric.normalize("First Name"),
+            "firstname"
+        );
+        assert_eq!(
+            NormalizeMode::Alphanumeric.normalize("first_name"),
+            "firstname"
+        );
+        assert_eq!(
+            NormalizeMode::Alphanumeric.normalize("FIRSTNAME"),
+            "firstname"
+        );
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn datetime_should_be_parsed_in_timezone() -> Result<(), Box<dyn Error>> {
+        use chrono::{DateTime, Utc};
+        use chrono_tz::Europe::Paris;
+
+        #[derive(Deserialize)]
+        struct Event {
+            name: String,
+            date: DateTime<Utc>,
+        }
+
+        let reader = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .datetime_format("date", "%d/%m/%Y %H:%M", Paris)
+            .from_reader("name,date\nwinter,15/01/2024 14:30\nsummer,15/07/2024 14:30".as_bytes());
+
+        let winter: Event = reader.read()?.unwrap();
+        assert_eq!(winter.name, "winter");
+        assert_eq!(winter.date.to_rfc3339(), "2024-01-15T13:30:00+00:00");
+
+        let summer: Event = reader.read()?.unwrap();
+        assert_eq!(summer.date.to_rfc3339(), "2024-07-15T12:30:00+00:00");
+
+        let result: Result<Option<Event>, _> = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .datetime_format("date", "%d/%m/%Y %H:%M", Paris)
+            .from_reader("name,date\ninvalid,2024-01-15".as_bytes())
+            .read();
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    #[should_panic(expected = "Unknown datetime_format column: when")]
+    fn datetime_format_of_an_unknown_column_should_panic() {
+        CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .datetime_format("when", "%d/%m/%Y %H:%M", chrono_tz::Europe::Paris)
+            .from_reader("name,date\nwinter,15/01/2024 14:30".as_bytes());
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    #[should_panic(expected = "datetime_format requires has_headers(true)")]
+    fn datetime_format_without_headers_should_panic() {
+        CsvItemReaderBuilder::new()
+            .datetime_format("date", "%d/%m/%Y %H:%M", chrono_tz::Europe::Paris)
+            .from_reader("winter,15/01/2024 14:30".as_bytes());
+    }
+
+    #[test]
+    fn mixed_case_headers_should_be_deserialized() -> Result<(), Box<dyn Error>> {
+        let inputs = [
+            "First Name,Last Name\nAlice,Smith",
+            "first_name,last_name\nAlice,Smith",
+            "LASTNAME,FIRSTNAME\nSmith,Alice",
+        ];
+
+        for input in inputs {
+            let reader = CsvItemReaderBuilder::new()
+                .has_headers(true)
+                .normalize_headers(NormalizeMode::Alphanumeric)
+                .from_reader(input.as_bytes());
+
+            let person: Option<Person> = reader.read()?;
+
+            assert_eq!(
+                person,
+                Some(Person {
+                    first_name: "Alice".to_string(),
+                    last_name: "Smith".to_string(),
+                })
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn columns_should_be_deserialized_by_position_by_default() -> Result<(), Box<dyn Error>> {
+        let reader = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .from_reader("First Name,Last Name\nAlice,Smith".as_bytes());
+
+        let person: Person = reader.read()?.unwrap();
+        assert_eq!(person.first_name, "Alice");
+        assert_eq!(person.last_name, "Smith");
+
+        Ok(())
+    }
+
+    #[test]
+    fn reordered_columns_should_be_deserialized_by_header() -> Result<(), Box<dyn Error>> {
+        let reader = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .by_header(true)
+            .from_reader("lastname,firstname\nSmith,Alice".as_bytes());
+
+        let person: Person = reader.read()?.unwrap();
+        assert_eq!(person.first_name, "Alice");
+        assert_eq!(person.last_name, "Smith");
+
+        let result: Result<Option<Person>, _> = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .by_header(true)
+            .from_reader("firstname,surname\nAlice,Smith".as_bytes())
+            .read();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Error occurred while deserializing record 1: missing header `lastname` in CSV headers: firstname,surname"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn flexible_reader_should_accept_short_records() -> Result<(), Box<dyn Error>> {
+        #[derive(Deserialize)]
+        struct Contact {
+            name: String,
+            phone: Option<String>,
+        }
+
+        let csv = "name,phone\nAlice,0102\nBob";
+
+        let strict = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .by_header(true)
+            .from_reader(csv.as_bytes());
+        let _: Contact = strict.read()?.unwrap();
+        assert!(ItemReader::<Contact>::read(&strict).is_err());
+
+        let flexible = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .by_header(true)
+            .flexible(true)
+            .from_reader(csv.as_bytes());
+        let _: Contact = flexible.read()?.unwrap();
+        let bob: Contact = flexible.read()?.unwrap();
+        assert_eq!(bob.name, "Bob");
+        assert_eq!(bob.phone, None);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn records_should_be_read_from_url() {
+        use crate::item::http::download::tests::serve;
+
+        let url = serve(200, "firstname,lastname\nAlice,Martin\n");
+
+        let reader = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .from_url(&url)
+            .unwrap();
+
+        let person: Person = reader.read().unwrap().unwrap();
+        assert_eq!(person.first_name, "Alice");
+        assert!(ItemReader::<Person>::read(&reader).unwrap().is_none());
+    }
+
+    #[cfg(feature = "object-store")]
+    #[test]
+    fn records_should_be_read_from_an_object_store() {
+        use crate::item::object_store::download::tests::memory_store;
+
+        let store = memory_store("exports/persons.csv", "firstname,lastname\nAlice,Martin\n");
+
+        let reader = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .from_object_store(store, "exports/persons.csv")
+            .unwrap();
+
+        let person: Person = reader.read().unwrap().unwrap();
+        assert_eq!(person.first_name, "Alice");
+        assert!(ItemReader::<Person>::read(&reader).unwrap().is_none());
+    }
+
+    #[test]
+    fn malformed_record_should_be_a_deserialization_error() {
+        let reader = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .from_reader("firstname,lastname\nAlice,Smith\nBob\n".as_bytes());
+
+        let _: Person = reader.read().unwrap().unwrap();
+        let result: Result<Option<Person>, _> = reader.read();
+
+        let Err(BatchError::Deserialization { record, source }) = result else {
+            panic!("expected a deserialization error");
+        };
+        assert_eq!(record, Some(2));
+        assert!(source.get_ref().downcast_ref::<csv::Error>().is_some());
+    }
+
+    #[test]
+    fn comments_and_preamble_lines_should_be_ignored() -> Result<(), Box<dyn Error>> {
+        let data = "Export generated by the legacy system
+Do not edit by hand
+# ----------------
+# people registry
+# ----------------
+
+
+firstname,lastname
+# disabled,person
+Alice,Smith
+Bob,Jones
+";
+
+        let reader = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .comment(b'#')
+            .skip_lines(2)
+            .from_reader(data.as_bytes());
+
+        let first: Person = reader.read()?.unwrap();
+        let second: Person = reader.read()?.unwrap();
+
+        assert_eq!(first.first_name, "Alice");
+        assert_eq!(second.last_name, "Jones");
+        let end: Option<Person> = reader.read()?;
+        assert!(end.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn quoted_line_breaks_should_belong_to_the_field() -> Result<(), Box<dyn Error>> {
+        #[derive(Deserialize)]
+        struct Note {
+            author: String,
+            text: String,
+        }
+
+        let data = "author,text\r\nAlice,\"first line\r\nsecond, \"\"quoted\"\" line\nthird line\"\r\nBob,single\r\n";
+
+        let reader = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .from_reader(data.as_bytes());
+
+        let first: Note = reader.read()?.unwrap();
+        let second: Note = reader.read()?.unwrap();
+
+        assert_eq!(first.author, "Alice");
+        assert_eq!(
+            first.text,
+            "first line\r\nsecond, \"quoted\" line\nthird line"
+        );
+        assert_eq!(second.author, "Bob");
+        assert_eq!(second.text, "single");
+        assert!(ItemReader::<Note>::read(&reader)?.is_none());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "zip")]
+    #[test]
+    fn records_should_be_read_from_a_zip_entry() -> Result<(), Box<dyn Error>> {
+        use crate::item::file::zip_tests::zip_archive;
+        use zip::CompressionMethod;
+
+        let archive = zip_archive(&[
+            ("README.txt", "people export", CompressionMethod::Stored),
+            (
+                "people.csv",
+                "firstname,lastname\nAlice,Smith",
+                CompressionMethod::Deflated,
+            ),
+        ]);
+
+        let reader = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .from_zip_entry(archive.path(), "people.csv")?;
+        let person: Person = reader.read()?.unwrap();
+        assert_eq!(person.last_name, "Smith");
+
+        let error = CsvItemReaderBuilder::new()
+            .from_zip_entry(archive.path(), "cars.csv")
+            .err()
+            .unwrap();
+        assert!(matches!(error, BatchError::ItemReader(message) if message.contains("cars.csv")));
+
+        Ok(())
+    }
 }
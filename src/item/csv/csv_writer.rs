@@ -1,25 +1,56 @@
-use std::{cell::RefCell, fs::File, io::Write, path::Path};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    fs::{self, File},
+    io::{self, ErrorKind, Write},
+    path::Path,
+};
 
-use csv::{Writer, WriterBuilder};
+use csv::{QuoteStyle, Terminator, Writer, WriterBuilder};
 use serde::Serialize;
 
 use crate::{
     core::item::{ItemWriter, ItemWriterResult},
+    item::file::{is_gzip_path, FileWriter},
     BatchError,
 };
 
+/// Completes the underlying writer when the item writer is closed.
+type FinishFn<T> = fn(&T) -> io::Result<()>;
+
 pub struct CsvItemWriter<T: Write> {
     writer: RefCell<Writer<T>>,
+    stop_on_broken_pipe: bool,
+    closed: Cell<bool>,
+    finish: Option<FinishFn<T>>,
+}
+
+impl<T: Write> CsvItemWriter<T> {
+    /// Returns `true` if the error is a broken pipe to be treated as the end of the step.
+    fn is_stop(&self, error: &io::Error) -> bool {
+        self.stop_on_broken_pipe && error.kind() == ErrorKind::BrokenPipe
+    }
 }
 
 impl<T: Write, R: Serialize> ItemWriter<R> for CsvItemWriter<T> {
     fn write(&self, items: &[R]) -> ItemWriterResult {
+        if self.closed.get() {
+            return Ok(());
+        }
+
         for item in items.iter() {
             let result = self.writer.borrow_mut().serialize(item);
 
             if result.is_err() {
                 let error = result.err().unwrap();
-                return Err(BatchError::ItemWriter(error.to_string()));
+                if let csv::ErrorKind::Io(io_error) = error.kind() {
+                    if self.is_stop(io_error) {
+                        self.closed.set(true);
+                        return Ok(());
+                    }
+                    return Err(BatchError::ItemWriter(error.to_string()));
+                }
+                return Err(BatchError::serialization(None, error));
             }
         }
         Ok(())
@@ -32,25 +63,62 @@ impl<T: Write, R: Serialize> ItemWriter<R> for CsvItemWriter<T> {
     ///
     /// Note that this also flushes the underlying writer.
     fn flush(&self) -> ItemWriterResult {
+        if self.closed.get() {
+            return Ok(());
+        }
+
         let result = self.writer.borrow_mut().flush();
         match result {
             Ok(()) => Ok(()),
+            Err(error) if self.is_stop(&error) => {
+                self.closed.set(true);
+                Ok(())
+            }
             Err(error) => Err(BatchError::ItemWriter(error.to_string())),
         }
     }
+
+    /// Flushes the writer and completes the file created by `from_path`.
+    fn close(&self) -> ItemWriterResult {
+        ItemWriter::<R>::flush(self)?;
+
+        match self.finish {
+            Some(finish) if !self.closed.get() => finish(self.writer.borrow().get_ref())
+                .map_err(|error| BatchError::ItemWriter(error.to_string())),
+            _ => Ok(()),
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        self.closed.get()
+    }
 }
 
 #[derive(Default)]
 pub struct CsvItemWriterBuilder {
     delimiter: u8,
+    quote: u8,
+    quote_style: QuoteStyle,
+    escape: Option<u8>,
+    terminator: Option<Terminator>,
     has_headers: bool,
+    stop_on_broken_pipe: bool,
+    gzip: bool,
+    append: bool,
 }
 
 impl CsvItemWriterBuilder {
     pub fn new() -> Self {
         Self {
             delimiter: b',',
+            quote: b'"',
+            quote_style: QuoteStyle::Necessary,
+            escape: None,
+            terminator: None,
             has_headers: false,
+            stop_on_broken_pipe: false,
+            gzip: false,
+            append: false,
         }
     }
 
@@ -59,20 +127,80 @@ impl CsvItemWriterBuilder {
         self
     }
 
+    /// Writes tab-separated values: a shortcut for `delimiter(b'\t')`.
+    pub fn tsv(self) -> Self {
+        self.delimiter(b'\t')
+    }
+
+    /// Sets the quote character. Defaults to `"`.
+    pub fn quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Sets when fields are quoted. Defaults to `QuoteStyle::Necessary`.
+    pub fn quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Escapes quotes inside quoted fields with the given character instead of doubling them.
+    pub fn escape(mut self, escape: u8) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    /// Sets the record terminator. Defaults to `\n`.
+    pub fn terminator(mut self, terminator: Terminator) -> Self {
+        self.terminator = Some(terminator);
+        self
+    }
+
     pub fn has_headers(mut self, yes: bool) -> Self {
         self.has_headers = yes;
         self
     }
 
-    pub fn from_path<R: AsRef<Path>>(self, path: R) -> CsvItemWriter<File> {
-        let writer = WriterBuilder::new()
-            .flexible(false)
-            .has_headers(self.has_headers)
-            .from_path(path);
+    /// Treats a broken pipe as the end of the step instead of a write error,
+    /// as Unix tools do when piped into a consumer that stops reading (`| head`).
+    pub fn stop_on_broken_pipe(mut self, yes: bool) -> Self {
+        self.stop_on_broken_pipe = yes;
+        self
+    }
 
-        CsvItemWriter {
-            writer: RefCell::new(writer.unwrap()),
+    /// Compresses the file created by `from_path` with gzip, whatever its extension.
+    #[cfg(feature = "gzip")]
+    pub fn gzip(mut self, yes: bool) -> Self {
+        self.gzip = yes;
+        self
+    }
+
+    /// Makes `from_path` write at the end of an existing file instead of truncating it.
+    ///
+    /// The header row is only written if the file is missing or empty, so that
+    /// successive runs append their records under a single header.
+    pub fn append(mut self, yes: bool) -> Self {
+        self.append = yes;
+        self
+    }
+
+    /// Creates a `CsvItemWriter` writing to a file.
+    ///
+    /// With the `gzip` feature, files with a `.gz` extension are compressed while written.
+    pub fn from_path<R: AsRef<Path>>(mut self, path: R) -> CsvItemWriter<FileWriter> {
+        let gzip = self.gzip || is_gzip_path(&path);
+        let file = if self.append {
+            let has_content = fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0);
+            self.has_headers &= !has_content;
+            FileWriter::append(path, gzip)
+        } else {
+            FileWriter::create(path, gzip)
         }
+        .expect("Unable to open file");
+
+        let mut writer = self.from_writer(file);
+        writer.finish = Some(FileWriter::finish);
+        writer
     }
 
     /// Serialize a single record using Serde.
@@ -121,13 +249,323 @@ impl CsvItemWriterBuilder {
     /// }
     /// ```
     pub fn from_writer<W: Write>(self, wtr: W) -> CsvItemWriter<W> {
-        let wtr = WriterBuilder::new()
+        let mut builder = WriterBuilder::new();
+        builder
             .flexible(false)
             .has_headers(self.has_headers)
-            .from_writer(wtr);
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .quote_style(self.quote_style);
+
+        if let Some(escape) = self.escape {
+            builder.double_quote(false).escape(escape);
+        }
+        if let Some(terminator) = self.terminator {
+            builder.terminator(terminator);
+        }
+
+        let wtr = builder.from_writer(wtr);
 
         CsvItemWriter {
             writer: RefCell::new(wtr),
+            stop_on_broken_pipe: self.stop_on_broken_pipe,
+            closed: Cell::new(false),
+            finish: None,
+        }
+    }
+}
+
+/// A CSV writer for records whose columns are only known at runtime.
+///
+/// Rows are written as `HashMap<String, String>` in the order of the configured columns.
+/// The header is emitted once, before the first row. Missing keys are written as
+/// empty fields. Keys that are not part of the columns make the write fail, unless
+/// the writer has been built with `ignore_extra_keys(true)`.
+pub struct DynamicCsvItemWriter<T: Write> {
+    writer: RefCell<Writer<T>>,
+    columns: Vec<String>,
+    ignore_extra_keys: bool,
+    header_written: Cell<bool>,
+}
+
+impl<T: Write> DynamicCsvItemWriter<T> {
+    /// Writes the header row if it has not been written yet.
+    fn write_header(&self) -> ItemWriterResult {
+        if self.header_written.get() {
+            return Ok(());
+        }
+
+        self.writer
+            .borrow_mut()
+            .write_record(&self.columns)
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+        self.header_written.set(true);
+
+        Ok(())
+    }
+
+    /// Orders the values of a row according to the columns.
+    fn to_record<'b>(
+        &'b self,
+        item: &'b HashMap<String, String>,
+    ) -> Result<Vec<&'b str>, BatchError> {
+        if !self.ignore_extra_keys {
+            if let Some(key) = item.keys().find(|key| !self.columns.contains(key)) {
+                return Err(BatchError::ItemWriter(format!("unknown column: {}", key)));
+            }
+        }
+
+        Ok(self
+            .columns
+            .iter()
+            .map(|column| item.get(column).map(String::as_str).unwrap_or_default())
+            .collect())
+    }
+}
+
+impl<T: Write> ItemWriter<HashMap<String, String>> for DynamicCsvItemWriter<T> {
+    fn write(&self, items: &[HashMap<String, String>]) -> ItemWriterResult {
+        self.write_header()?;
+
+        for item in items.iter() {
+            let record = self.to_record(item)?;
+
+            self.writer
+                .borrow_mut()
+                .write_record(record)
+                .map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Flush the contents of the internal buffer to the underlying writer.
+    fn flush(&self) -> ItemWriterResult {
+        let result = self.writer.borrow_mut().flush();
+        match result {
+            Ok(()) => Ok(()),
+            Err(error) => Err(BatchError::ItemWriter(error.to_string())),
+        }
+    }
+
+    /// Writes the header, so that it is present even if no row is written.
+    fn open(&self) -> ItemWriterResult {
+        self.write_header()
+    }
+}
+
+#[derive(Default)]
+pub struct DynamicCsvItemWriterBuilder {
+    delimiter: u8,
+    columns: Vec<String>,
+    ignore_extra_keys: bool,
+}
+
+impl DynamicCsvItemWriterBuilder {
+    pub fn new() -> Self {
+        Self {
+            delimiter: b',',
+            columns: Vec::new(),
+            ignore_extra_keys: false,
+        }
+    }
+
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the ordered list of columns written for each row.
+    pub fn columns(mut self, columns: Vec<String>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Ignores the keys of a row that are not part of the columns instead of failing.
+    pub fn ignore_extra_keys(mut self, yes: bool) -> Self {
+        self.ignore_extra_keys = yes;
+        self
+    }
+
+    pub fn from_path<R: AsRef<Path>>(self, path: R) -> DynamicCsvItemWriter<File> {
+        let writer = self.writer_builder().from_path(path);
+
+        self.build(writer.unwrap())
+    }
+
+    pub fn from_writer<W: Write>(self, wtr: W) -> DynamicCsvItemWriter<W> {
+        let wtr = self.writer_builder().from_writer(wtr);
+
+        self.build(wtr)
+    }
+
+    fn writer_builder(&self) -> WriterBuilder {
+        let mut builder = WriterBuilder::new();
+        builder
+            .flexible(false)
+            .has_headers(false)
+            .delimiter(self.delimiter);
+        builder
+    }
+
+    fn build<W: Write>(self, wtr: Writer<W>) -> DynamicCsvItemWriter<W> {
+        DynamicCsvItemWriter {
+            writer: RefCell::new(wtr),
+            columns: self.columns,
+            ignore_extra_keys: self.ignore_extra_keys,
+            header_written: Cell::new(false),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::core::item::ItemWriter;
+
+    use csv::{QuoteStyle, Terminator};
+
+    use super::{CsvItemWriterBuilder, DynamicCsvItemWriterBuilder};
+
+    fn row(values: &[(&str, &str)]) -> HashMap<String, String> {
+        values
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn rows_should_be_written_in_column_order() {
+        let writer = DynamicCsvItemWriterBuilder::new()
+            .columns(vec![
+                "id".to_string(),
+                "name".to_string(),
+                "city".to_string(),
+            ])
+            .delimiter(b';')
+            .from_writer(vec![]);
+
+        writer
+            .write(&[row(&[("name", "Alice"), ("id", "1"), ("city", "Paris")])])
+            .unwrap();
+        writer
+            .write(&[row(&[("id", "2"), ("name", "Bob")])])
+            .unwrap();
+
+        let content = writer.writer.into_inner().into_inner().unwrap();
+        assert_eq!(
+            String::from_utf8(content).unwrap(),
+            "id;name;city\n1;Alice;Paris\n2;Bob;\n"
+        );
+    }
+
+    #[test]
+    fn extra_keys_should_fail_unless_ignored() {
+        let columns = vec!["id".to_string()];
+        let item = row(&[("id", "1"), ("extra", "x")]);
+
+        let writer = DynamicCsvItemWriterBuilder::new()
+            .columns(columns.clone())
+            .from_writer(vec![]);
+        assert!(writer.write(std::slice::from_ref(&item)).is_err());
+
+        let writer = DynamicCsvItemWriterBuilder::new()
+            .columns(columns)
+            .ignore_extra_keys(true)
+            .from_writer(vec![]);
+        assert!(writer.write(&[item]).is_ok());
+    }
+
+    #[test]
+    fn header_should_be_written_once_on_open() {
+        let writer = DynamicCsvItemWriterBuilder::new()
+            .columns(vec!["id".to_string()])
+            .from_writer(vec![]);
+
+        writer.open().unwrap();
+        writer.write(&[row(&[("id", "1")])]).unwrap();
+
+        let content = writer.writer.into_inner().into_inner().unwrap();
+        assert_eq!(String::from_utf8(content).unwrap(), "id\n1\n");
+    }
+
+    #[test]
+    fn broken_pipe_should_close_the_writer_when_enabled() {
+        let (reader, pipe) = std::io::pipe().unwrap();
+        drop(reader);
+
+        let writer = CsvItemWriterBuilder::new()
+            .stop_on_broken_pipe(true)
+            .from_writer(pipe);
+
+        writer.write(&[("a", 1)]).unwrap();
+        assert!(ItemWriter::<(&str, i32)>::flush(&writer).is_ok());
+        assert!(ItemWriter::<(&str, i32)>::is_closed(&writer));
+
+        let (reader, pipe) = std::io::pipe().unwrap();
+        drop(reader);
+
+        let writer = CsvItemWriterBuilder::new().from_writer(pipe);
+
+        writer.write(&[("a", 1)]).unwrap();
+        assert!(ItemWriter::<(&str, i32)>::flush(&writer).is_err());
+        assert!(!ItemWriter::<(&str, i32)>::is_closed(&writer));
+    }
+
+    #[test]
+    fn fields_with_embedded_delimiters_should_be_quoted() {
+        let writer = CsvItemWriterBuilder::new().tsv().from_writer(vec![]);
+        writer.write(&[("a\tb", "c d")]).unwrap();
+
+        let content = writer.writer.into_inner().into_inner().unwrap();
+        assert_eq!(String::from_utf8(content).unwrap(), "\"a\tb\"\tc d\n");
+
+        let writer = CsvItemWriterBuilder::new()
+            .delimiter(b';')
+            .quote(b'\'')
+            .quote_style(QuoteStyle::NonNumeric)
+            .escape(b'\\')
+            .terminator(Terminator::CRLF)
+            .from_writer(vec![]);
+        writer.write(&[("it's;here", 42)]).unwrap();
+
+        let content = writer.writer.into_inner().into_inner().unwrap();
+        assert_eq!(String::from_utf8(content).unwrap(), "'it\\'s;here';42\r\n");
+    }
+
+    #[test]
+    fn appending_runs_should_keep_a_single_header() {
+        #[derive(serde::Serialize)]
+        struct Car {
+            year: u16,
+            make: &'static str,
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cars.csv");
+
+        for car in [
+            Car {
+                year: 1948,
+                make: "Porsche",
+            },
+            Car {
+                year: 2011,
+                make: "Peugeot",
+            },
+        ] {
+            let writer = CsvItemWriterBuilder::new()
+                .has_headers(true)
+                .append(true)
+                .from_path(&path);
+
+            writer.write(&[car]).unwrap();
+            ItemWriter::<Car>::close(&writer).unwrap();
+        }
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "year,make\n1948,Porsche\n2011,Peugeot\n"
+        );
+    }
+}
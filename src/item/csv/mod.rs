@@ -1,4 +1,8 @@
 /// This module provides functionality for reading CSV files.
+///
+/// Monetary values should be deserialized into `rust_decimal::Decimal` rather than `f64`.
+/// Annotate such fields with `#[serde(with = "rust_decimal::serde::str")]` (feature `serde-with-str`
+/// of `rust_decimal`) so that values are parsed from their textual form without going through a float.
 pub mod csv_reader;
 
 /// This module provides a CSV item writer implementation for Spring Batch.
@@ -0,0 +1,102 @@
+use chrono::{DateTime, LocalResult, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// Describes how a datetime field is parsed by a reader.
+///
+/// The value of the field is parsed with a `chrono` format as a local datetime in
+/// the given timezone, then normalized to UTC and handed to serde as an RFC 3339
+/// string, so that it can be deserialized into a `chrono::DateTime<Utc>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateTimeFormat {
+    field: String,
+    format: String,
+    tz: Tz,
+}
+
+impl DateTimeFormat {
+    /// Creates a new `DateTimeFormat`.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The name of the field to parse.
+    /// * `format` - The `chrono` format of the field, for example `%d/%m/%Y %H:%M`.
+    /// * `tz` - The timezone in which the values are expressed.
+    pub fn new(field: &str, format: &str, tz: Tz) -> Self {
+        Self {
+            field: field.to_string(),
+            format: format.to_string(),
+            tz,
+        }
+    }
+
+    /// Returns the name of the field to parse.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Parses a value and converts it to UTC.
+    ///
+    /// Ambiguous local times (when clocks go back) resolve to the earliest instant.
+    /// Local times that do not exist (when clocks go forward) are rejected.
+    pub fn parse(&self, value: &str) -> Result<DateTime<Utc>, String> {
+        let naive = NaiveDateTime::parse_from_str(value, &self.format)
+            .map_err(|error| format!("field {}: {}: {}", self.field, value, error))?;
+
+        match self.tz.from_local_datetime(&naive) {
+            LocalResult::Single(datetime) | LocalResult::Ambiguous(datetime, _) => {
+                Ok(datetime.with_timezone(&Utc))
+            }
+            LocalResult::None => Err(format!(
+                "field {}: {} does not exist in {}",
+                self.field, value, self.tz
+            )),
+        }
+    }
+
+    /// Parses a value and formats it as an RFC 3339 UTC datetime.
+    pub fn to_rfc3339(&self, value: &str) -> Result<String, String> {
+        self.parse(value).map(|datetime| datetime.to_rfc3339())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono_tz::Europe::Paris;
+
+    use super::DateTimeFormat;
+
+    #[test]
+    fn paris_time_should_be_normalized_to_utc() {
+        let format = DateTimeFormat::new("date", "%d/%m/%Y %H:%M", Paris);
+
+        // Winter time: UTC+1
+        assert_eq!(
+            format.to_rfc3339("15/01/2024 14:30").unwrap(),
+            "2024-01-15T13:30:00+00:00"
+        );
+        // Summer time: UTC+2
+        assert_eq!(
+            format.to_rfc3339("15/07/2024 14:30").unwrap(),
+            "2024-07-15T12:30:00+00:00"
+        );
+    }
+
+    #[test]
+    fn ambiguous_time_should_resolve_to_earliest() {
+        let format = DateTimeFormat::new("date", "%d/%m/%Y %H:%M", Paris);
+
+        assert_eq!(
+            format.to_rfc3339("27/10/2024 02:30").unwrap(),
+            "2024-10-27T00:30:00+00:00"
+        );
+    }
+
+    #[test]
+    fn invalid_values_should_fail() {
+        let format = DateTimeFormat::new("date", "%d/%m/%Y %H:%M", Paris);
+
+        assert!(format.parse("2024-01-15").is_err());
+        // Skipped hour when clocks go forward
+        assert!(format.parse("31/03/2024 02:30").is_err());
+    }
+}
@@ -0,0 +1,346 @@
+use std::{
+    cell::Cell,
+    io::{Cursor, Read, Seek},
+    marker::PhantomData,
+    path::Path,
+};
+
+use calamine::{
+    open_workbook_auto, open_workbook_auto_from_rs, Data, Range, Reader, RowDeserializer, Sheets,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    core::item::{ItemReader, ItemReaderResult},
+    BatchError,
+};
+
+/// A reader deserializing the rows of a sheet of an Excel workbook.
+///
+/// The first row is used as headers, and each following row is deserialized into
+/// the item by header name. Empty rows are skipped.
+///
+/// Cells are converted to the types of the item fields as follows:
+/// - numeric fields accept numeric cells, truncated for integer fields, and text cells
+///   holding a number;
+/// - `String` fields accept any cell, numbers and booleans being formatted;
+/// - `bool` fields accept boolean cells, numbers (true when not zero) and the texts
+///   `true` and `false`;
+/// - `Option` fields are `None` for empty cells;
+/// - date cells hold the serial number of the date in Excel, and are read into
+///   `chrono` fields with `calamine::deserialize_as_datetime_or_none` or
+///   `calamine::deserialize_as_date_or_none`, as in the example of
+///   [`ExcelItemReaderBuilder`].
+///
+/// Cells holding an error such as `#DIV/0!` make the read fail.
+pub struct ExcelItemReader<T> {
+    _pd: PhantomData<T>,
+    range: Range<Data>,
+    headers: Vec<String>,
+    columns: Vec<usize>,
+    next_row: Cell<usize>,
+}
+
+impl<T: DeserializeOwned> ItemReader<T> for ExcelItemReader<T> {
+    /// Reads the next non-empty row of the sheet.
+    ///
+    /// Returns `Ok(None)` after the last row, or `Err(BatchError::Deserialization { .. })`
+    /// with the row number, as displayed in Excel, if the row cannot be deserialized.
+    fn read(&self) -> ItemReaderResult<T> {
+        let (first_row, first_column) = self.range.start().unwrap_or_default();
+
+        loop {
+            let index = self.next_row.get();
+            if index >= self.range.height() {
+                return Ok(None);
+            }
+            let row = &self.range[index];
+            self.next_row.set(index + 1);
+
+            if row.iter().all(|cell| *cell == Data::Empty) {
+                continue;
+            }
+
+            let position = (first_row + index as u32, first_column);
+            let deserializer =
+                RowDeserializer::new(&self.columns, Some(&self.headers), row, position);
+
+            return T::deserialize(deserializer)
+                .map(Some)
+                .map_err(|error| BatchError::deserialization(Some(position.0 as u64 + 1), error));
+        }
+    }
+}
+
+/// The sheet read by an `ExcelItemReader`.
+enum Sheet {
+    Index(usize),
+    Name(String),
+}
+
+/// A builder for configuring Excel item reading.
+///
+/// # Examples
+///
+/// ```no_run
+/// use chrono::NaiveDateTime;
+/// use serde::Deserialize;
+/// use spring_batch_rs::core::item::ItemReader;
+/// use spring_batch_rs::item::excel::excel_reader::ExcelItemReaderBuilder;
+///
+/// #[derive(Deserialize)]
+/// struct Order {
+///     reference: String,
+///     quantity: u32,
+///     #[serde(deserialize_with = "calamine::deserialize_as_datetime_or_none")]
+///     shipped_at: Option<NaiveDateTime>,
+/// }
+///
+/// let reader = ExcelItemReaderBuilder::new()
+///     .sheet_name("Orders")
+///     .skip_rows(2)
+///     .from_path("orders.xlsx");
+///
+/// while let Some(order) = ItemReader::<Order>::read(&reader).unwrap() {
+///     println!("{}: {}", order.reference, order.quantity);
+/// }
+/// ```
+pub struct ExcelItemReaderBuilder<T> {
+    _pd: PhantomData<T>,
+    sheet: Sheet,
+    skip_rows: usize,
+}
+
+impl<T: DeserializeOwned> Default for ExcelItemReaderBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: DeserializeOwned> ExcelItemReaderBuilder<T> {
+    /// Creates a new `ExcelItemReaderBuilder`, reading the first sheet.
+    pub fn new() -> Self {
+        Self {
+            _pd: PhantomData,
+            sheet: Sheet::Index(0),
+            skip_rows: 0,
+        }
+    }
+
+    /// Reads the sheet with the given name.
+    pub fn sheet_name(mut self, name: &str) -> Self {
+        self.sheet = Sheet::Name(name.to_string());
+        self
+    }
+
+    /// Reads the sheet at the given position in the workbook, starting at 0.
+    pub fn sheet_index(mut self, index: usize) -> Self {
+        self.sheet = Sheet::Index(index);
+        self
+    }
+
+    /// Skips the given number of rows before the header row, such as a title.
+    ///
+    /// Rows are counted from the first non-empty row of the sheet.
+    pub fn skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Creates an `ExcelItemReader` from a reader.
+    ///
+    /// The whole content is loaded in memory, as workbooks are zip or compound files.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the content cannot be read, is not a workbook,
+    /// or has no such sheet.
+    pub fn from_reader<R: Read>(self, mut rdr: R) -> ExcelItemReader<T> {
+        let mut content = Vec::new();
+        rdr.read_to_end(&mut content)
+            .expect("Unable to read Excel content");
+
+        let workbook = open_workbook_auto_from_rs(Cursor::new(content)).expect("Invalid workbook");
+
+        self.build(workbook)
+    }
+
+    /// Creates an `ExcelItemReader` from a file path.
+    ///
+    /// The format of the workbook (xlsx, xlsm, xlsb, xls or ods) is detected from
+    /// the extension of the file.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the file cannot be opened, is not a workbook,
+    /// or has no such sheet.
+    pub fn from_path<P: AsRef<Path>>(self, path: P) -> ExcelItemReader<T> {
+        let workbook = open_workbook_auto(path).expect("Unable to open file");
+
+        self.build(workbook)
+    }
+
+    fn build<RS: Read + Seek>(self, mut workbook: Sheets<RS>) -> ExcelItemReader<T> {
+        let range = match self.sheet {
+            Sheet::Index(index) => workbook
+                .worksheet_range_at(index)
+                .unwrap_or_else(|| panic!("No sheet at index {}", index)),
+            Sheet::Name(name) => workbook.worksheet_range(&name),
+        }
+        .expect("Unable to read sheet");
+
+        let headers: Vec<String> = range
+            .rows()
+            .nth(self.skip_rows)
+            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+            .unwrap_or_default();
+
+        ExcelItemReader {
+            _pd: PhantomData,
+            columns: (0..headers.len()).collect(),
+            headers,
+            range,
+            next_row: Cell::new(self.skip_rows + 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_xlsxwriter::Workbook;
+    use serde::Deserialize;
+
+    use crate::{
+        core::{
+            item::{ItemReader, ItemReaderResult, VecItemWriter},
+            step::{Step, StepBuilder, StepInstance},
+        },
+        BatchError,
+    };
+
+    use super::ExcelItemReaderBuilder;
+
+    #[derive(Deserialize, Debug, PartialEq, Clone)]
+    struct Car {
+        year: u16,
+        make: String,
+        price: f64,
+        electric: bool,
+        model: Option<String>,
+    }
+
+    fn workbook() -> Vec<u8> {
+        let mut workbook = Workbook::new();
+
+        let summary = workbook.add_worksheet().set_name("Summary").unwrap();
+        summary.write(0, 0, "Not a car").unwrap();
+
+        let cars = workbook.add_worksheet().set_name("Cars").unwrap();
+        cars.write(0, 0, "Car inventory").unwrap();
+        for (column, header) in ["make", "year", "price", "electric", "model"]
+            .iter()
+            .enumerate()
+        {
+            cars.write(1, column as u16, *header).unwrap();
+        }
+        cars.write(2, 0, "Porsche").unwrap();
+        cars.write(2, 1, 1948).unwrap();
+        cars.write(2, 2, 12_500.5).unwrap();
+        cars.write(2, 3, false).unwrap();
+        cars.write(2, 4, "356").unwrap();
+        cars.write(4, 0, "Tesla").unwrap();
+        cars.write(4, 1, "2012").unwrap();
+        cars.write(4, 2, 49_900).unwrap();
+        cars.write(4, 3, true).unwrap();
+        cars.write(5, 0, "Ford").unwrap();
+        cars.write(5, 1, "unknown").unwrap();
+
+        workbook.save_to_buffer().unwrap()
+    }
+
+    #[test]
+    fn rows_should_be_read_from_the_selected_sheet() {
+        let content = workbook();
+
+        let reader = ExcelItemReaderBuilder::new()
+            .sheet_name("Cars")
+            .skip_rows(1)
+            .from_reader(content.as_slice());
+
+        let porsche: Car = reader.read().unwrap().unwrap();
+        assert_eq!(
+            porsche,
+            Car {
+                year: 1948,
+                make: "Porsche".to_string(),
+                price: 12_500.5,
+                electric: false,
+                model: Some("356".to_string()),
+            }
+        );
+
+        let tesla: Car = reader.read().unwrap().unwrap();
+        assert_eq!(tesla.year, 2012);
+        assert_eq!(tesla.price, 49_900.0);
+        assert!(tesla.electric);
+        assert_eq!(tesla.model, None);
+
+        let result: ItemReaderResult<Car> = reader.read();
+        assert!(matches!(
+            result,
+            Err(BatchError::Deserialization {
+                record: Some(6),
+                ..
+            })
+        ));
+
+        let result: ItemReaderResult<Car> = reader.read();
+        assert!(result.unwrap().is_none());
+    }
+
+    #[test]
+    fn malformed_rows_should_be_skipped_but_not_retried() {
+        let content = workbook();
+
+        for skip_limit in [0, 1] {
+            let reader = ExcelItemReaderBuilder::new()
+                .sheet_name("Cars")
+                .skip_rows(1)
+                .from_reader(content.as_slice());
+            let writer = VecItemWriter::new();
+
+            let step: StepInstance<Car, Car> = StepBuilder::new()
+                .reader(&reader)
+                .writer(&writer)
+                .retry_limit(1)
+                .skip_limit(skip_limit)
+                .build();
+
+            let result = step.execute();
+
+            assert_eq!(result.is_ok(), skip_limit == 1);
+            assert_eq!(step.get_read_error_count(), 1);
+            assert_eq!(step.get_retry_count(), 0);
+            if skip_limit == 1 {
+                assert_eq!(writer.items().len(), 2);
+            }
+        }
+    }
+
+    #[test]
+    fn sheet_should_be_selected_by_index() {
+        #[derive(Deserialize)]
+        struct Line {
+            #[serde(rename = "Not a car")]
+            _label: String,
+        }
+
+        let content = workbook();
+
+        let reader = ExcelItemReaderBuilder::<Line>::new()
+            .sheet_index(0)
+            .from_reader(content.as_slice());
+
+        assert!(reader.read().unwrap().is_none());
+    }
+}
@@ -0,0 +1,318 @@
+use std::{
+    cell::{Cell, RefCell},
+    fs::File,
+    io::Write,
+    path::Path,
+};
+
+use rust_xlsxwriter::{CustomSerializeField, Format, SerializeFieldOptions, Workbook};
+use serde::Serialize;
+
+use crate::{
+    core::item::{ItemWriter, ItemWriterResult},
+    BatchError,
+};
+
+/// A writer serializing items into the rows of an Excel (xlsx) sheet.
+///
+/// The first row holds the names of the fields of the items, as serialized by serde.
+/// An xlsx file is a zip archive which can only be written once complete, so the rows
+/// are kept in memory and the workbook is written when the writer is closed.
+/// The memory used grows with the number of items, which makes it unsuitable
+/// for very large outputs: prefer the CSV writer for those.
+///
+/// Dates are written from `chrono` fields serialized with
+/// `rust_xlsxwriter::serialize_datetime_to_excel`, and need a number format
+/// set with [`ExcelItemWriterBuilder::column_format`] to be displayed as dates.
+pub struct ExcelItemWriter<W: Write + Send> {
+    workbook: RefCell<Workbook>,
+    destination: RefCell<Option<W>>,
+    header_options: SerializeFieldOptions,
+    autofit: bool,
+    has_headers: Cell<bool>,
+}
+
+impl<W: Write + Send> ExcelItemWriter<W> {
+    /// Serializes an item into the next row, writing the header row before the first one.
+    fn serialize<R: Serialize>(&self, item: &R) -> Result<(), BatchError> {
+        let mut workbook = self.workbook.borrow_mut();
+        let worksheet = workbook
+            .worksheet_from_index(0)
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+
+        if !self.has_headers.get() {
+            worksheet
+                .serialize_headers_with_options(0, 0, item, &self.header_options)
+                .map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+            self.has_headers.set(true);
+        }
+
+        worksheet
+            .serialize(item)
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+
+        Ok(())
+    }
+}
+
+impl<W: Write + Send, R: Serialize> ItemWriter<R> for ExcelItemWriter<W> {
+    fn write(&self, items: &[R]) -> ItemWriterResult {
+        for item in items.iter() {
+            self.serialize(item)?;
+        }
+
+        Ok(())
+    }
+
+    /// Does nothing, as the workbook can only be written once complete, on `close`.
+    fn flush(&self) -> ItemWriterResult {
+        Ok(())
+    }
+
+    /// Writes the workbook to the destination.
+    ///
+    /// Closing the writer again does nothing.
+    fn close(&self) -> ItemWriterResult {
+        let Some(destination) = self.destination.borrow_mut().take() else {
+            return Ok(());
+        };
+
+        let mut workbook = self.workbook.borrow_mut();
+
+        if self.autofit {
+            workbook
+                .worksheet_from_index(0)
+                .map_err(|error| BatchError::ItemWriter(error.to_string()))?
+                .autofit();
+        }
+
+        workbook
+            .save_to_writer(destination)
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))
+    }
+}
+
+/// A builder for configuring Excel item writing.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use serde::Serialize;
+/// use spring_batch_rs::core::item::ItemWriter;
+/// use spring_batch_rs::item::excel::excel_writer::ExcelItemWriterBuilder;
+///
+/// #[derive(Serialize)]
+/// struct Order {
+///     reference: String,
+///     amount: f64,
+///     #[serde(serialize_with = "rust_xlsxwriter::serialize_datetime_to_excel")]
+///     ordered_on: NaiveDate,
+/// }
+///
+/// let mut content = Vec::new();
+///
+/// let writer = ExcelItemWriterBuilder::new()
+///     .sheet_name("Orders")
+///     .bold_headers(true)
+///     .autofit(true)
+///     .column_format("amount", "#,##0.00")
+///     .column_format("ordered_on", "yyyy-mm-dd")
+///     .from_writer(&mut content);
+///
+/// let orders = [Order {
+///     reference: "A-1".to_string(),
+///     amount: 1250.5,
+///     ordered_on: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+/// }];
+/// writer.write(&orders).unwrap();
+/// ItemWriter::<Order>::close(&writer).unwrap();
+/// ```
+#[derive(Default)]
+pub struct ExcelItemWriterBuilder {
+    sheet_name: Option<String>,
+    bold_headers: bool,
+    autofit: bool,
+    column_formats: Vec<(String, String)>,
+}
+
+impl ExcelItemWriterBuilder {
+    /// Creates a new `ExcelItemWriterBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the sheet. Defaults to `Sheet1`.
+    pub fn sheet_name(mut self, sheet_name: &str) -> Self {
+        self.sheet_name = Some(sheet_name.to_string());
+        self
+    }
+
+    /// Writes the header row in bold.
+    pub fn bold_headers(mut self, yes: bool) -> Self {
+        self.bold_headers = yes;
+        self
+    }
+
+    /// Adjusts the width of the columns to their content when the writer is closed.
+    pub fn autofit(mut self, yes: bool) -> Self {
+        self.autofit = yes;
+        self
+    }
+
+    /// Sets the Excel number format of the values of a field, such as `0.00` or `yyyy-mm-dd`.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The name of the field, as serialized by serde.
+    /// * `num_format` - The Excel number format.
+    pub fn column_format(mut self, field: &str, num_format: &str) -> Self {
+        self.column_formats
+            .push((field.to_string(), num_format.to_string()));
+        self
+    }
+
+    /// Creates an `ExcelItemWriter` writing to a file.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the file cannot be created or the sheet name is invalid.
+    pub fn from_path<P: AsRef<Path>>(self, path: P) -> ExcelItemWriter<File> {
+        let file = File::create(path).expect("Unable to open file");
+
+        self.from_writer(file)
+    }
+
+    /// Creates an `ExcelItemWriter` writing to a writer.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the sheet name is invalid.
+    pub fn from_writer<W: Write + Send>(self, wtr: W) -> ExcelItemWriter<W> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        if let Some(sheet_name) = &self.sheet_name {
+            worksheet.set_name(sheet_name).expect("Invalid sheet name");
+        }
+
+        let mut header_options = SerializeFieldOptions::new();
+        if self.bold_headers {
+            header_options = header_options.set_header_format(Format::new().set_bold());
+        }
+
+        let custom_headers: Vec<CustomSerializeField> = self
+            .column_formats
+            .iter()
+            .map(|(field, num_format)| {
+                CustomSerializeField::new(field)
+                    .set_value_format(Format::new().set_num_format(num_format))
+            })
+            .collect();
+        header_options = header_options.set_custom_headers(&custom_headers);
+
+        ExcelItemWriter {
+            workbook: RefCell::new(workbook),
+            destination: RefCell::new(Some(wtr)),
+            header_options,
+            autofit: self.autofit,
+            has_headers: Cell::new(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        core::item::{ItemReader, ItemReaderResult, ItemWriter},
+        item::excel::excel_reader::ExcelItemReaderBuilder,
+    };
+
+    use super::ExcelItemWriterBuilder;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Car {
+        make: String,
+        year: u16,
+        price: f64,
+        model: Option<String>,
+    }
+
+    #[test]
+    fn items_should_be_read_back_from_the_sheet() {
+        let cars = vec![
+            Car {
+                make: "Porsche".to_string(),
+                year: 1948,
+                price: 12_500.5,
+                model: Some("356".to_string()),
+            },
+            Car {
+                make: "Tesla".to_string(),
+                year: 2012,
+                price: 49_900.0,
+                model: None,
+            },
+        ];
+
+        let mut content = Vec::new();
+        {
+            let writer = ExcelItemWriterBuilder::new()
+                .sheet_name("Cars")
+                .bold_headers(true)
+                .autofit(true)
+                .column_format("price", "#,##0.00")
+                .from_writer(&mut content);
+
+            writer.write(&cars[..1]).unwrap();
+            writer.write(&cars[1..]).unwrap();
+            ItemWriter::<Car>::close(&writer).unwrap();
+            ItemWriter::<Car>::close(&writer).unwrap();
+        }
+
+        let reader = ExcelItemReaderBuilder::new()
+            .sheet_name("Cars")
+            .from_reader(content.as_slice());
+
+        let read: Vec<Car> = std::iter::from_fn(|| reader.read().unwrap()).collect();
+        assert_eq!(read, cars);
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn dates_should_be_written_as_excel_dates() {
+        use chrono::NaiveDate;
+
+        #[derive(Serialize, Deserialize)]
+        struct Order {
+            #[serde(
+                serialize_with = "rust_xlsxwriter::serialize_option_datetime_to_excel",
+                deserialize_with = "calamine::deserialize_as_date_or_none"
+            )]
+            ordered_on: Option<NaiveDate>,
+        }
+
+        let ordered_on = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+
+        let mut content = Vec::new();
+        {
+            let writer = ExcelItemWriterBuilder::new()
+                .column_format("ordered_on", "yyyy-mm-dd")
+                .from_writer(&mut content);
+
+            writer
+                .write(&[Order {
+                    ordered_on: Some(ordered_on),
+                }])
+                .unwrap();
+            ItemWriter::<Order>::close(&writer).unwrap();
+        }
+
+        let reader = ExcelItemReaderBuilder::new().from_reader(content.as_slice());
+
+        let result: ItemReaderResult<Order> = reader.read();
+        assert_eq!(result.unwrap().unwrap().ordered_on, Some(ordered_on));
+    }
+}
@@ -0,0 +1,9 @@
+/// This module provides an item reader for Excel workbooks.
+///
+/// Rows of a sheet are deserialized into serde structs, using the first row as headers.
+pub mod excel_reader;
+
+/// This module provides an item writer for Excel workbooks.
+///
+/// Items are serialized into the rows of a sheet, under a header row derived from their fields.
+pub mod excel_writer;
@@ -1,4 +1,4 @@
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::fmt;
 
 use ::serde::{ser::Error, Deserialize, Serialize, Serializer};
@@ -6,7 +6,7 @@ use fake::faker::internet::raw::*;
 use fake::locales::*;
 use fake::{faker::name::raw::*, Fake};
 use log::debug;
-use rand::Rng;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use time::format_description;
 use time::{Date, Month};
@@ -15,7 +15,10 @@ use crate::core::item::ItemReader;
 use crate::core::item::ItemReaderResult;
 
 /// Represents a person with their personal information.
-#[derive(Serialize, Deserialize, Clone)]
+///
+/// All the fields are generated with French (`FR_FR`) fakers: first name, last name,
+/// title and free email address, plus a birth date between 1900 and 2021.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Person {
     first_name: String,
     last_name: String,
@@ -54,6 +57,7 @@ impl fmt::Display for Person {
 /// A reader for generating fake `Person` objects.
 pub struct PersonReader {
     count: Cell<usize>,
+    rng: RefCell<StdRng>,
 }
 
 impl ItemReader<Person> for PersonReader {
@@ -68,21 +72,21 @@ impl ItemReader<Person> for PersonReader {
 
         self.count.set(self.count.get() - 1);
 
+        let rng = &mut *self.rng.borrow_mut();
         let person = Person {
-            first_name: FirstName(FR_FR).fake(),
-            last_name: LastName(FR_FR).fake(),
-            title: Title(FR_FR).fake(),
-            email: FreeEmail(FR_FR).fake(),
-            birth_date: fake_date(),
+            first_name: FirstName(FR_FR).fake_with_rng(rng),
+            last_name: LastName(FR_FR).fake_with_rng(rng),
+            title: Title(FR_FR).fake_with_rng(rng),
+            email: FreeEmail(FR_FR).fake_with_rng(rng),
+            birth_date: fake_date(rng),
         };
-        debug!("Person: {}", person.to_string());
+        debug!("Person: {}", person);
         Ok(Some(person))
     }
 }
 
 /// Generates a random `Date` object.
-fn fake_date() -> Date {
-    let mut rng = rand::thread_rng();
+fn fake_date(rng: &mut impl Rng) -> Date {
     let year = rng.gen_range(1900..2022);
     let month = rng.gen_range(1..12);
     let day = rng.gen_range(1..28);
@@ -94,24 +98,46 @@ fn fake_date() -> Date {
 #[derive(Default)]
 pub struct PersonReaderBuilder {
     number_of_items: usize,
+    seed: Option<u64>,
 }
 
 impl PersonReaderBuilder {
     /// Creates a new `PersonReaderBuilder` instance.
     pub fn new() -> Self {
-        Self { number_of_items: 0 }
+        Self {
+            number_of_items: 0,
+            seed: None,
+        }
     }
 
     /// Sets the number of `Person` objects to generate.
+    ///
+    /// The reader returns exactly this number of items, then `Ok(None)`.
     pub fn number_of_items(mut self, number_of_items: usize) -> Self {
         self.number_of_items = number_of_items;
         self
     }
 
+    /// Seeds the random generator, so that the same persons are generated on every run.
+    ///
+    /// Every field is generated from a single `StdRng` seeded with this value, which is
+    /// passed to the `fake` generators. The output is stable for given versions of `rand`
+    /// and `fake`. Without a seed, the generator is seeded from the operating system.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
     /// Builds a `PersonReader` instance with the configured settings.
     pub fn build(self) -> PersonReader {
+        let rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
         PersonReader {
             count: self.number_of_items.into(),
+            rng: RefCell::new(rng),
         }
     }
 }
@@ -128,20 +154,36 @@ mod tests {
 
         let result1 = reader.read();
         assert_eq!(reader.count.get(), 1);
-        assert_eq!(result1.is_ok(), true);
+        assert!(result1.is_ok());
 
         let person = result1.unwrap();
-        assert_eq!(person.is_some(), true);
-        assert_eq!(person.as_ref().unwrap().first_name.is_empty(), false);
-        assert_eq!(person.as_ref().unwrap().last_name.is_empty(), false);
+        assert!(person.is_some());
+        assert!(!person.as_ref().unwrap().first_name.is_empty());
+        assert!(!person.as_ref().unwrap().last_name.is_empty());
 
         let result2 = reader.read();
         assert_eq!(reader.count.get(), 0);
-        assert_eq!(result2.is_ok(), true);
-        assert_eq!(result2.unwrap().is_some(), true);
+        assert!(result2.is_ok());
+        assert!(result2.unwrap().is_some());
 
         let result3 = reader.read();
         assert_eq!(reader.count.get(), 0);
-        assert_eq!(result3.unwrap().is_none(), true);
+        assert!(result3.unwrap().is_none());
+    }
+
+    #[test]
+    fn seeded_readers_should_generate_the_same_persons() {
+        let read_all = |seed| {
+            let reader = PersonReaderBuilder::new()
+                .number_of_items(3)
+                .seed(seed)
+                .build();
+            std::iter::from_fn(|| reader.read().unwrap()).collect::<Vec<_>>()
+        };
+
+        let first = read_all(42);
+        assert_eq!(first.len(), 3);
+        assert_eq!(first, read_all(42));
+        assert_ne!(first, read_all(7));
     }
 }
@@ -0,0 +1,301 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Read, Write},
+    path::Path,
+};
+
+#[cfg(feature = "gzip")]
+use std::cell::{Cell, RefCell};
+
+#[cfg(feature = "gzip")]
+use flate2::{read::MultiGzDecoder, write::GzEncoder, Compression};
+
+#[cfg(feature = "zip")]
+use std::io::{Seek, SeekFrom, Take};
+
+#[cfg(feature = "zip")]
+use flate2::read::DeflateDecoder;
+#[cfg(feature = "zip")]
+use zip::{result::ZipError, CompressionMethod, ZipArchive};
+
+/// A file opened for reading by the `from_path` builders of file readers.
+///
+/// With the `gzip` feature, the content of gzip-compressed files is decompressed while read.
+pub enum FileReader {
+    /// A plain file.
+    Plain(File),
+    /// A gzip-compressed file.
+    #[cfg(feature = "gzip")]
+    Gzip(Box<MultiGzDecoder<File>>),
+    /// An entry stored without compression in a zip archive.
+    #[cfg(feature = "zip")]
+    ZipStored(Take<File>),
+    /// An entry compressed with deflate in a zip archive.
+    #[cfg(feature = "zip")]
+    ZipDeflated(Box<DeflateDecoder<Take<File>>>),
+}
+
+impl FileReader {
+    /// Opens a file, decompressing it if `gzip` is `true`.
+    pub fn open<P: AsRef<Path>>(path: P, gzip: bool) -> io::Result<FileReader> {
+        let file = File::open(path)?;
+
+        #[cfg(feature = "gzip")]
+        if gzip {
+            return Ok(FileReader::Gzip(Box::new(MultiGzDecoder::new(file))));
+        }
+        #[cfg(not(feature = "gzip"))]
+        let _ = gzip;
+
+        Ok(FileReader::Plain(file))
+    }
+
+    /// Opens the entry named `entry` of a zip archive, which is decompressed while read.
+    ///
+    /// Only the entries stored as is or compressed with deflate, without encryption,
+    /// can be read. The entry is streamed from the archive, not extracted.
+    #[cfg(feature = "zip")]
+    pub fn open_zip_entry<P: AsRef<Path>>(path: P, entry: &str) -> io::Result<FileReader> {
+        let path = path.as_ref();
+        let mut archive = ZipArchive::new(File::open(path)?).map_err(io::Error::other)?;
+
+        let (compression, start, size) = {
+            let file = archive.by_name(entry).map_err(|error| match error {
+                ZipError::FileNotFound => io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("no entry `{}` in archive {}", entry, path.display()),
+                ),
+                error => io::Error::other(error),
+            })?;
+
+            if file.encrypted() {
+                return Err(io::Error::other(format!(
+                    "entry `{}` of archive {} is encrypted",
+                    entry,
+                    path.display()
+                )));
+            }
+
+            let start = file
+                .data_start()
+                .ok_or_else(|| io::Error::other(format!("invalid entry `{}`", entry)))?;
+            (file.compression(), start, file.compressed_size())
+        };
+
+        let mut file = archive.into_inner();
+        file.seek(SeekFrom::Start(start))?;
+        let data = file.take(size);
+
+        match compression {
+            CompressionMethod::Stored => Ok(FileReader::ZipStored(data)),
+            CompressionMethod::Deflated => {
+                Ok(FileReader::ZipDeflated(Box::new(DeflateDecoder::new(data))))
+            }
+            method => Err(io::Error::other(format!(
+                "unsupported compression {} of entry `{}`",
+                method, entry
+            ))),
+        }
+    }
+}
+
+impl Read for FileReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            FileReader::Plain(file) => file.read(buf),
+            #[cfg(feature = "gzip")]
+            FileReader::Gzip(decoder) => decoder.read(buf),
+            #[cfg(feature = "zip")]
+            FileReader::ZipStored(data) => data.read(buf),
+            #[cfg(feature = "zip")]
+            FileReader::ZipDeflated(decoder) => decoder.read(buf),
+        }
+    }
+}
+
+/// A file created for writing by the `from_path` builders of file writers.
+///
+/// With the `gzip` feature, the content of gzip-compressed files is compressed while written.
+pub enum FileWriter {
+    /// A plain file.
+    Plain(File),
+    /// A gzip-compressed file, which can no longer be written once finished.
+    #[cfg(feature = "gzip")]
+    Gzip {
+        encoder: RefCell<Box<GzEncoder<File>>>,
+        finished: Cell<bool>,
+    },
+}
+
+impl FileWriter {
+    /// Creates a file, compressing it if `gzip` is `true`.
+    pub fn create<P: AsRef<Path>>(path: P, gzip: bool) -> io::Result<FileWriter> {
+        Ok(Self::new(File::create(path)?, gzip))
+    }
+
+    /// Opens a file to write at its end, creating it if needed, compressing it if `gzip` is `true`.
+    ///
+    /// Appending to a gzip-compressed file adds a new gzip member, which file readers
+    /// decompress as part of the same content.
+    pub fn append<P: AsRef<Path>>(path: P, gzip: bool) -> io::Result<FileWriter> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        Ok(Self::new(file, gzip))
+    }
+
+    fn new(file: File, gzip: bool) -> FileWriter {
+        #[cfg(feature = "gzip")]
+        if gzip {
+            let encoder = GzEncoder::new(file, Compression::default());
+            return FileWriter::Gzip {
+                encoder: RefCell::new(Box::new(encoder)),
+                finished: Cell::new(false),
+            };
+        }
+        #[cfg(not(feature = "gzip"))]
+        let _ = gzip;
+
+        FileWriter::Plain(file)
+    }
+
+    /// Completes the file, writing the gzip trailer of compressed files.
+    ///
+    /// Writers call it when they are closed, so that the file is valid without waiting
+    /// for the writer to be dropped.
+    pub fn finish(&self) -> io::Result<()> {
+        match self {
+            FileWriter::Plain(_) => Ok(()),
+            #[cfg(feature = "gzip")]
+            FileWriter::Gzip { encoder, finished } => {
+                encoder.borrow_mut().try_finish()?;
+                finished.set(true);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Write for FileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            FileWriter::Plain(file) => file.write(buf),
+            #[cfg(feature = "gzip")]
+            FileWriter::Gzip { finished, .. } if finished.get() => {
+                Err(io::Error::other("gzip file already finished"))
+            }
+            #[cfg(feature = "gzip")]
+            FileWriter::Gzip { encoder, .. } => encoder.get_mut().write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            FileWriter::Plain(file) => file.flush(),
+            #[cfg(feature = "gzip")]
+            FileWriter::Gzip { finished, .. } if finished.get() => Ok(()),
+            #[cfg(feature = "gzip")]
+            FileWriter::Gzip { encoder, .. } => encoder.get_mut().flush(),
+        }
+    }
+}
+
+/// Returns `true` if the path has a `.gz` extension.
+pub fn is_gzip_path<P: AsRef<Path>>(path: P) -> bool {
+    path.as_ref()
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("gz"))
+}
+
+#[cfg(all(test, feature = "gzip"))]
+mod tests {
+    use std::io::{Read, Write};
+
+    use super::{is_gzip_path, FileReader, FileWriter};
+
+    #[test]
+    fn gzip_extension_should_be_detected() {
+        assert!(is_gzip_path("data.csv.gz"));
+        assert!(is_gzip_path("DATA.JSON.GZ"));
+        assert!(!is_gzip_path("data.csv"));
+    }
+
+    #[test]
+    fn compressed_content_should_round_trip() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+
+        let mut writer = FileWriter::create(file.path(), true).unwrap();
+        writer.write_all(b"hello gzip").unwrap();
+        writer.finish().unwrap();
+
+        let mut content = String::new();
+        FileReader::open(file.path(), true)
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+
+        assert_eq!(content, "hello gzip");
+        assert_ne!(std::fs::read(file.path()).unwrap(), b"hello gzip");
+    }
+}
+
+#[cfg(all(test, feature = "zip"))]
+pub(crate) mod zip_tests {
+    use std::io::{Read, Write};
+
+    use tempfile::NamedTempFile;
+    use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+    use super::FileReader;
+
+    /// Creates a zip archive holding the given entries, with their content and compression.
+    pub(crate) fn zip_archive(entries: &[(&str, &str, CompressionMethod)]) -> NamedTempFile {
+        let file = NamedTempFile::new().unwrap();
+        let mut writer = ZipWriter::new(file.reopen().unwrap());
+
+        for (name, content, compression) in entries {
+            let options = SimpleFileOptions::default().compression_method(*compression);
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap();
+
+        file
+    }
+
+    #[test]
+    fn zip_entries_should_be_streamed() {
+        let archive = zip_archive(&[
+            ("stored.txt", "stored content", CompressionMethod::Stored),
+            (
+                "deflated.txt",
+                "deflated content",
+                CompressionMethod::Deflated,
+            ),
+        ]);
+
+        for (entry, expected) in [
+            ("stored.txt", "stored content"),
+            ("deflated.txt", "deflated content"),
+        ] {
+            let mut content = String::new();
+            FileReader::open_zip_entry(archive.path(), entry)
+                .unwrap()
+                .read_to_string(&mut content)
+                .unwrap();
+
+            assert_eq!(content, expected);
+        }
+    }
+
+    #[test]
+    fn missing_zip_entry_should_be_named_in_the_error() {
+        let archive = zip_archive(&[("data.csv", "a,b", CompressionMethod::Deflated)]);
+
+        let error = FileReader::open_zip_entry(archive.path(), "other.csv")
+            .err()
+            .unwrap();
+
+        assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+        assert!(error.to_string().contains("no entry `other.csv`"));
+    }
+}
@@ -0,0 +1,319 @@
+use std::{
+    cell::{Cell, RefCell},
+    io::{BufRead, BufReader, Read},
+    marker::PhantomData,
+    path::Path,
+};
+
+use csv::StringRecord;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    core::item::{ItemReader, ItemReaderResult},
+    item::file::{is_gzip_path, FileReader},
+    BatchError,
+};
+
+use super::{Alignment, FixedWidthField};
+
+/// A reader for fixed-width records, where each line holds one item and each field
+/// spans a fixed range of bytes.
+///
+/// Blank lines are skipped. Lines shorter than the declared fields give empty values
+/// for the missing fields, which can be deserialized into `Option` fields.
+pub struct FixedWidthItemReader<R, T> {
+    pd: PhantomData<T>,
+    reader: RefCell<BufReader<R>>,
+    line: RefCell<String>,
+    line_number: Cell<usize>,
+    fields: Vec<FixedWidthField>,
+    headers: StringRecord,
+}
+
+impl<R, T> FixedWidthItemReader<R, T> {
+    /// Extracts the value of a field from a line, without its padding.
+    fn value<'l>(&self, line: &'l str, field: &FixedWidthField) -> Result<&'l str, BatchError> {
+        let start = field.start.min(line.len());
+        let end = field.end().min(line.len());
+
+        let value = line.get(start..end).ok_or_else(|| {
+            BatchError::deserialization(
+                Some(self.line_number.get() as u64),
+                format!(
+                    "field `{}` does not fall on character boundaries",
+                    field.name
+                ),
+            )
+        })?;
+
+        let value = value.trim();
+        let value = match (field.padding, field.alignment) {
+            (' ' | '0', _) => value,
+            (padding, Alignment::Left) => value.trim_end_matches(padding),
+            (padding, Alignment::Right) => value.trim_start_matches(padding),
+        };
+
+        Ok(value)
+    }
+}
+
+impl<R: Read, T: DeserializeOwned> ItemReader<T> for FixedWidthItemReader<R, T> {
+    /// Reads the next item from the next non-blank line.
+    ///
+    /// Returns `Ok(None)` at the end of the input, or `Err(BatchError::Deserialization { .. })`
+    /// with the line number if the line cannot be deserialized.
+    fn read(&self) -> ItemReaderResult<T> {
+        let mut reader = self.reader.borrow_mut();
+        let mut line = self.line.borrow_mut();
+
+        loop {
+            line.clear();
+
+            let length = reader
+                .read_line(&mut line)
+                .map_err(|error| BatchError::ItemReader(error.to_string()))?;
+
+            if length == 0 {
+                return Ok(None);
+            }
+
+            self.line_number.set(self.line_number.get() + 1);
+
+            let content = line.trim_end_matches(['\r', '\n']);
+            if content.trim().is_empty() {
+                continue;
+            }
+
+            let record = self
+                .fields
+                .iter()
+                .map(|field| self.value(content, field))
+                .collect::<Result<StringRecord, BatchError>>()?;
+
+            return record
+                .deserialize(Some(&self.headers))
+                .map(Some)
+                .map_err(|error| {
+                    BatchError::deserialization(Some(self.line_number.get() as u64), error)
+                });
+        }
+    }
+}
+
+/// Builder for creating a `FixedWidthItemReader`.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use spring_batch_rs::core::item::ItemReader;
+/// use spring_batch_rs::item::fixed_width::{
+///     fixed_width_reader::FixedWidthItemReaderBuilder, FixedWidthField,
+/// };
+///
+/// #[derive(Deserialize)]
+/// struct Account {
+///     name: String,
+///     balance: i64,
+/// }
+///
+/// let reader = FixedWidthItemReaderBuilder::new()
+///     .field(FixedWidthField::new("name", 0, 8))
+///     .field(FixedWidthField::new("balance", 8, 6).align_right().padding('0'))
+///     .from_reader("ALICE   001250\nBOB     -00042\n".as_bytes());
+///
+/// let alice: Account = reader.read().unwrap().unwrap();
+/// assert_eq!(alice.name, "ALICE");
+/// assert_eq!(alice.balance, 1250);
+/// ```
+#[derive(Default)]
+pub struct FixedWidthItemReaderBuilder<T> {
+    _pd: PhantomData<T>,
+    fields: Vec<FixedWidthField>,
+    capacity: usize,
+    gzip: bool,
+}
+
+impl<T: DeserializeOwned> FixedWidthItemReaderBuilder<T> {
+    pub fn new() -> FixedWidthItemReaderBuilder<T> {
+        Self {
+            _pd: PhantomData,
+            fields: Vec::new(),
+            capacity: 8 * 1024,
+            gzip: false,
+        }
+    }
+
+    /// Declares a field of the records. Fields can be declared in any order.
+    pub fn field(mut self, field: FixedWidthField) -> FixedWidthItemReaderBuilder<T> {
+        self.fields.push(field);
+        self
+    }
+
+    pub fn capacity(mut self, capacity: usize) -> FixedWidthItemReaderBuilder<T> {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Creates a `FixedWidthItemReader` from a reader.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no field has been declared.
+    pub fn from_reader<R: Read>(self, rdr: R) -> FixedWidthItemReader<R, T> {
+        assert!(!self.fields.is_empty(), "One or more fields are required");
+
+        let headers = self.fields.iter().map(FixedWidthField::name).collect();
+
+        FixedWidthItemReader {
+            pd: PhantomData,
+            reader: RefCell::new(BufReader::with_capacity(self.capacity, rdr)),
+            line: RefCell::new(String::new()),
+            line_number: Cell::new(0),
+            fields: self.fields,
+            headers,
+        }
+    }
+
+    /// Decompresses the file opened by `from_path` with gzip, whatever its extension.
+    #[cfg(feature = "gzip")]
+    pub fn gzip(mut self, yes: bool) -> FixedWidthItemReaderBuilder<T> {
+        self.gzip = yes;
+        self
+    }
+
+    /// Creates a `FixedWidthItemReader` from a file path.
+    ///
+    /// With the `gzip` feature, files with a `.gz` extension are decompressed while read.
+    pub fn from_path<P: AsRef<Path>>(self, path: P) -> FixedWidthItemReader<FileReader, T> {
+        let gzip = self.gzip || is_gzip_path(&path);
+        let file = FileReader::open(path, gzip).expect("Unable to open file");
+
+        self.from_reader(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::{
+        core::{
+            item::{ItemReader, ItemReaderResult, VecItemWriter},
+            step::{Step, StepBuilder, StepInstance},
+        },
+        item::fixed_width::FixedWidthField,
+        BatchError,
+    };
+
+    use super::FixedWidthItemReaderBuilder;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Payment {
+        account: String,
+        amount: f64,
+        count: u32,
+        note: Option<String>,
+    }
+
+    #[test]
+    fn fields_should_be_sliced_and_trimmed() {
+        let input = "\
+0042AC  0012.50   3**paid
+0007B   -002.00  12
+
+0100    0000.00   0";
+
+        let reader = FixedWidthItemReaderBuilder::new()
+            .field(FixedWidthField::new("account", 0, 8))
+            .field(
+                FixedWidthField::new("amount", 8, 7)
+                    .align_right()
+                    .padding('0'),
+            )
+            .field(FixedWidthField::new("count", 15, 4).align_right())
+            .field(
+                FixedWidthField::new("note", 19, 6)
+                    .align_right()
+                    .padding('*'),
+            )
+            .from_reader(input.as_bytes());
+
+        let payments: Vec<Payment> = std::iter::from_fn(|| reader.read().unwrap()).collect();
+
+        assert_eq!(
+            payments,
+            vec![
+                Payment {
+                    account: "0042AC".to_string(),
+                    amount: 12.5,
+                    count: 3,
+                    note: Some("paid".to_string()),
+                },
+                Payment {
+                    account: "0007B".to_string(),
+                    amount: -2.0,
+                    count: 12,
+                    note: None,
+                },
+                Payment {
+                    account: "0100".to_string(),
+                    amount: 0.0,
+                    count: 0,
+                    note: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_value_should_report_the_line() {
+        let reader = FixedWidthItemReaderBuilder::<Payment>::new()
+            .field(FixedWidthField::new("account", 0, 4))
+            .field(FixedWidthField::new("amount", 4, 4))
+            .field(FixedWidthField::new("count", 8, 2))
+            .from_reader("0001  1.0 1\n0002 abc 2\n".as_bytes());
+
+        assert!(reader.read().is_ok());
+
+        let result: ItemReaderResult<Payment> = reader.read();
+        assert!(matches!(
+            result,
+            Err(BatchError::Deserialization {
+                record: Some(2),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn malformed_lines_should_be_skipped_but_not_retried() {
+        #[derive(Deserialize, Clone, Debug, PartialEq)]
+        struct Count {
+            value: u32,
+        }
+
+        for skip_limit in [0, 1] {
+            let reader = FixedWidthItemReaderBuilder::new()
+                .field(FixedWidthField::new("value", 0, 1))
+                .from_reader("1\nX\n3\n".as_bytes());
+            let writer = VecItemWriter::new();
+
+            let step: StepInstance<Count, Count> = StepBuilder::new()
+                .reader(&reader)
+                .writer(&writer)
+                .retry_limit(1)
+                .skip_limit(skip_limit)
+                .build();
+
+            let result = step.execute();
+
+            assert_eq!(result.is_ok(), skip_limit == 1);
+            assert_eq!(step.get_read_error_count(), 1);
+            assert_eq!(step.get_retry_count(), 0);
+            if skip_limit == 1 {
+                assert_eq!(writer.items(), vec![Count { value: 1 }, Count { value: 3 }]);
+            }
+        }
+    }
+}
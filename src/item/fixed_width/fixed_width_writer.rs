@@ -0,0 +1,298 @@
+use std::{
+    cell::RefCell,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    core::item::{ItemWriter, ItemWriterResult},
+    item::file::{is_gzip_path, FileWriter},
+    BatchError,
+};
+
+use super::{Alignment, FixedWidthField};
+
+/// Completes the underlying writer when the item writer is closed.
+type FinishFn<T> = fn(&T) -> io::Result<()>;
+
+/// A writer of fixed-width records, writing one line per item.
+///
+/// Each field is padded to its length with its padding character, according to its
+/// alignment. Gaps between fields are filled with spaces. A value longer than its
+/// field is an error rather than being truncated.
+pub struct FixedWidthItemWriter<T: Write> {
+    stream: RefCell<BufWriter<T>>,
+    fields: Vec<FixedWidthField>,
+    finish: Option<FinishFn<T>>,
+}
+
+impl<T: Write> FixedWidthItemWriter<T> {
+    /// Formats an item as a fixed-width line, without the line terminator.
+    fn to_line<R: Serialize>(&self, item: &R) -> Result<String, BatchError> {
+        let value = serde_json::to_value(item)
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+
+        let Value::Object(object) = value else {
+            return Err(BatchError::ItemWriter(
+                "fixed-width items must serialize to a struct or a map".to_string(),
+            ));
+        };
+
+        let mut line = String::new();
+
+        for field in &self.fields {
+            let value = match object.get(field.name()) {
+                None | Some(Value::Null) => String::new(),
+                Some(Value::String(value)) => value.clone(),
+                Some(value) => value.to_string(),
+            };
+
+            let padded = pad(&value, field)?;
+
+            if line.len() < field.start {
+                line.push_str(&" ".repeat(field.start - line.len()));
+            } else if line.len() > field.start {
+                return Err(BatchError::ItemWriter(format!(
+                    "field `{}` overlaps the previous field",
+                    field.name()
+                )));
+            }
+
+            line.push_str(&padded);
+        }
+
+        Ok(line)
+    }
+}
+
+/// Pads a value to the length of its field.
+///
+/// A negative number padded with zeros keeps its sign first, such as `-0042`.
+fn pad(value: &str, field: &FixedWidthField) -> Result<String, BatchError> {
+    if value.len() > field.length {
+        return Err(BatchError::ItemWriter(format!(
+            "value `{}` is longer than the {} bytes of field `{}`",
+            value,
+            field.length,
+            field.name()
+        )));
+    }
+
+    let padding = field
+        .padding
+        .to_string()
+        .repeat((field.length - value.len()) / field.padding.len_utf8());
+
+    let padded = match field.alignment {
+        Alignment::Left => format!("{}{}", value, padding),
+        Alignment::Right => match value.strip_prefix('-') {
+            Some(digits) if field.padding == '0' => format!("-{}{}", padding, digits),
+            _ => format!("{}{}", padding, value),
+        },
+    };
+
+    Ok(padded)
+}
+
+impl<T: Write, R: Serialize> ItemWriter<R> for FixedWidthItemWriter<T> {
+    fn write(&self, items: &[R]) -> ItemWriterResult {
+        let mut lines = String::new();
+
+        for item in items.iter() {
+            lines.push_str(&self.to_line(item)?);
+            lines.push('\n');
+        }
+
+        self.stream
+            .borrow_mut()
+            .write_all(lines.as_bytes())
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))
+    }
+
+    fn flush(&self) -> ItemWriterResult {
+        self.stream
+            .borrow_mut()
+            .flush()
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))
+    }
+
+    /// Flushes the writer and completes the file created by `from_path`.
+    fn close(&self) -> ItemWriterResult {
+        ItemWriter::<R>::flush(self)?;
+
+        match self.finish {
+            Some(finish) => finish(self.stream.borrow().get_ref())
+                .map_err(|error| BatchError::ItemWriter(error.to_string())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Builder for creating a `FixedWidthItemWriter`.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+/// use spring_batch_rs::core::item::ItemWriter;
+/// use spring_batch_rs::item::fixed_width::{
+///     fixed_width_writer::FixedWidthItemWriterBuilder, FixedWidthField,
+/// };
+///
+/// #[derive(Serialize)]
+/// struct Account {
+///     name: String,
+///     balance: i64,
+/// }
+///
+/// let mut output = Vec::new();
+/// {
+///     let writer = FixedWidthItemWriterBuilder::new()
+///         .field(FixedWidthField::new("name", 0, 8))
+///         .field(FixedWidthField::new("balance", 8, 6).align_right().padding('0'))
+///         .from_writer(&mut output);
+///
+///     let accounts = vec![Account { name: "ALICE".to_string(), balance: 1250 }];
+///     writer.write(&accounts).unwrap();
+///     ItemWriter::<Account>::flush(&writer).unwrap();
+/// }
+///
+/// assert_eq!(String::from_utf8(output).unwrap(), "ALICE   001250\n");
+/// ```
+#[derive(Default)]
+pub struct FixedWidthItemWriterBuilder {
+    fields: Vec<FixedWidthField>,
+    gzip: bool,
+}
+
+impl FixedWidthItemWriterBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares a field of the records. Fields must be declared in the order of
+    /// their positions and must not overlap.
+    pub fn field(mut self, field: FixedWidthField) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Compresses the file created by `from_path` with gzip, whatever its extension.
+    #[cfg(feature = "gzip")]
+    pub fn gzip(mut self, yes: bool) -> Self {
+        self.gzip = yes;
+        self
+    }
+
+    /// Creates a `FixedWidthItemWriter` writing to a file.
+    ///
+    /// With the `gzip` feature, files with a `.gz` extension are compressed while written.
+    pub fn from_path<R: AsRef<Path>>(self, path: R) -> FixedWidthItemWriter<FileWriter> {
+        let gzip = self.gzip || is_gzip_path(&path);
+        let file = FileWriter::create(path, gzip).expect("Unable to open file");
+
+        let mut writer = self.from_writer(file);
+        writer.finish = Some(FileWriter::finish);
+        writer
+    }
+
+    /// Creates a `FixedWidthItemWriter` from a writer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no field has been declared.
+    pub fn from_writer<W: Write>(self, wtr: W) -> FixedWidthItemWriter<W> {
+        assert!(!self.fields.is_empty(), "One or more fields are required");
+
+        FixedWidthItemWriter {
+            stream: RefCell::new(BufWriter::new(wtr)),
+            fields: self.fields,
+            finish: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        core::item::{ItemReader, ItemWriter},
+        item::fixed_width::{fixed_width_reader::FixedWidthItemReaderBuilder, FixedWidthField},
+    };
+
+    use super::FixedWidthItemWriterBuilder;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Payment {
+        account: String,
+        amount: i32,
+        note: Option<String>,
+    }
+
+    fn fields() -> Vec<FixedWidthField> {
+        vec![
+            FixedWidthField::new("account", 0, 6).padding('_'),
+            FixedWidthField::new("amount", 6, 5)
+                .align_right()
+                .padding('0'),
+            FixedWidthField::new("note", 12, 4),
+        ]
+    }
+
+    fn payment(account: &str, amount: i32, note: Option<&str>) -> Payment {
+        Payment {
+            account: account.to_string(),
+            amount,
+            note: note.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn values_should_be_padded_and_aligned() {
+        let payments = vec![payment("AC42", 125, Some("paid")), payment("B7", -3, None)];
+
+        let mut output = Vec::new();
+        {
+            let writer = fields()
+                .into_iter()
+                .fold(FixedWidthItemWriterBuilder::new(), |builder, field| {
+                    builder.field(field)
+                })
+                .from_writer(&mut output);
+
+            writer.write(&payments).unwrap();
+            ItemWriter::<Payment>::flush(&writer).unwrap();
+        }
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output, "AC42__00125 paid\nB7____-0003     \n");
+
+        let reader = fields()
+            .into_iter()
+            .fold(FixedWidthItemReaderBuilder::new(), |builder, field| {
+                builder.field(field)
+            })
+            .from_reader(output.as_bytes());
+
+        let read: Vec<Payment> = std::iter::from_fn(|| reader.read().unwrap()).collect();
+        assert_eq!(read, payments);
+    }
+
+    #[test]
+    fn value_longer_than_its_field_should_fail() {
+        let writer = FixedWidthItemWriterBuilder::new()
+            .field(FixedWidthField::new("account", 0, 3))
+            .from_writer(Vec::new());
+
+        let result = writer.write(&[payment("AC42", 0, None)]);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("longer than the 3 bytes of field `account`"));
+    }
+}
@@ -0,0 +1,85 @@
+/// This module provides a fixed-width item reader.
+///
+/// Each line is sliced into the declared fields, which are trimmed and deserialized
+/// into the item like the columns of a CSV record.
+pub mod fixed_width_reader;
+
+/// This module provides a fixed-width item writer.
+///
+/// Each item is serialized into the declared fields, padded to their width.
+pub mod fixed_width_writer;
+
+/// Alignment of a value within its field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    /// The value starts the field and padding is added on the right, as for text.
+    Left,
+    /// The value ends the field and padding is added on the left, as for numbers.
+    Right,
+}
+
+/// Declares a field of a fixed-width record.
+///
+/// The field spans `length` bytes from byte `start` of the line (starting at 0).
+/// It is left-aligned and padded with spaces by default.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::item::fixed_width::FixedWidthField;
+///
+/// let name = FixedWidthField::new("name", 0, 10);
+/// let amount = FixedWidthField::new("amount", 10, 8).align_right().padding('0');
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedWidthField {
+    name: String,
+    start: usize,
+    length: usize,
+    padding: char,
+    alignment: Alignment,
+}
+
+impl FixedWidthField {
+    /// Creates a left-aligned, space-padded field.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the field, matching the name of the item field.
+    /// * `start` - The offset of the field in the line, in bytes.
+    /// * `length` - The length of the field, in bytes.
+    pub fn new(name: &str, start: usize, length: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            start,
+            length,
+            padding: ' ',
+            alignment: Alignment::Left,
+        }
+    }
+
+    /// Sets the padding character. Defaults to a space.
+    ///
+    /// The reader strips it from the padded side of the value, except `0`, which is kept
+    /// as leading zeros are significant in codes and harmless in numbers.
+    pub fn padding(mut self, padding: char) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Aligns the value on the right of the field, as numbers usually are.
+    pub fn align_right(mut self) -> Self {
+        self.alignment = Alignment::Right;
+        self
+    }
+
+    /// Gets the name of the field.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the byte offset just after the field.
+    fn end(&self) -> usize {
+        self.start + self.length
+    }
+}
@@ -0,0 +1,513 @@
+use std::{
+    cell::{Cell, RefCell},
+    error::Error,
+    fs::File,
+    io::{self, BufReader, ErrorKind, Read},
+    path::Path,
+};
+
+use crate::{
+    core::item::{ItemReader, ItemReaderResult},
+    BatchError,
+};
+
+/// Default maximum size of a single frame: 16 MiB.
+const DEFAULT_MAX_FRAME_BYTES: usize = 16 * 1024 * 1024;
+
+/// Decodes the payload of a frame into an item.
+type Decoder<T> = dyn Fn(&[u8]) -> Result<T, Box<dyn Error + Send + Sync>>;
+
+/// The size of the length header preceding each frame.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LengthPrefix {
+    /// A 2-byte length, for frames up to 64 KiB.
+    U16,
+    /// A 4-byte length.
+    #[default]
+    U32,
+}
+
+impl LengthPrefix {
+    fn size(self) -> usize {
+        match self {
+            LengthPrefix::U16 => 2,
+            LengthPrefix::U32 => 4,
+        }
+    }
+}
+
+/// The byte order of the length header.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ByteOrder {
+    /// Most significant byte first, the network byte order.
+    #[default]
+    BigEndian,
+    /// Least significant byte first.
+    LittleEndian,
+}
+
+/// Reads into `buf` until it is full or the end of the input is reached.
+///
+/// Returns the number of bytes read, which is less than the size of `buf` only at the end
+/// of the input.
+fn read_full<R: Read>(rdr: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+
+    while filled < buf.len() {
+        match rdr.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(error) if error.kind() == ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(filled)
+}
+
+/// A reader of binary records framed by a length prefix or a delimiter, such as the dumps
+/// of a binary protocol.
+///
+/// By default, each frame is a length header, of the configured size and byte order,
+/// followed by that many bytes of payload. The payload is decoded into an item by the
+/// configured decoder, for instance with `bincode` or `prost`.
+pub struct FramedItemReader<R, T> {
+    reader: RefCell<R>,
+    length_prefix: LengthPrefix,
+    byte_order: ByteOrder,
+    delimiter: Option<u8>,
+    max_frame_bytes: usize,
+    decoder: Box<Decoder<T>>,
+    record: Cell<u64>,
+}
+
+impl<R: Read, T> FramedItemReader<R, T> {
+    /// Reads the length header of the next frame, or `None` at the end of the input.
+    fn read_length(&self, rdr: &mut R) -> Result<Option<usize>, BatchError> {
+        let mut header = [0u8; 4];
+        let header = &mut header[..self.length_prefix.size()];
+
+        let read =
+            read_full(rdr, header).map_err(|error| BatchError::ItemReader(error.to_string()))?;
+        if read == 0 {
+            return Ok(None);
+        } else if read < header.len() {
+            return Err(BatchError::ItemReader(format!(
+                "truncated length header of frame {}: {} of {} bytes",
+                self.record.get(),
+                read,
+                header.len()
+            )));
+        }
+
+        let length = match (self.length_prefix, self.byte_order) {
+            (LengthPrefix::U16, ByteOrder::BigEndian) => {
+                u16::from_be_bytes([header[0], header[1]]) as usize
+            }
+            (LengthPrefix::U16, ByteOrder::LittleEndian) => {
+                u16::from_le_bytes([header[0], header[1]]) as usize
+            }
+            (LengthPrefix::U32, ByteOrder::BigEndian) => {
+                u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize
+            }
+            (LengthPrefix::U32, ByteOrder::LittleEndian) => {
+                u32::from_le_bytes([header[0], header[1], header[2], header[3]]) as usize
+            }
+        };
+
+        Ok(Some(length))
+    }
+
+    /// Reads the payload of the next length-prefixed frame, or `None` at the end of the input.
+    fn read_prefixed(&self, rdr: &mut R) -> Result<Option<Vec<u8>>, BatchError> {
+        let Some(length) = self.read_length(rdr)? else {
+            return Ok(None);
+        };
+
+        self.record.set(self.record.get() + 1);
+        if length > self.max_frame_bytes {
+            // The payload is skipped, so that the next read starts at the next frame
+            io::copy(&mut rdr.take(length as u64), &mut io::sink())
+                .map_err(|error| BatchError::ItemReader(error.to_string()))?;
+            return Err(self.oversized(length));
+        }
+
+        let mut payload = vec![0u8; length];
+        let read = read_full(rdr, &mut payload)
+            .map_err(|error| BatchError::ItemReader(error.to_string()))?;
+        if read < length {
+            return Err(BatchError::ItemReader(format!(
+                "truncated frame {}: {} of {} bytes",
+                self.record.get(),
+                read,
+                length
+            )));
+        }
+
+        Ok(Some(payload))
+    }
+
+    /// Reads the payload of the next frame ended by `delimiter`, or `None` at the end of the input.
+    ///
+    /// The last frame may omit its delimiter.
+    fn read_delimited(&self, rdr: &mut R, delimiter: u8) -> Result<Option<Vec<u8>>, BatchError> {
+        let mut payload = Vec::new();
+        let mut length = 0;
+        let mut byte = [0u8; 1];
+
+        loop {
+            let read = read_full(rdr, &mut byte)
+                .map_err(|error| BatchError::ItemReader(error.to_string()))?;
+            if read == 0 && length == 0 {
+                return Ok(None);
+            } else if read == 0 || byte[0] == delimiter {
+                break;
+            }
+
+            // The bytes past the maximum size are dropped until the next delimiter
+            if length < self.max_frame_bytes {
+                payload.push(byte[0]);
+            }
+            length += 1;
+        }
+
+        self.record.set(self.record.get() + 1);
+        if length > self.max_frame_bytes {
+            return Err(self.oversized(length));
+        }
+
+        Ok(Some(payload))
+    }
+
+    /// Builds the error of a frame exceeding the maximum size, which has been skipped.
+    fn oversized(&self, length: usize) -> BatchError {
+        BatchError::deserialization(
+            Some(self.record.get()),
+            format!(
+                "frame of {} bytes exceeds the maximum size of {} bytes",
+                length, self.max_frame_bytes
+            ),
+        )
+    }
+}
+
+impl<R: Read, T> ItemReader<T> for FramedItemReader<R, T> {
+    /// Reads and decodes the next frame.
+    ///
+    /// Returns `Ok(None)` at the end of the input, when it ends on a frame boundary,
+    /// `Err(BatchError::Deserialization { .. })` with the number of the frame if it cannot
+    /// be decoded or exceeds the maximum size, and `Err(BatchError::ItemReader(error))` if
+    /// the input cannot be read or ends within a length-prefixed frame.
+    fn read(&self) -> ItemReaderResult<T> {
+        let mut rdr = self.reader.borrow_mut();
+
+        let payload = match self.delimiter {
+            Some(delimiter) => self.read_delimited(&mut rdr, delimiter)?,
+            None => self.read_prefixed(&mut rdr)?,
+        };
+        let Some(payload) = payload else {
+            return Ok(None);
+        };
+
+        (self.decoder)(&payload)
+            .map(Some)
+            .map_err(|error| BatchError::deserialization(Some(self.record.get()), error))
+    }
+}
+
+/// A builder for configuring the reading of length-prefixed frames.
+pub struct FramedItemReaderBuilder<T> {
+    length_prefix: LengthPrefix,
+    byte_order: ByteOrder,
+    delimiter: Option<u8>,
+    max_frame_bytes: usize,
+    decoder: Option<Box<Decoder<T>>>,
+}
+
+impl<T> Default for FramedItemReaderBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FramedItemReaderBuilder<T> {
+    /// Creates a new `FramedItemReaderBuilder`, for frames prefixed with a 4-byte big-endian
+    /// length.
+    pub fn new() -> Self {
+        Self {
+            length_prefix: LengthPrefix::default(),
+            byte_order: ByteOrder::default(),
+            delimiter: None,
+            max_frame_bytes: DEFAULT_MAX_FRAME_BYTES,
+            decoder: None,
+        }
+    }
+
+    /// Sets the size of the length header (4 bytes by default).
+    pub fn length_prefix(mut self, length_prefix: LengthPrefix) -> Self {
+        self.length_prefix = length_prefix;
+        self
+    }
+
+    /// Sets the byte order of the length header (big-endian by default).
+    pub fn byte_order(mut self, byte_order: ByteOrder) -> Self {
+        self.byte_order = byte_order;
+        self
+    }
+
+    /// Splits the frames on `delimiter` instead of reading a length header before each of them.
+    ///
+    /// The delimiter is not part of the payload, and the last frame may omit it.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Sets the maximum size in bytes of a single frame (16 MiB by default).
+    ///
+    /// Reading fails with a `BatchError::Deserialization` on a larger frame, before its payload
+    /// is allocated, which protects against a corrupted length header. The frame is skipped,
+    /// so that the next read, for instance with a skip limit, starts at the next frame.
+    pub fn max_frame_bytes(mut self, max_frame_bytes: usize) -> Self {
+        self.max_frame_bytes = max_frame_bytes;
+        self
+    }
+
+    /// Sets the function decoding the payload of a frame into an item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spring_batch_rs::core::item::ItemReader;
+    /// use spring_batch_rs::item::framed::FramedItemReaderBuilder;
+    ///
+    /// let reader = FramedItemReaderBuilder::new()
+    ///     .decoder(|payload: &[u8]| String::from_utf8(payload.to_vec()))
+    ///     .from_reader(&b"\0\0\0\x05hello"[..]);
+    ///
+    /// assert_eq!(reader.read().unwrap(), Some("hello".to_string()));
+    /// assert_eq!(reader.read().unwrap(), None);
+    /// ```
+    pub fn decoder<E>(mut self, decoder: impl Fn(&[u8]) -> Result<T, E> + 'static) -> Self
+    where
+        E: Into<Box<dyn Error + Send + Sync>>,
+    {
+        self.decoder = Some(Box::new(move |payload| {
+            decoder(payload).map_err(Into::into)
+        }));
+        self
+    }
+
+    /// Creates a `FramedItemReader` from a reader.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the decoder is not set.
+    pub fn from_reader<R: Read>(self, rdr: R) -> FramedItemReader<R, T> {
+        FramedItemReader {
+            reader: RefCell::new(rdr),
+            length_prefix: self.length_prefix,
+            byte_order: self.byte_order,
+            delimiter: self.delimiter,
+            max_frame_bytes: self.max_frame_bytes,
+            decoder: self.decoder.expect("Decoder is mandatory"),
+            record: Cell::new(0),
+        }
+    }
+
+    /// Creates a `FramedItemReader` from a file path.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the file cannot be opened or the decoder is not set.
+    pub fn from_path<P: AsRef<Path>>(self, path: P) -> FramedItemReader<BufReader<File>, T> {
+        let file = File::open(path).expect("Unable to open file");
+
+        self.from_reader(BufReader::new(file))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use tempfile::NamedTempFile;
+
+    use crate::{
+        core::{
+            item::{ItemReader, VecItemWriter},
+            step::{Step, StepBuilder, StepInstance},
+        },
+        BatchError,
+    };
+
+    use super::{ByteOrder, FramedItemReaderBuilder, LengthPrefix};
+
+    fn utf8(payload: &[u8]) -> Result<String, std::string::FromUtf8Error> {
+        String::from_utf8(payload.to_vec())
+    }
+
+    #[test]
+    fn frames_should_be_read_with_each_header_format() {
+        let inputs: [(LengthPrefix, ByteOrder, &[u8]); 4] = [
+            (
+                LengthPrefix::U16,
+                ByteOrder::BigEndian,
+                b"\0\x02ab\0\x00\0\x01c",
+            ),
+            (
+                LengthPrefix::U16,
+                ByteOrder::LittleEndian,
+                b"\x02\0ab\0\0\x01\0c",
+            ),
+            (
+                LengthPrefix::U32,
+                ByteOrder::BigEndian,
+                b"\0\0\0\x02ab\0\0\0\0\0\0\0\x01c",
+            ),
+            (
+                LengthPrefix::U32,
+                ByteOrder::LittleEndian,
+                b"\x02\0\0\0ab\0\0\0\0\x01\0\0\0c",
+            ),
+        ];
+
+        for (length_prefix, byte_order, input) in inputs {
+            let reader = FramedItemReaderBuilder::new()
+                .length_prefix(length_prefix)
+                .byte_order(byte_order)
+                .decoder(utf8)
+                .from_reader(input);
+
+            assert_eq!(reader.read(), Ok(Some("ab".to_string())));
+            assert_eq!(reader.read(), Ok(Some(String::new())));
+            assert_eq!(reader.read(), Ok(Some("c".to_string())));
+            assert_eq!(reader.read(), Ok(None));
+        }
+    }
+
+    #[test]
+    fn truncated_frames_should_be_errors() {
+        let truncated_header = FramedItemReaderBuilder::new()
+            .decoder(utf8)
+            .from_reader(&b"\0\0\0\x01a\0\0"[..]);
+        assert_eq!(truncated_header.read(), Ok(Some("a".to_string())));
+        assert_eq!(
+            truncated_header.read(),
+            Err(BatchError::ItemReader(
+                "truncated length header of frame 1: 2 of 4 bytes".to_string()
+            ))
+        );
+
+        let truncated_payload = FramedItemReaderBuilder::new()
+            .decoder(utf8)
+            .from_reader(&b"\0\0\0\x05abc"[..]);
+        assert_eq!(
+            truncated_payload.read(),
+            Err(BatchError::ItemReader(
+                "truncated frame 1: 3 of 5 bytes".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn undecodable_or_oversized_frames_should_be_errors() {
+        let reader = FramedItemReaderBuilder::new()
+            .length_prefix(LengthPrefix::U16)
+            .max_frame_bytes(2)
+            .decoder(utf8)
+            .from_reader(&b"\0\x01a\0\x01\xff\0\x03abc\0\x01d"[..]);
+
+        assert_eq!(reader.read(), Ok(Some("a".to_string())));
+        assert!(matches!(
+            reader.read(),
+            Err(BatchError::Deserialization {
+                record: Some(2),
+                ..
+            })
+        ));
+        assert_eq!(
+            reader.read(),
+            Err(BatchError::deserialization(
+                Some(3),
+                "frame of 3 bytes exceeds the maximum size of 2 bytes"
+            ))
+        );
+        assert_eq!(reader.read(), Ok(Some("d".to_string())));
+        assert_eq!(reader.read(), Ok(None));
+    }
+
+    #[test]
+    fn delimited_frames_should_be_read_until_the_end_of_the_input() {
+        let reader = FramedItemReaderBuilder::new()
+            .delimiter(0)
+            .max_frame_bytes(3)
+            .decoder(utf8)
+            .from_reader(&b"ab\0\0abc\0abcdef\0g\0"[..]);
+
+        assert_eq!(reader.read(), Ok(Some("ab".to_string())));
+        assert_eq!(reader.read(), Ok(Some(String::new())));
+        assert_eq!(reader.read(), Ok(Some("abc".to_string())));
+        assert_eq!(
+            reader.read(),
+            Err(BatchError::deserialization(
+                Some(4),
+                "frame of 6 bytes exceeds the maximum size of 3 bytes"
+            ))
+        );
+        assert_eq!(reader.read(), Ok(Some("g".to_string())));
+        assert_eq!(reader.read(), Ok(None));
+
+        let unterminated = FramedItemReaderBuilder::new()
+            .delimiter(b'|')
+            .decoder(utf8)
+            .from_reader(&b"a|b"[..]);
+
+        assert_eq!(unterminated.read(), Ok(Some("a".to_string())));
+        assert_eq!(unterminated.read(), Ok(Some("b".to_string())));
+        assert_eq!(unterminated.read(), Ok(None));
+    }
+
+    #[test]
+    fn frames_should_be_read_from_a_file() -> Result<(), Box<dyn std::error::Error>> {
+        let mut file = NamedTempFile::new()?;
+        file.write_all(b"\0\0\0\x05hello")?;
+
+        let reader = FramedItemReaderBuilder::new()
+            .decoder(utf8)
+            .from_path(file.path());
+
+        assert_eq!(reader.read(), Ok(Some("hello".to_string())));
+        assert_eq!(reader.read(), Ok(None));
+
+        Ok(())
+    }
+
+    #[test]
+    fn oversized_frames_should_be_skipped_but_not_retried() {
+        for skip_limit in [0, 1] {
+            let reader = FramedItemReaderBuilder::new()
+                .length_prefix(LengthPrefix::U16)
+                .max_frame_bytes(2)
+                .decoder(utf8)
+                .from_reader(&b"\0\x01a\0\x03abc\0\x01d"[..]);
+            let writer = VecItemWriter::new();
+
+            let step: StepInstance<String, String> = StepBuilder::new()
+                .reader(&reader)
+                .writer(&writer)
+                .retry_limit(1)
+                .skip_limit(skip_limit)
+                .build();
+
+            let result = step.execute();
+
+            assert_eq!(result.is_ok(), skip_limit == 1);
+            assert_eq!(step.get_read_error_count(), 1);
+            assert_eq!(step.get_retry_count(), 0);
+            if skip_limit == 1 {
+                assert_eq!(writer.items(), vec!["a".to_string(), "d".to_string()]);
+            }
+        }
+    }
+}
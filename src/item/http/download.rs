@@ -0,0 +1,89 @@
+use std::io::Read;
+
+use crate::BatchError;
+
+/// The body of an HTTP response, streamed as it is read.
+pub type ResponseBody = Box<dyn Read + Send + Sync + 'static>;
+
+/// Sends a `GET` request to `url` and returns the body of the response, to be read by a reader.
+///
+/// Redirections are followed. The body is not loaded in memory but streamed from the
+/// connection as the reader consumes it.
+///
+/// # Errors
+///
+/// Returns a `BatchError::ItemReader` if the request fails or the response status
+/// is not `2xx`.
+pub fn open_url(url: &str) -> Result<ResponseBody, BatchError> {
+    match ureq::get(url).call() {
+        Ok(response) if (200..300).contains(&response.status()) => Ok(response.into_reader()),
+        Ok(response) | Err(ureq::Error::Status(_, response)) => Err(BatchError::ItemReader(
+            format!("GET {} answered with status {}", url, response.status()),
+        )),
+        Err(error) => Err(BatchError::ItemReader(format!("GET {}: {}", url, error))),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::open_url;
+
+    /// Starts a server answering a single request with the given status and body.
+    pub(crate) fn serve(status: u16, body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/data", listener.local_addr().unwrap());
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                line.clear();
+            }
+
+            write!(
+                stream,
+                "HTTP/1.1 {} STATUS\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+        });
+
+        url
+    }
+
+    #[test]
+    fn body_should_be_streamed() {
+        let url = serve(200, "hello");
+
+        let mut content = String::new();
+        open_url(&url)
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+
+        assert_eq!(content, "hello");
+    }
+
+    #[test]
+    fn error_status_should_fail() {
+        let url = serve(404, "not found");
+
+        let result = open_url(&url);
+
+        assert!(result
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("answered with status 404"));
+    }
+}
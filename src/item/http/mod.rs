@@ -0,0 +1,6 @@
+/// This module contains the webhook writer implementation.
+/// It posts each chunk of items as a JSON payload to a configured URL.
+pub mod webhook_writer;
+
+/// This module contains the download of the content read by the `from_url` builders of readers.
+pub mod download;
@@ -0,0 +1,385 @@
+use std::{thread, time::Duration};
+
+use log::{debug, warn};
+use serde::Serialize;
+use serde_json::Value;
+use ureq::{Agent, AgentBuilder};
+
+use crate::{
+    core::item::{ItemWriter, ItemWriterResult},
+    BatchError,
+};
+
+/// Status code returned by servers asking the client to slow down.
+const TOO_MANY_REQUESTS: u16 = 429;
+
+/// Function used to wrap a chunk of items into the payload sent to the webhook.
+type BatchWrapper<'a, O> = dyn Fn(&[O]) -> Value + 'a;
+
+/// A writer that posts each chunk of items to a webhook as a JSON payload.
+///
+/// By default the payload is a JSON array of the items. A custom envelope can be
+/// built with [`WebhookItemWriterBuilder::batch_wrap`].
+///
+/// Requests answered with a `5xx` or `429` status are retried up to `max_retries`
+/// times, doubling the backoff between attempts. Any other failure, or a response whose
+/// status is not expected, is returned as a `BatchError::ItemWriter` so the step's
+/// skip logic applies.
+pub struct WebhookItemWriter<'a, O> {
+    agent: Agent,
+    url: String,
+    headers: Vec<(String, String)>,
+    expected_status: Vec<u16>,
+    max_retries: usize,
+    retry_backoff: Duration,
+    batch_wrap: Option<Box<BatchWrapper<'a, O>>>,
+}
+
+impl<'a, O: Serialize> WebhookItemWriter<'a, O> {
+    /// Builds the JSON payload for a chunk of items.
+    fn payload(&self, items: &[O]) -> Result<Value, BatchError> {
+        match &self.batch_wrap {
+            Some(batch_wrap) => Ok(batch_wrap(items)),
+            None => serde_json::to_value(items)
+                .map_err(|error| BatchError::ItemWriter(error.to_string())),
+        }
+    }
+
+    /// Checks if the given status code is accepted as a success.
+    ///
+    /// When no expected status has been configured, any `2xx` status is accepted.
+    fn is_expected(&self, status: u16) -> bool {
+        if self.expected_status.is_empty() {
+            (200..300).contains(&status)
+        } else {
+            self.expected_status.contains(&status)
+        }
+    }
+
+    /// Sends the payload once and returns the status code of the response.
+    fn send(&self, payload: &Value) -> Result<u16, BatchError> {
+        let mut request = self.agent.post(&self.url);
+
+        for (name, value) in &self.headers {
+            request = request.set(name, value);
+        }
+
+        match request.send_json(payload) {
+            Ok(response) => Ok(response.status()),
+            Err(ureq::Error::Status(status, _response)) => Ok(status),
+            Err(error) => Err(BatchError::ItemWriter(error.to_string())),
+        }
+    }
+}
+
+impl<'a, O: Serialize> ItemWriter<O> for WebhookItemWriter<'a, O> {
+    /// Posts the items to the webhook.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The items to be written.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` once the webhook answered with an expected status.
+    fn write(&self, items: &[O]) -> ItemWriterResult {
+        if items.is_empty() {
+            return Ok(());
+        }
+
+        let payload = self.payload(items)?;
+        let mut backoff = self.retry_backoff;
+        let mut attempt = 0;
+
+        loop {
+            let status = self.send(&payload)?;
+
+            if self.is_expected(status) {
+                debug!(
+                    "Webhook accepted {} items with status {}",
+                    items.len(),
+                    status
+                );
+                return Ok(());
+            }
+
+            let retryable = status == TOO_MANY_REQUESTS || (500..600).contains(&status);
+
+            if !retryable || attempt >= self.max_retries {
+                return Err(BatchError::ItemWriter(format!(
+                    "webhook {} answered with unexpected status {}",
+                    self.url, status
+                )));
+            }
+
+            attempt += 1;
+            warn!(
+                "Webhook answered with status {}, retrying in {:?} (attempt {}/{})",
+                status, backoff, attempt, self.max_retries
+            );
+            thread::sleep(backoff);
+            backoff *= 2;
+        }
+    }
+}
+
+/// Builder for creating a `WebhookItemWriter`.
+pub struct WebhookItemWriterBuilder<'a, O> {
+    url: Option<String>,
+    headers: Vec<(String, String)>,
+    expected_status: Vec<u16>,
+    timeout: Option<Duration>,
+    max_retries: usize,
+    retry_backoff: Duration,
+    batch_wrap: Option<Box<BatchWrapper<'a, O>>>,
+}
+
+impl<'a, O> Default for WebhookItemWriterBuilder<'a, O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, O> WebhookItemWriterBuilder<'a, O> {
+    /// Creates a new `WebhookItemWriterBuilder` instance.
+    ///
+    /// By default, requests are retried 3 times with an initial backoff of 500ms.
+    pub fn new() -> Self {
+        Self {
+            url: None,
+            headers: Vec::new(),
+            expected_status: Vec::new(),
+            timeout: None,
+            max_retries: 3,
+            retry_backoff: Duration::from_millis(500),
+            batch_wrap: None,
+        }
+    }
+
+    /// Sets the URL the items are posted to.
+    pub fn url(mut self, url: &str) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    /// Adds a header sent with every request.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Authenticates requests with a bearer token.
+    pub fn bearer_auth(self, token: &str) -> Self {
+        let value = format!("Bearer {}", token);
+        self.header("Authorization", &value)
+    }
+
+    /// Adds a status code considered as a success.
+    ///
+    /// When no status is added, any `2xx` status is considered as a success.
+    pub fn expected_status(mut self, status: u16) -> Self {
+        self.expected_status.push(status);
+        self
+    }
+
+    /// Sets the timeout of each request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the number of retries when the webhook answers with a `5xx` or `429` status.
+    pub fn max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the initial delay between two retries. The delay doubles after each retry.
+    pub fn retry_backoff(mut self, retry_backoff: Duration) -> Self {
+        self.retry_backoff = retry_backoff;
+        self
+    }
+
+    /// Sets the function used to wrap a chunk of items into the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use spring_batch_rs::item::http::webhook_writer::{WebhookItemWriter, WebhookItemWriterBuilder};
+    ///
+    /// let writer: WebhookItemWriter<u32> = WebhookItemWriterBuilder::new()
+    ///     .url("http://localhost:8080/events")
+    ///     .batch_wrap(|items| json!({ "count": items.len(), "data": items }))
+    ///     .build();
+    /// ```
+    pub fn batch_wrap(mut self, batch_wrap: impl Fn(&[O]) -> Value + 'a) -> Self {
+        self.batch_wrap = Some(Box::new(batch_wrap));
+        self
+    }
+
+    /// Builds a `WebhookItemWriter` instance.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the URL is not set.
+    pub fn build(self) -> WebhookItemWriter<'a, O> {
+        let url = self.url.expect("URL is mandatory");
+
+        let mut agent_builder = AgentBuilder::new();
+        if let Some(timeout) = self.timeout {
+            agent_builder = agent_builder.timeout(timeout);
+        }
+
+        WebhookItemWriter {
+            agent: agent_builder.build(),
+            url,
+            headers: self.headers,
+            expected_status: self.expected_status,
+            max_retries: self.max_retries,
+            retry_backoff: self.retry_backoff,
+            batch_wrap: self.batch_wrap,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+        time::Duration,
+    };
+
+    use serde_json::json;
+
+    use super::WebhookItemWriterBuilder;
+    use crate::core::item::ItemWriter;
+
+    /// Starts a server answering with the given statuses and sending back the received bodies.
+    fn serve(statuses: Vec<u16>) -> (String, mpsc::Receiver<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for status in statuses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut content_length = 0;
+                let mut authorization = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end().to_string();
+                    if line.is_empty() {
+                        break;
+                    }
+                    let lowercase = line.to_lowercase();
+                    if let Some(value) = lowercase.strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap();
+                    } else if lowercase.starts_with("authorization:") {
+                        authorization = line["authorization:".len()..].trim().to_string();
+                    }
+                }
+
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                sender
+                    .send((authorization, String::from_utf8(body).unwrap()))
+                    .unwrap();
+
+                let mut stream = stream;
+                write!(
+                    stream,
+                    "HTTP/1.1 {} STATUS\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                    status
+                )
+                .unwrap();
+            }
+        });
+
+        (url, receiver)
+    }
+
+    #[test]
+    fn items_should_be_posted_as_json_array() {
+        let (url, receiver) = serve(vec![200]);
+
+        let writer = WebhookItemWriterBuilder::new()
+            .url(&url)
+            .bearer_auth("secret")
+            .build();
+
+        let result = writer.write(&[1, 2, 3]);
+
+        assert!(result.is_ok());
+        let (authorization, body) = receiver.recv().unwrap();
+        assert_eq!(authorization, "Bearer secret");
+        assert_eq!(body, "[1,2,3]");
+    }
+
+    #[test]
+    fn payload_should_be_wrapped() {
+        let (url, receiver) = serve(vec![202]);
+
+        let writer = WebhookItemWriterBuilder::new()
+            .url(&url)
+            .batch_wrap(|items: &[u32]| json!({ "count": items.len(), "data": items }))
+            .build();
+
+        let result = writer.write(&[1, 2]);
+
+        assert!(result.is_ok());
+        let (_, body) = receiver.recv().unwrap();
+        assert_eq!(body, r#"{"count":2,"data":[1,2]}"#);
+    }
+
+    #[test]
+    fn server_errors_should_be_retried() {
+        let (url, receiver) = serve(vec![503, 429, 200]);
+
+        let writer = WebhookItemWriterBuilder::new()
+            .url(&url)
+            .retry_backoff(Duration::from_millis(1))
+            .build();
+
+        let result = writer.write(&["a"]);
+
+        assert!(result.is_ok());
+        assert_eq!(receiver.iter().count(), 3);
+    }
+
+    #[test]
+    fn unexpected_status_should_fail_without_retry() {
+        let (url, receiver) = serve(vec![400]);
+
+        let writer = WebhookItemWriterBuilder::new()
+            .url(&url)
+            .retry_backoff(Duration::from_millis(1))
+            .build();
+
+        let result = writer.write(&["a"]);
+
+        assert!(result.is_err());
+        assert_eq!(receiver.iter().count(), 1);
+    }
+
+    #[test]
+    fn exhausted_retries_should_fail() {
+        let (url, _receiver) = serve(vec![500, 500]);
+
+        let writer = WebhookItemWriterBuilder::new()
+            .url(&url)
+            .max_retries(1)
+            .retry_backoff(Duration::from_millis(1))
+            .build();
+
+        let result = writer.write(&["a"]);
+
+        assert!(result.is_err());
+    }
+}
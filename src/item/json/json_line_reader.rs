@@ -0,0 +1,159 @@
+use std::{
+    cell::{Cell, RefCell},
+    io::{BufRead, BufReader, Read},
+    marker::PhantomData,
+    path::Path,
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    core::item::{ItemReader, ItemReaderResult},
+    item::file::{is_gzip_path, FileReader},
+    BatchError,
+};
+
+/// A reader for newline-delimited JSON (JSONL), where each line holds one JSON object.
+///
+/// Blank lines are skipped and trailing whitespace is ignored.
+pub struct JsonLineItemReader<R, T> {
+    pd: PhantomData<T>,
+    reader: RefCell<BufReader<R>>,
+    line: RefCell<String>,
+    line_number: Cell<usize>,
+}
+
+impl<R: Read, T: DeserializeOwned> ItemReader<T> for JsonLineItemReader<R, T> {
+    /// Reads the next item from the next non-blank line.
+    ///
+    /// Returns `Ok(None)` at the end of the input, or a `BatchError::Deserialization`
+    /// with the line number as record if the line cannot be deserialized.
+    fn read(&self) -> ItemReaderResult<T> {
+        let mut reader = self.reader.borrow_mut();
+        let mut line = self.line.borrow_mut();
+
+        loop {
+            line.clear();
+
+            let length = reader
+                .read_line(&mut line)
+                .map_err(|error| BatchError::ItemReader(error.to_string()))?;
+
+            if length == 0 {
+                return Ok(None);
+            }
+
+            self.line_number.set(self.line_number.get() + 1);
+
+            let content = line.trim();
+            if content.is_empty() {
+                continue;
+            }
+
+            return serde_json::from_str(content).map(Some).map_err(|error| {
+                BatchError::deserialization(Some(self.line_number.get() as u64), error)
+            });
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct JsonLineItemReaderBuilder<T> {
+    _pd: PhantomData<T>,
+    capacity: Option<usize>,
+    gzip: bool,
+}
+
+impl<T: DeserializeOwned> JsonLineItemReaderBuilder<T> {
+    pub fn new() -> JsonLineItemReaderBuilder<T> {
+        Self {
+            _pd: PhantomData,
+            capacity: Some(8 * 1024),
+            gzip: false,
+        }
+    }
+
+    pub fn capacity(mut self, capacity: usize) -> JsonLineItemReaderBuilder<T> {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    pub fn from_reader<R: Read>(self, rdr: R) -> JsonLineItemReader<R, T> {
+        JsonLineItemReader {
+            pd: PhantomData,
+            reader: RefCell::new(BufReader::with_capacity(self.capacity.unwrap(), rdr)),
+            line: RefCell::new(String::new()),
+            line_number: Cell::new(0),
+        }
+    }
+
+    /// Decompresses the file opened by `from_path` with gzip, whatever its extension.
+    #[cfg(feature = "gzip")]
+    pub fn gzip(mut self, yes: bool) -> JsonLineItemReaderBuilder<T> {
+        self.gzip = yes;
+        self
+    }
+
+    /// Creates a `JsonLineItemReader` from a file path.
+    ///
+    /// With the `gzip` feature, files with a `.gz` extension are decompressed while read.
+    pub fn from_path<P: AsRef<Path>>(self, path: P) -> JsonLineItemReader<FileReader, T> {
+        let gzip = self.gzip || is_gzip_path(&path);
+        let file = FileReader::open(path, gzip).expect("Unable to open file");
+
+        self.from_reader(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::{
+        core::item::{ItemReader, ItemReaderResult},
+        BatchError,
+    };
+
+    use super::JsonLineItemReaderBuilder;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Event {
+        name: String,
+        count: u32,
+    }
+
+    #[test]
+    fn lines_should_be_deserialized_skipping_blank_ones() {
+        let input = "{\"name\": \"created\", \"count\": 1}\n\n   \n{\"name\": \"deleted\", \"count\": 2}   \r\n";
+
+        let reader = JsonLineItemReaderBuilder::new()
+            .capacity(16)
+            .from_reader(input.as_bytes());
+
+        let first: Event = reader.read().unwrap().unwrap();
+        let second: Event = reader.read().unwrap().unwrap();
+        let end: ItemReaderResult<Event> = reader.read();
+
+        assert_eq!(first.name, "created");
+        assert_eq!(second.count, 2);
+        assert!(end.unwrap().is_none());
+    }
+
+    #[test]
+    fn invalid_line_should_report_its_number() {
+        let input = "{\"name\": \"created\", \"count\": 1}\n\n{\"name\": \"deleted\"\n";
+
+        let reader = JsonLineItemReaderBuilder::new().from_reader(input.as_bytes());
+
+        let _: Event = reader.read().unwrap().unwrap();
+        let result: ItemReaderResult<Event> = reader.read();
+
+        assert!(matches!(
+            result,
+            Err(BatchError::Deserialization {
+                record: Some(3),
+                ..
+            })
+        ));
+    }
+}
@@ -1,14 +1,22 @@
 use std::{
     cell::{Cell, RefCell},
-    io::{BufRead, BufReader, Read},
+    io::{stdin, BufRead, BufReader, ErrorKind, Read, StdinLock},
     marker::PhantomData,
+    path::Path,
 };
 
 use log::debug;
 use serde::de::DeserializeOwned;
 
+#[cfg(feature = "datetime")]
+use crate::item::datetime::DateTimeFormat;
+#[cfg(feature = "http")]
+use crate::item::http::download::{open_url, ResponseBody};
+#[cfg(feature = "object-store")]
+use crate::item::object_store::download::{open_object, open_s3_object, ObjectBody};
 use crate::{
     core::item::{ItemReader, ItemReaderResult},
+    item::file::{is_gzip_path, FileReader},
     BatchError,
 };
 
@@ -16,28 +24,49 @@ use crate::{
 enum JsonParserResult {
     NotEnded,
     ParsingError { error: serde_json::Error },
+    ItemTooLarge,
 }
 
+/// Default maximum size of a single item: 16 MiB.
+const DEFAULT_MAX_ITEM_BYTES: usize = 16 * 1024 * 1024;
+
+/// Byte order mark written at the start of UTF-8 files by some Windows tools.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
 pub struct JsonItemReader<R, T> {
     pd: PhantomData<T>,
     reader: RefCell<BufReader<R>>,
     capacity: usize,
+    max_item_bytes: usize,
     level: Cell<u16>,
     index: Cell<usize>,
     object: RefCell<Vec<u8>>,
+    in_string: Cell<bool>,
+    escaped: Cell<bool>,
+    record: Cell<u64>,
+    started: Cell<bool>,
+    #[cfg(feature = "datetime")]
+    datetime_formats: Vec<DateTimeFormat>,
 }
 
 impl<R: Read, T: DeserializeOwned> JsonItemReader<R, T> {
-    fn new(rdr: R, capacity: usize) -> Self {
+    fn new(rdr: R, capacity: usize, max_item_bytes: usize) -> Self {
         let buf_reader = BufReader::with_capacity(capacity, rdr);
 
         Self {
             pd: PhantomData,
             reader: RefCell::new(buf_reader),
             capacity,
+            max_item_bytes,
             level: Cell::new(0),
             index: Cell::new(0),
             object: RefCell::new(Vec::new()),
+            in_string: Cell::new(false),
+            escaped: Cell::new(false),
+            record: Cell::new(0),
+            started: Cell::new(false),
+            #[cfg(feature = "datetime")]
+            datetime_formats: Vec::new(),
         }
     }
 
@@ -46,19 +75,19 @@ impl<R: Read, T: DeserializeOwned> JsonItemReader<R, T> {
     }
 
     fn is_new_seq(&self, buffer: &[u8]) -> bool {
-        self.level == 0.into() && self.get_current_char(buffer) == b'['
+        !self.in_string.get() && self.level == 0.into() && self.get_current_char(buffer) == b'['
     }
 
     fn is_end_seq(&self, buffer: &[u8]) -> bool {
-        self.level == 0.into() && self.get_current_char(buffer) == b']'
+        !self.in_string.get() && self.level == 0.into() && self.get_current_char(buffer) == b']'
     }
 
     fn is_new_object(&self, buffer: &[u8]) -> bool {
-        self.level == 0.into() && self.get_current_char(buffer) == b'{'
+        !self.in_string.get() && self.level == 0.into() && self.get_current_char(buffer) == b'{'
     }
 
     fn is_end_object(&self, buffer: &[u8]) -> bool {
-        self.level == 1.into() && self.get_current_char(buffer) == b'}'
+        !self.in_string.get() && self.level == 1.into() && self.get_current_char(buffer) == b'}'
     }
 
     fn start_new(&self) {
@@ -67,9 +96,27 @@ impl<R: Read, T: DeserializeOwned> JsonItemReader<R, T> {
 
     fn append_char(&self, buffer: &[u8]) {
         let current_char = self.get_current_char(buffer);
-        if current_char != b' ' && current_char != b'\n' {
-            self.object.borrow_mut().push(self.get_current_char(buffer));
+
+        // Whitespace is only insignificant outside of strings
+        if self.in_string.get() {
+            if self.escaped.get() {
+                self.escaped.set(false);
+            } else if current_char == b'\\' {
+                self.escaped.set(true);
+            } else if current_char == b'"' {
+                self.in_string.set(false);
+            }
+        } else if current_char == b'"' {
+            self.in_string.set(true);
+        } else if current_char.is_ascii_whitespace() {
+            return;
         }
+
+        self.object.borrow_mut().push(current_char);
+    }
+
+    fn is_too_large(&self) -> bool {
+        self.object.borrow().len() > self.max_item_bytes
     }
 
     fn clear_buff(&self) {
@@ -99,7 +146,9 @@ impl<R: Read, T: DeserializeOwned> JsonItemReader<R, T> {
 
             let current_char = self.get_current_char(buffer);
 
-            if current_char == b'{' {
+            if self.in_string.get() {
+                // Braces inside strings do not change the nesting level
+            } else if current_char == b'{' {
                 self.level_inc();
             } else if current_char == b'}' {
                 self.level_dec();
@@ -107,12 +156,16 @@ impl<R: Read, T: DeserializeOwned> JsonItemReader<R, T> {
 
             self.append_char(buffer);
 
+            if self.is_too_large() {
+                return Err(JsonParserResult::ItemTooLarge);
+            }
+
             self.index_inc();
 
             if self.is_end_object(buffer) {
                 self.append_char(buffer);
 
-                let result = serde_json::from_slice(self.object.borrow_mut().as_slice());
+                let result = self.deserialize(self.object.borrow().as_slice());
                 debug!(
                     "object ok: {}",
                     std::str::from_utf8(self.object.borrow().as_slice()).unwrap()
@@ -125,16 +178,61 @@ impl<R: Read, T: DeserializeOwned> JsonItemReader<R, T> {
         }
 
         self.append_char(buffer);
+
+        if self.is_too_large() {
+            return Err(JsonParserResult::ItemTooLarge);
+        }
+
         Err(JsonParserResult::NotEnded)
     }
+
+    /// Deserializes an object, converting the configured datetime fields to UTC.
+    #[cfg(feature = "datetime")]
+    fn deserialize(&self, object: &[u8]) -> Result<T, serde_json::Error> {
+        use serde::de::Error;
+
+        if self.datetime_formats.is_empty() {
+            return serde_json::from_slice(object);
+        }
+
+        let mut value: serde_json::Value = serde_json::from_slice(object)?;
+
+        for format in &self.datetime_formats {
+            if let Some(field) = value.get_mut(format.field()) {
+                if let Some(text) = field.as_str() {
+                    let datetime = format.to_rfc3339(text).map_err(serde_json::Error::custom)?;
+                    *field = serde_json::Value::String(datetime);
+                }
+            }
+        }
+
+        serde_json::from_value(value)
+    }
+
+    #[cfg(not(feature = "datetime"))]
+    fn deserialize(&self, object: &[u8]) -> Result<T, serde_json::Error> {
+        serde_json::from_slice(object)
+    }
 }
 
 impl<R: Read, T: DeserializeOwned> ItemReader<T> for JsonItemReader<R, T> {
+    /// Reads the next item of the JSON array.
+    ///
+    /// A leading UTF-8 byte order mark and whitespace are skipped, and a single top-level
+    /// object which is not wrapped in an array is read as the only item.
+    ///
+    /// Returns `Err(BatchError::Deserialization { .. })` with the number of the item in
+    /// the array if it cannot be deserialized, and `Err(BatchError::ItemReader(error))`
+    /// if the input cannot be read or the item is too large.
     fn read(&self) -> ItemReaderResult<T> {
         let mut buf_reader = self.reader.borrow_mut();
 
         loop {
-            let buffer = &mut buf_reader.fill_buf().unwrap();
+            let buffer = &mut match buf_reader.fill_buf() {
+                Ok(buffer) => buffer,
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => return Err(BatchError::ItemReader(error.to_string())),
+            };
 
             let buffer_length = buffer.len();
 
@@ -142,9 +240,16 @@ impl<R: Read, T: DeserializeOwned> ItemReader<T> for JsonItemReader<R, T> {
                 return Ok(None);
             }
 
+            // A leading byte order mark is not part of the JSON document
+            if !self.started.replace(true) && buffer.starts_with(UTF8_BOM) {
+                buf_reader.consume(UTF8_BOM.len());
+                continue;
+            }
+
             let result: Result<T, JsonParserResult> = self.next(buffer);
 
             if let Ok(record) = result {
+                self.record.set(self.record.get() + 1);
                 return Ok(Some(record));
             } else if let Err(error) = result {
                 match error {
@@ -153,7 +258,14 @@ impl<R: Read, T: DeserializeOwned> ItemReader<T> for JsonItemReader<R, T> {
                         buf_reader.consume(self.capacity)
                     }
                     JsonParserResult::ParsingError { error } => {
-                        return Err(BatchError::ItemReader(error.to_string()))
+                        self.record.set(self.record.get() + 1);
+                        return Err(BatchError::deserialization(Some(self.record.get()), error));
+                    }
+                    JsonParserResult::ItemTooLarge => {
+                        return Err(BatchError::ItemReader(format!(
+                            "item exceeds the maximum size of {} bytes",
+                            self.max_item_bytes
+                        )))
                     }
                 }
             }
@@ -165,6 +277,10 @@ impl<R: Read, T: DeserializeOwned> ItemReader<T> for JsonItemReader<R, T> {
 pub struct JsonItemReaderBuilder<T> {
     _pd: PhantomData<T>,
     capacity: Option<usize>,
+    max_item_bytes: usize,
+    gzip: bool,
+    #[cfg(feature = "datetime")]
+    datetime_formats: Vec<DateTimeFormat>,
 }
 
 impl<T: DeserializeOwned> JsonItemReaderBuilder<T> {
@@ -172,6 +288,10 @@ impl<T: DeserializeOwned> JsonItemReaderBuilder<T> {
         Self {
             _pd: PhantomData,
             capacity: Some(8 * 1024),
+            max_item_bytes: DEFAULT_MAX_ITEM_BYTES,
+            gzip: false,
+            #[cfg(feature = "datetime")]
+            datetime_formats: Vec::new(),
         }
     }
 
@@ -180,8 +300,144 @@ impl<T: DeserializeOwned> JsonItemReaderBuilder<T> {
         self
     }
 
+    /// Sets the maximum size in bytes of a single item (16 MiB by default).
+    ///
+    /// Reading fails with a `BatchError::ItemReader` as soon as an item exceeds this size,
+    /// which prevents unbounded buffering on malformed input such as an unterminated object.
+    pub fn max_item_bytes(mut self, max_item_bytes: usize) -> JsonItemReaderBuilder<T> {
+        self.max_item_bytes = max_item_bytes;
+        self
+    }
+
+    /// Parses the named field with a `chrono` format, as a local datetime in the given timezone.
+    ///
+    /// Values are normalized to UTC, so the field can be deserialized into a `chrono::DateTime<Utc>`.
+    #[cfg(feature = "datetime")]
+    pub fn datetime_format(
+        mut self,
+        field: &str,
+        format: &str,
+        tz: chrono_tz::Tz,
+    ) -> JsonItemReaderBuilder<T> {
+        self.datetime_formats
+            .push(DateTimeFormat::new(field, format, tz));
+        self
+    }
+
     pub fn from_reader<R: Read>(self, rdr: R) -> JsonItemReader<R, T> {
-        JsonItemReader::new(rdr, self.capacity.unwrap())
+        #[allow(unused_mut)]
+        let mut reader = JsonItemReader::new(rdr, self.capacity.unwrap(), self.max_item_bytes);
+
+        #[cfg(feature = "datetime")]
+        {
+            reader.datetime_formats = self.datetime_formats;
+        }
+
+        reader
+    }
+
+    /// Decompresses the file opened by `from_path` with gzip, whatever its extension.
+    #[cfg(feature = "gzip")]
+    pub fn gzip(mut self, yes: bool) -> JsonItemReaderBuilder<T> {
+        self.gzip = yes;
+        self
+    }
+
+    /// Creates a `JsonItemReader` from a file path.
+    ///
+    /// With the `gzip` feature, files with a `.gz` extension are decompressed while read.
+    pub fn from_path<P: AsRef<Path>>(self, path: P) -> JsonItemReader<FileReader, T> {
+        let gzip = self.gzip || is_gzip_path(&path);
+        let file = FileReader::open(path, gzip).expect("Unable to open file");
+
+        self.from_reader(file)
+    }
+
+    /// Creates a `JsonItemReader` reading the entry named `entry` of the zip archive at `path`.
+    ///
+    /// The entry is decompressed while read, without extracting the archive.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BatchError::ItemReader` if the archive cannot be opened, if it has no
+    /// such entry, or if the entry is encrypted or compressed with another method than deflate.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use spring_batch_rs::item::json::json_reader::JsonItemReaderBuilder;
+    ///
+    /// let reader = JsonItemReaderBuilder::<serde_json::Value>::new()
+    ///     .from_zip_entry("export.zip", "people.json")
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "zip")]
+    pub fn from_zip_entry<P: AsRef<Path>>(
+        self,
+        path: P,
+        entry: &str,
+    ) -> Result<JsonItemReader<FileReader, T>, BatchError> {
+        let file = FileReader::open_zip_entry(path, entry)
+            .map_err(|error| BatchError::ItemReader(error.to_string()))?;
+
+        Ok(self.from_reader(file))
+    }
+
+    /// Creates a `JsonItemReader` reading from the standard input, so that data can be piped
+    /// into a batch job (`cat data.json | mytool`).
+    ///
+    /// The reader locks stdin for its whole lifetime: only one reader can own stdin,
+    /// and any other attempt to read from it blocks until the reader is dropped.
+    /// Reads block until data is available, and the reader ends when stdin is closed.
+    pub fn from_stdin(self) -> JsonItemReader<StdinLock<'static>, T> {
+        self.from_reader(stdin().lock())
+    }
+
+    /// Creates a `JsonItemReader` reading the body of the response to a `GET` request to `url`.
+    ///
+    /// The body is streamed from the connection as items are read.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BatchError::ItemReader` if the request fails or the response status
+    /// is not `2xx`.
+    #[cfg(feature = "http")]
+    pub fn from_url(self, url: &str) -> Result<JsonItemReader<ResponseBody, T>, BatchError> {
+        Ok(self.from_reader(open_url(url)?))
+    }
+
+    /// Creates a `JsonItemReader` reading the object `key` of the S3 `bucket`.
+    ///
+    /// The region, the endpoint and the credentials are read from the standard `AWS_*`
+    /// environment variables; use `from_object_store` to set them explicitly.
+    /// The object is streamed from the store as items are read.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BatchError::ItemReader` if the object cannot be fetched.
+    #[cfg(feature = "object-store")]
+    pub fn from_s3(
+        self,
+        bucket: &str,
+        key: &str,
+    ) -> Result<JsonItemReader<ObjectBody, T>, BatchError> {
+        Ok(self.from_reader(open_s3_object(bucket, key)?))
+    }
+
+    /// Creates a `JsonItemReader` reading the object at `location` in `store`.
+    ///
+    /// The object is streamed from the store as items are read.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BatchError::ItemReader` if the object cannot be fetched.
+    #[cfg(feature = "object-store")]
+    pub fn from_object_store(
+        self,
+        store: std::sync::Arc<dyn object_store::ObjectStore>,
+        location: &str,
+    ) -> Result<JsonItemReader<ObjectBody, T>, BatchError> {
+        Ok(self.from_reader(open_object(store, location)?))
     }
 }
 
@@ -239,6 +495,134 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn spaces_and_braces_inside_strings_should_be_kept() -> Result<(), Box<dyn Error>> {
+        #[derive(serde::Deserialize)]
+        struct Note {
+            text: String,
+        }
+
+        let input = Cursor::new(r#"[{"text": "a {nested} \"quoted\" text"}, {"text": "b"}]"#);
+
+        let reader = JsonItemReaderBuilder::new().from_reader(input);
+
+        let note: Note = reader.read()?.unwrap();
+        assert_eq!(note.text, r#"a {nested} "quoted" text"#);
+
+        let note: Note = reader.read()?.unwrap();
+        assert_eq!(note.text, "b");
+
+        Ok(())
+    }
+
+    #[test]
+    fn bom_and_whitespace_before_the_array_should_be_skipped() -> Result<(), Box<dyn Error>> {
+        #[derive(serde::Deserialize)]
+        struct Note {
+            text: String,
+        }
+
+        let input = Cursor::new(
+            b"\xEF\xBB\xBF \r\n\t[\r\n\t{\"text\": \"a\"},\r\n\t{\"text\": \"b\"}\r\n]\r\n",
+        );
+
+        let reader = JsonItemReaderBuilder::new().from_reader(input);
+
+        let note: Note = reader.read()?.unwrap();
+        assert_eq!(note.text, "a");
+        let note: Note = reader.read()?.unwrap();
+        assert_eq!(note.text, "b");
+        assert!(ItemReader::<Note>::read(&reader)?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn bare_object_should_be_read_as_the_only_item() -> Result<(), Box<dyn Error>> {
+        #[derive(serde::Deserialize)]
+        struct Note {
+            text: String,
+        }
+
+        let input = Cursor::new(b"\xEF\xBB\xBF\n{\"text\": \"alone\"}\n");
+
+        let reader = JsonItemReaderBuilder::new().from_reader(input);
+
+        let note: Note = reader.read()?.unwrap();
+        assert_eq!(note.text, "alone");
+        assert!(ItemReader::<Note>::read(&reader)?.is_none());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "zip")]
+    #[test]
+    fn content_should_be_read_from_a_zip_entry() -> Result<(), Box<dyn Error>> {
+        use crate::item::file::zip_tests::zip_archive;
+        use zip::CompressionMethod;
+
+        #[derive(serde::Deserialize)]
+        struct Note {
+            text: String,
+        }
+
+        let archive = zip_archive(&[(
+            "notes.json",
+            r#"[{"text": "zipped"}]"#,
+            CompressionMethod::Deflated,
+        )]);
+
+        let reader = JsonItemReaderBuilder::new().from_zip_entry(archive.path(), "notes.json")?;
+
+        let note: Note = reader.read()?.unwrap();
+        assert_eq!(note.text, "zipped");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "object-store")]
+    #[test]
+    fn content_should_be_read_from_an_object_store() -> Result<(), Box<dyn Error>> {
+        use crate::item::object_store::download::tests::memory_store;
+
+        #[derive(serde::Deserialize)]
+        struct Note {
+            text: String,
+        }
+
+        let store = memory_store("exports/notes.json", r#"[{"text": "stored"}]"#);
+
+        let reader = JsonItemReaderBuilder::new().from_object_store(store, "exports/notes.json")?;
+
+        let note: Note = reader.read()?.unwrap();
+        assert_eq!(note.text, "stored");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn datetime_should_be_parsed_in_timezone() -> Result<(), Box<dyn Error>> {
+        use chrono::{DateTime, Utc};
+        use chrono_tz::Europe::Paris;
+
+        #[derive(serde::Deserialize)]
+        struct Event {
+            date: DateTime<Utc>,
+        }
+
+        let input = Cursor::new(r#"[{"date": "15/01/2024 14:30"}]"#);
+
+        let reader = JsonItemReaderBuilder::new()
+            .datetime_format("date", "%d/%m/%Y %H:%M", Paris)
+            .from_reader(input);
+
+        let event: Event = reader.read()?.unwrap();
+        assert_eq!(event.date.to_rfc3339(), "2024-01-15T13:30:00+00:00");
+
+        Ok(())
+    }
+
     #[test]
     fn content_from_bytes_should_be_deserialized() -> Result<(), Box<dyn Error>> {
         let input = Cursor::new(String::from("foo\nbar\nbaz\n"));
@@ -253,4 +637,57 @@ mod tests {
 
         Ok(())
     }
+
+    /// A source behaving like a pipe: interrupted once, then delivering small reads.
+    struct PipeLike {
+        data: Cursor<Vec<u8>>,
+        interrupted: bool,
+    }
+
+    impl std::io::Read for PipeLike {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if !self.interrupted {
+                self.interrupted = true;
+                return Err(std::io::ErrorKind::Interrupted.into());
+            }
+            let len = buf.len().min(7);
+            self.data.read(&mut buf[..len])
+        }
+    }
+
+    #[test]
+    fn interrupted_and_partial_reads_should_be_handled() -> Result<(), Box<dyn Error>> {
+        let input = PipeLike {
+            data: Cursor::new(br#"[{"value": 1}, {"value": 2}]"#.to_vec()),
+            interrupted: false,
+        };
+
+        let reader = JsonItemReaderBuilder::new().from_reader(input);
+
+        let first: serde_json::Value = reader.read()?.unwrap();
+        let second: serde_json::Value = reader.read()?.unwrap();
+
+        assert_eq!(first["value"], 1);
+        assert_eq!(second["value"], 2);
+        assert!(ItemReader::<serde_json::Value>::read(&reader)?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unterminated_object_should_hit_the_size_limit() {
+        let input = format!(r#"[{{"name": "{}"#, "x".repeat(1000));
+
+        let reader = JsonItemReaderBuilder::new()
+            .capacity(64)
+            .max_item_bytes(256)
+            .from_reader(input.as_bytes());
+
+        let result: ItemReaderResult<Person> = reader.read();
+
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "Error occurred in the ItemReader: item exceeds the maximum size of 256 bytes"
+        );
+    }
 }
@@ -1,39 +1,102 @@
 use std::{
     cell::{Cell, RefCell},
-    fs::File,
-    io::{BufWriter, Write},
+    io::{self, BufWriter, ErrorKind, Write},
     path::Path,
 };
 
 use crate::{
     core::item::{ItemWriter, ItemWriterResult},
+    item::file::{is_gzip_path, FileWriter},
     BatchError,
 };
 
+/// Completes the underlying writer when the item writer is closed.
+type FinishFn<T> = fn(&T) -> io::Result<()>;
+
+/// Layout of the items written by a `JsonItemWriter`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JsonOutputFormat {
+    /// A single JSON array holding all the items.
+    #[default]
+    Array,
+    /// One compact JSON object per line (JSONL), without enclosing array,
+    /// which suits append-only log ingestion.
+    Lines,
+}
+
 pub struct JsonItemWriter<T: Write> {
     stream: RefCell<BufWriter<T>>,
     use_pretty_formatter: bool,
+    format: JsonOutputFormat,
     is_first_element: Cell<bool>,
+    stop_on_broken_pipe: bool,
+    closed: Cell<bool>,
+    finish: Option<FinishFn<T>>,
+}
+
+impl<T: Write> JsonItemWriter<T> {
+    /// Converts the result of an I/O operation, marking the writer as closed
+    /// on a broken pipe when `stop_on_broken_pipe` is enabled.
+    fn manage_io_result(&self, result: io::Result<()>) -> ItemWriterResult {
+        match result {
+            Ok(()) => Ok(()),
+            Err(error) if self.stop_on_broken_pipe && error.kind() == ErrorKind::BrokenPipe => {
+                self.closed.set(true);
+                Ok(())
+            }
+            Err(error) => Err(BatchError::ItemWriter(error.to_string())),
+        }
+    }
+
+    /// Writes each item as a compact JSON object on its own line.
+    fn write_lines<R: serde::Serialize>(&self, items: &[R]) -> ItemWriterResult {
+        let mut lines = Vec::new();
+
+        for item in items.iter() {
+            serde_json::to_writer(&mut lines, item)
+                .map_err(|error| BatchError::serialization(None, error))?;
+            lines.push(b'\n');
+        }
+
+        let result = self.stream.borrow_mut().write_all(&lines);
+
+        self.manage_io_result(result)
+    }
 }
 
 impl<T: Write, R: serde::Serialize> ItemWriter<R> for JsonItemWriter<T> {
     fn write(&self, items: &[R]) -> ItemWriterResult {
+        if self.closed.get() {
+            return Ok(());
+        }
+
+        if self.format == JsonOutputFormat::Lines {
+            return self.write_lines(items);
+        }
+
+        // Serialize every item first, so that a failing item leaves the output untouched
+        let serialized: Vec<String> = items
+            .iter()
+            .map(|item| {
+                if self.use_pretty_formatter {
+                    serde_json::to_string_pretty(item)
+                } else {
+                    serde_json::to_string(item)
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|error| BatchError::serialization(None, error))?;
+
         let mut json_chunk = String::new();
 
-        for item in items.iter() {
+        for item in serialized {
             if !self.is_first_element.get() {
                 json_chunk.push(',');
             } else {
                 self.is_first_element.set(false);
             }
 
-            let result = if self.use_pretty_formatter {
-                serde_json::to_string_pretty(item)
-            } else {
-                serde_json::to_string(item)
-            };
-
-            json_chunk.push_str(&result.unwrap());
+            json_chunk.push_str(&item);
 
             if self.use_pretty_formatter {
                 json_chunk.push('\n');
@@ -42,22 +105,24 @@ impl<T: Write, R: serde::Serialize> ItemWriter<R> for JsonItemWriter<T> {
 
         let result = self.stream.borrow_mut().write_all(json_chunk.as_bytes());
 
-        match result {
-            Ok(_ser) => Ok(()),
-            Err(error) => Err(BatchError::ItemWriter(error.to_string())),
-        }
+        self.manage_io_result(result)
     }
 
     fn flush(&self) -> ItemWriterResult {
+        if self.closed.get() {
+            return Ok(());
+        }
+
         let result = self.stream.borrow_mut().flush();
 
-        match result {
-            Ok(()) => Ok(()),
-            Err(error) => Err(BatchError::ItemWriter(error.to_string())),
-        }
+        self.manage_io_result(result)
     }
 
     fn open(&self) -> ItemWriterResult {
+        if self.format == JsonOutputFormat::Lines {
+            return Ok(());
+        }
+
         let begin_array = if self.use_pretty_formatter {
             b"[\n".to_vec()
         } else {
@@ -66,33 +131,45 @@ impl<T: Write, R: serde::Serialize> ItemWriter<R> for JsonItemWriter<T> {
 
         let result = self.stream.borrow_mut().write_all(&begin_array);
 
-        match result {
-            Ok(()) => Ok(()),
-            Err(error) => Err(BatchError::ItemWriter(error.to_string())),
-        }
+        self.manage_io_result(result)
     }
 
     fn close(&self) -> ItemWriterResult {
-        let end_array = if self.use_pretty_formatter {
-            b"\n]\n".to_vec()
-        } else {
-            b"]\n".to_vec()
+        if self.closed.get() {
+            return Ok(());
+        }
+
+        let end_array: &[u8] = match (self.format, self.use_pretty_formatter) {
+            (JsonOutputFormat::Lines, _) => b"",
+            (JsonOutputFormat::Array, true) => b"\n]\n",
+            (JsonOutputFormat::Array, false) => b"]\n",
         };
 
-        let result = self.stream.borrow_mut().write_all(&end_array);
+        let result = self.stream.borrow_mut().write_all(end_array);
         let _ = self.stream.borrow_mut().flush();
+        self.manage_io_result(result)?;
 
-        match result {
-            Ok(()) => Ok(()),
-            Err(error) => Err(BatchError::ItemWriter(error.to_string())),
+        match self.finish {
+            Some(finish) if !self.closed.get() => {
+                let result = finish(self.stream.borrow().get_ref());
+                self.manage_io_result(result)
+            }
+            _ => Ok(()),
         }
     }
+
+    fn is_closed(&self) -> bool {
+        self.closed.get()
+    }
 }
 
 #[derive(Default)]
 pub struct JsonItemWriterBuilder {
     indent: Box<[u8]>,
     pretty_formatter: bool,
+    format: JsonOutputFormat,
+    stop_on_broken_pipe: bool,
+    gzip: bool,
 }
 
 impl JsonItemWriterBuilder {
@@ -100,6 +177,9 @@ impl JsonItemWriterBuilder {
         Self {
             indent: Box::from(b"  ".to_vec()),
             pretty_formatter: false,
+            format: JsonOutputFormat::Array,
+            stop_on_broken_pipe: false,
+            gzip: false,
         }
     }
 
@@ -113,16 +193,38 @@ impl JsonItemWriterBuilder {
         self
     }
 
-    pub fn from_path<R: AsRef<Path>>(self, path: R) -> JsonItemWriter<File> {
-        let file = File::create(path).expect("Unable to open file");
+    /// Sets the layout of the output. Defaults to `JsonOutputFormat::Array`.
+    ///
+    /// In `JsonOutputFormat::Lines` mode, items are always written compact, one per line.
+    pub fn format(mut self, format: JsonOutputFormat) -> Self {
+        self.format = format;
+        self
+    }
 
-        let buf_writer = BufWriter::new(file);
+    /// Treats a broken pipe as the end of the step instead of a write error,
+    /// as Unix tools do when piped into a consumer that stops reading (`| head`).
+    pub fn stop_on_broken_pipe(mut self, yes: bool) -> Self {
+        self.stop_on_broken_pipe = yes;
+        self
+    }
 
-        JsonItemWriter {
-            stream: RefCell::new(buf_writer),
-            use_pretty_formatter: self.pretty_formatter,
-            is_first_element: Cell::new(true),
-        }
+    /// Compresses the file created by `from_path` with gzip, whatever its extension.
+    #[cfg(feature = "gzip")]
+    pub fn gzip(mut self, yes: bool) -> Self {
+        self.gzip = yes;
+        self
+    }
+
+    /// Creates a `JsonItemWriter` writing to a file.
+    ///
+    /// With the `gzip` feature, files with a `.gz` extension are compressed while written.
+    pub fn from_path<R: AsRef<Path>>(self, path: R) -> JsonItemWriter<FileWriter> {
+        let gzip = self.gzip || is_gzip_path(&path);
+        let file = FileWriter::create(path, gzip).expect("Unable to open file");
+
+        let mut writer = self.from_writer(file);
+        writer.finish = Some(FileWriter::finish);
+        writer
     }
 
     pub fn from_writer<W: Write>(self, wtr: W) -> JsonItemWriter<W> {
@@ -131,7 +233,11 @@ impl JsonItemWriterBuilder {
         JsonItemWriter {
             stream: RefCell::new(buf_writer),
             use_pretty_formatter: self.pretty_formatter,
+            format: self.format,
             is_first_element: Cell::new(true),
+            stop_on_broken_pipe: self.stop_on_broken_pipe,
+            closed: Cell::new(false),
+            finish: None,
         }
     }
 }
@@ -1,3 +1,7 @@
+/// The `json_line_reader` module contains the `JsonLineItemReader` struct, which reads newline-delimited JSON (JSONL),
+/// deserializing each non-blank line into a Rust struct.
+///
+pub mod json_line_reader;
 /// The `json_reader` module contains the `JsonItemReader` struct, which is the main entry point for reading items from a JSON data source.
 /// It implements the `ItemReader` trait and provides methods for reading items from a JSON data source and deserializing them into Rust structs.
 ///
@@ -5,4 +9,7 @@ pub mod json_reader;
 /// The `json_writer` module contains the `JsonItemWriter` struct, which is the main entry point for writing items to a JSON data source.
 /// It implements the `ItemWriter` trait and provides methods for serializing Rust structs into JSON and writing them to a data source.
 ///
+/// `rust_decimal::Decimal` fields annotated with `#[serde(with = "rust_decimal::serde::str")]` are written
+/// as JSON strings (for example `"1299.99"`), which keeps their exact scale and avoids scientific notation.
+///
 pub mod json_writer;
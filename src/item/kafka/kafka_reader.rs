@@ -0,0 +1,294 @@
+use std::{cell::Cell, marker::PhantomData, time::Duration};
+
+use rdkafka::{
+    consumer::{BaseConsumer, Consumer},
+    ClientConfig, Message, Offset, TopicPartitionList,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    core::item::{ItemReader, ItemReaderResult},
+    BatchError,
+};
+
+/// Deserializes the JSON payload of the message at `offset` into an item.
+fn from_payload<R: DeserializeOwned>(payload: Option<&[u8]>, offset: i64) -> Result<R, BatchError> {
+    let record = u64::try_from(offset).ok();
+
+    let payload =
+        payload.ok_or_else(|| BatchError::deserialization(record, "message has no payload"))?;
+
+    serde_json::from_slice(payload).map_err(|error| BatchError::deserialization(record, error))
+}
+
+/// A reader consuming a bounded range of offsets from a single Kafka topic partition.
+///
+/// Each message payload is expected to be a JSON document, as written by the
+/// `KafkaItemWriter`. The reader returns `Ok(None)` once the end offset is reached,
+/// which makes it suitable for jobs turning a stream into a batch.
+pub struct KafkaItemReader<R> {
+    consumer: BaseConsumer,
+    topic: String,
+    partition: i32,
+    end_offset: i64,
+    total: Option<usize>,
+    next_offset: Cell<i64>,
+    poll_timeout: Duration,
+    _pd: PhantomData<R>,
+}
+
+impl<R> KafkaItemReader<R> {
+    /// Returns the offset of the next message to be fetched from the partition, if known.
+    fn position(&self) -> Option<i64> {
+        let positions = self.consumer.position().ok()?;
+
+        match positions
+            .find_partition(&self.topic, self.partition)?
+            .offset()
+        {
+            Offset::Offset(offset) => Some(offset),
+            _ => None,
+        }
+    }
+}
+
+impl<R: DeserializeOwned> ItemReader<R> for KafkaItemReader<R> {
+    /// Reads the next item from the Kafka topic partition.
+    ///
+    /// Returns `Ok(Some(item))` if an item is read successfully,
+    /// `Ok(None)` if the end offset is reached, even if the last offsets hold no message,
+    /// or an error if reading the item fails.
+    fn read(&self) -> ItemReaderResult<R> {
+        if self.next_offset.get() >= self.end_offset {
+            return Ok(None);
+        }
+
+        let message = match self.consumer.poll(self.poll_timeout) {
+            Some(message) => message.map_err(|error| BatchError::ItemReader(error.to_string()))?,
+            None => match self.position() {
+                // The last offsets may hold no message, such as transaction markers
+                Some(position) if position >= self.end_offset => {
+                    self.next_offset.set(position);
+                    return Ok(None);
+                }
+                _ => {
+                    return Err(BatchError::ItemReader(format!(
+                        "no message received within {:?} while waiting for offset {}",
+                        self.poll_timeout,
+                        self.next_offset.get()
+                    )))
+                }
+            },
+        };
+
+        let offset = message.offset();
+        self.next_offset.set(offset + 1);
+
+        if offset >= self.end_offset {
+            return Ok(None);
+        }
+
+        from_payload(message.payload(), offset).map(Some)
+    }
+
+    /// Returns the number of offsets between the start and the end offsets.
+    fn total_hint(&self) -> Option<usize> {
+        self.total
+    }
+}
+
+/// Builder for `KafkaItemReader`.
+#[derive(Default)]
+pub struct KafkaItemReaderBuilder<R> {
+    brokers: Option<String>,
+    topic: Option<String>,
+    partition: i32,
+    start_offset: Option<i64>,
+    end_offset: Option<i64>,
+    poll_timeout: Option<Duration>,
+    options: Vec<(String, String)>,
+    _pd: PhantomData<R>,
+}
+
+impl<R> KafkaItemReaderBuilder<R> {
+    /// Creates a new `KafkaItemReaderBuilder` instance.
+    pub fn new() -> Self {
+        Self {
+            brokers: None,
+            topic: None,
+            partition: 0,
+            start_offset: None,
+            end_offset: None,
+            poll_timeout: None,
+            options: Vec::new(),
+            _pd: PhantomData,
+        }
+    }
+
+    /// Sets the list of bootstrap brokers (for example `localhost:9092`).
+    pub fn brokers(mut self, brokers: &str) -> Self {
+        self.brokers = Some(brokers.to_string());
+        self
+    }
+
+    /// Sets the name of the topic to read from.
+    pub fn topic(mut self, topic: &str) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+
+    /// Sets the partition to read from. Defaults to partition `0`.
+    pub fn partition(mut self, partition: i32) -> Self {
+        self.partition = partition;
+        self
+    }
+
+    /// Sets the first offset to read. Defaults to the first offset available in the partition.
+    pub fn start_offset(mut self, offset: i64) -> Self {
+        self.start_offset = Some(offset);
+        self
+    }
+
+    /// Sets the offset at which reading stops, exclusive.
+    /// Defaults to the end of the partition when the reader is built.
+    pub fn end_offset(mut self, offset: i64) -> Self {
+        self.end_offset = Some(offset);
+        self
+    }
+
+    /// Sets how long to wait for a message before failing. Defaults to 5 seconds.
+    pub fn poll_timeout(mut self, timeout: Duration) -> Self {
+        self.poll_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets a librdkafka configuration property of the consumer.
+    pub fn option(mut self, key: &str, value: &str) -> Self {
+        self.options.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Builds a `KafkaItemReader` instance.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the brokers or the topic is not set,
+    /// or if the consumer cannot be created or assigned to the partition.
+    pub fn build(self) -> KafkaItemReader<R> {
+        let brokers = self.brokers.expect("Brokers are mandatory");
+        let topic = self.topic.expect("Topic is mandatory");
+        let poll_timeout = self.poll_timeout.unwrap_or(Duration::from_secs(5));
+
+        let mut config = ClientConfig::new();
+        config
+            .set("bootstrap.servers", &brokers)
+            .set("group.id", "spring-batch")
+            .set("enable.auto.commit", "false")
+            .set("enable.partition.eof", "false");
+        for (key, value) in &self.options {
+            config.set(key, value);
+        }
+
+        let consumer: BaseConsumer = config.create().expect("Unable to create Kafka consumer");
+
+        let (low, high) = consumer
+            .fetch_watermarks(&topic, self.partition, poll_timeout)
+            .expect("Unable to fetch Kafka watermarks");
+
+        let start_offset = self.start_offset.unwrap_or(low);
+        let end_offset = self.end_offset.unwrap_or(high);
+
+        let mut assignment = TopicPartitionList::new();
+        assignment
+            .add_partition_offset(&topic, self.partition, Offset::Offset(start_offset))
+            .expect("Unable to assign Kafka partition");
+        consumer
+            .assign(&assignment)
+            .expect("Unable to assign Kafka partition");
+
+        KafkaItemReader {
+            consumer,
+            topic,
+            partition: self.partition,
+            end_offset,
+            total: usize::try_from(end_offset - start_offset).ok(),
+            next_offset: Cell::new(start_offset),
+            poll_timeout,
+            _pd: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, marker::PhantomData, time::Duration};
+
+    use rdkafka::ClientConfig;
+    use serde::Deserialize;
+
+    use crate::{core::item::ItemReader, BatchError};
+
+    use super::{from_payload, KafkaItemReader};
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Event {
+        name: String,
+        count: u32,
+    }
+
+    #[test]
+    fn payload_should_be_deserialized_from_json() {
+        let event: Event = from_payload(Some(br#"{"name":"created","count":3}"#), 7).unwrap();
+
+        assert_eq!(
+            event,
+            Event {
+                name: "created".to_string(),
+                count: 3
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_or_missing_payload_should_report_the_offset() {
+        let invalid = from_payload::<Event>(Some(b"not json"), 7).unwrap_err();
+        let missing = from_payload::<Event>(None, 8).unwrap_err();
+
+        assert!(matches!(
+            invalid,
+            BatchError::Deserialization {
+                record: Some(7),
+                ..
+            }
+        ));
+        assert!(matches!(
+            missing,
+            BatchError::Deserialization {
+                record: Some(8),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn total_hint_should_not_change_while_reading() {
+        let consumer = ClientConfig::new()
+            .set("bootstrap.servers", "localhost:9092")
+            .set("group.id", "spring-batch")
+            .create()
+            .unwrap();
+        let reader: KafkaItemReader<Event> = KafkaItemReader {
+            consumer,
+            topic: "events".to_string(),
+            partition: 0,
+            end_offset: 15,
+            total: Some(5),
+            next_offset: Cell::new(15),
+            poll_timeout: Duration::from_millis(10),
+            _pd: PhantomData,
+        };
+
+        assert_eq!(reader.read(), Ok(None));
+        assert_eq!(reader.total_hint(), Some(5));
+    }
+}
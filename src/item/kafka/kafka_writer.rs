@@ -0,0 +1,243 @@
+use std::{marker::PhantomData, sync::Mutex, time::Duration};
+
+use rdkafka::{
+    error::{KafkaError, RDKafkaErrorCode},
+    message::DeliveryResult,
+    producer::{BaseProducer, BaseRecord, Producer, ProducerContext},
+    ClientConfig, ClientContext,
+};
+use serde::Serialize;
+
+use crate::{
+    core::item::{ItemWriter, ItemWriterResult},
+    BatchError,
+};
+
+/// Extracts the key of the message produced for an item.
+type KeyExtractor<W> = dyn Fn(&W) -> String;
+
+/// Producer context collecting the errors reported by delivery callbacks.
+#[derive(Default)]
+struct DeliveryContext {
+    errors: Mutex<Vec<String>>,
+}
+
+impl ClientContext for DeliveryContext {}
+
+impl ProducerContext for DeliveryContext {
+    type DeliveryOpaque = ();
+
+    fn delivery(&self, result: &DeliveryResult<'_>, _: Self::DeliveryOpaque) {
+        if let Err((error, _)) = result {
+            self.errors.lock().unwrap().push(error.to_string());
+        }
+    }
+}
+
+impl DeliveryContext {
+    /// Returns an error listing the failed deliveries since the last call, if any.
+    fn take_errors(&self) -> ItemWriterResult {
+        let errors = std::mem::take(&mut *self.errors.lock().unwrap());
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(BatchError::ItemWriter(format!(
+                "{} message(s) could not be delivered: {}",
+                errors.len(),
+                errors.join(", ")
+            )))
+        }
+    }
+}
+
+/// A writer producing items as JSON messages to a Kafka topic.
+///
+/// Messages are sent asynchronously by the producer; `flush` and `close` wait for
+/// every pending message to be delivered and report the failed deliveries.
+pub struct KafkaItemWriter<W> {
+    producer: BaseProducer<DeliveryContext>,
+    topic: String,
+    partition: Option<i32>,
+    key: Option<Box<KeyExtractor<W>>>,
+    flush_timeout: Duration,
+}
+
+impl<W> KafkaItemWriter<W> {
+    /// Sends a single message, waiting for room in the producer queue if it is full.
+    fn send(&self, key: Option<&str>, payload: &[u8]) -> ItemWriterResult {
+        let mut record = BaseRecord::<str, [u8]>::to(&self.topic).payload(payload);
+        record.key = key;
+        record.partition = self.partition;
+
+        loop {
+            match self.producer.send(record) {
+                Ok(()) => return Ok(()),
+                Err((KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull), rejected)) => {
+                    self.producer.poll(Duration::from_millis(100));
+                    record = rejected;
+                }
+                Err((error, _)) => return Err(BatchError::ItemWriter(error.to_string())),
+            }
+        }
+    }
+}
+
+impl<W: Serialize> ItemWriter<W> for KafkaItemWriter<W> {
+    /// Writes the items to the Kafka topic.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The items to be written.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `ItemWriterResult` indicating the result of the write operation.
+    fn write(&self, items: &[W]) -> ItemWriterResult {
+        for item in items {
+            let payload =
+                serde_json::to_vec(item).map_err(|error| BatchError::serialization(None, error))?;
+            let key = self.key.as_ref().map(|key| key(item));
+
+            self.send(key.as_deref(), &payload)?;
+        }
+
+        self.producer.poll(Duration::ZERO);
+        self.producer.context().take_errors()
+    }
+
+    /// Waits for the pending messages to be delivered.
+    fn flush(&self) -> ItemWriterResult {
+        self.producer
+            .flush(self.flush_timeout)
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+
+        self.producer.context().take_errors()
+    }
+
+    /// Flushes the producer before the writer is dropped.
+    fn close(&self) -> ItemWriterResult {
+        ItemWriter::<W>::flush(self)
+    }
+}
+
+/// Builder for `KafkaItemWriter`.
+pub struct KafkaItemWriterBuilder<W> {
+    brokers: Option<String>,
+    topic: Option<String>,
+    partition: Option<i32>,
+    key: Option<Box<KeyExtractor<W>>>,
+    flush_timeout: Option<Duration>,
+    options: Vec<(String, String)>,
+    _pd: PhantomData<W>,
+}
+
+impl<W> Default for KafkaItemWriterBuilder<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W> KafkaItemWriterBuilder<W> {
+    /// Creates a new `KafkaItemWriterBuilder` instance.
+    pub fn new() -> Self {
+        Self {
+            brokers: None,
+            topic: None,
+            partition: None,
+            key: None,
+            flush_timeout: None,
+            options: Vec::new(),
+            _pd: PhantomData,
+        }
+    }
+
+    /// Sets the list of bootstrap brokers (for example `localhost:9092`).
+    pub fn brokers(mut self, brokers: &str) -> Self {
+        self.brokers = Some(brokers.to_string());
+        self
+    }
+
+    /// Sets the name of the topic to write to.
+    pub fn topic(mut self, topic: &str) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+
+    /// Writes every message to the given partition, instead of letting the producer choose it.
+    pub fn partition(mut self, partition: i32) -> Self {
+        self.partition = Some(partition);
+        self
+    }
+
+    /// Sets the function computing the key of the message produced for each item.
+    /// Messages have no key by default.
+    pub fn key(mut self, key: impl Fn(&W) -> String + 'static) -> Self {
+        self.key = Some(Box::new(key));
+        self
+    }
+
+    /// Sets how long to wait for pending messages when flushing. Defaults to 30 seconds.
+    pub fn flush_timeout(mut self, timeout: Duration) -> Self {
+        self.flush_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets a librdkafka configuration property of the producer.
+    pub fn option(mut self, key: &str, value: &str) -> Self {
+        self.options.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Builds a `KafkaItemWriter` instance.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the brokers or the topic is not set,
+    /// or if the producer cannot be created.
+    pub fn build(self) -> KafkaItemWriter<W> {
+        let brokers = self.brokers.expect("Brokers are mandatory");
+        let topic = self.topic.expect("Topic is mandatory");
+
+        let mut config = ClientConfig::new();
+        config.set("bootstrap.servers", &brokers);
+        for (key, value) in &self.options {
+            config.set(key, value);
+        }
+
+        let producer = config
+            .create_with_context(DeliveryContext::default())
+            .expect("Unable to create Kafka producer");
+
+        KafkaItemWriter {
+            producer,
+            topic,
+            partition: self.partition,
+            key: self.key,
+            flush_timeout: self.flush_timeout.unwrap_or(Duration::from_secs(30)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeliveryContext;
+
+    #[test]
+    fn delivery_errors_should_be_reported_once() {
+        let context = DeliveryContext::default();
+        context
+            .errors
+            .lock()
+            .unwrap()
+            .extend(["Broker: Unknown topic".to_string(), "timed out".to_string()]);
+
+        let error = context.take_errors().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Error occurred in the ItemWriter: 2 message(s) could not be delivered: Broker: Unknown topic, timed out"
+        );
+        assert!(context.take_errors().is_ok());
+    }
+}
@@ -0,0 +1,5 @@
+/// This module contains the Kafka topic reader implementation.
+pub mod kafka_reader;
+
+/// This module contains the Kafka topic writer implementation.
+pub mod kafka_writer;
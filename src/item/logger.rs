@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use log::info;
+use log::{info, log, Level};
 
 use crate::core::item::{ItemWriter, ItemWriterResult};
 
@@ -36,15 +36,129 @@ impl<T: Debug> ItemWriter<T> for LoggerWriter {
     }
 }
 
+/// Function formatting an item into a log line.
+type FormatFn<'a, T> = dyn Fn(&T) -> String + 'a;
+
+/// An item writer logging the items with a configurable level and format.
+///
+/// It can log a summary line per chunk instead of each item, to keep the
+/// log volume under control in production.
+pub struct FormattedLoggerWriter<'a, T> {
+    level: Level,
+    format: Box<FormatFn<'a, T>>,
+    summary: bool,
+}
+
+impl<'a, T> ItemWriter<T> for FormattedLoggerWriter<'a, T> {
+    fn write(&self, items: &[T]) -> ItemWriterResult {
+        if self.summary {
+            log!(self.level, "wrote {} items", items.len());
+        } else {
+            items
+                .iter()
+                .for_each(|item| log!(self.level, "{}", (self.format)(item)));
+        }
+        Ok(())
+    }
+}
+
+/// Builder for creating a `FormattedLoggerWriter`.
+///
+/// By default, items are logged one by one at the `Info` level, formatted as
+/// `LoggerWriter` does.
+///
+/// # Examples
+///
+/// ```
+/// use log::Level;
+/// use spring_batch_rs::core::item::ItemWriter;
+/// use spring_batch_rs::item::logger::FormattedLoggerWriterBuilder;
+///
+/// let writer = FormattedLoggerWriterBuilder::new()
+///     .level(Level::Debug)
+///     .format(|item: &u32| format!("item #{}", item))
+///     .build();
+///
+/// assert!(writer.write(&[1, 2, 3]).is_ok());
+/// ```
+pub struct FormattedLoggerWriterBuilder<'a, T> {
+    level: Level,
+    format: Box<FormatFn<'a, T>>,
+    summary: bool,
+}
+
+impl<'a, T: Debug> FormattedLoggerWriterBuilder<'a, T> {
+    /// Creates a new `FormattedLoggerWriterBuilder` instance.
+    pub fn new() -> Self {
+        Self {
+            level: Level::Info,
+            format: Box::new(|item| format!("Record:{:?}", item)),
+            summary: false,
+        }
+    }
+}
+
+impl<'a, T: Debug> Default for FormattedLoggerWriterBuilder<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> FormattedLoggerWriterBuilder<'a, T> {
+    /// Sets the level of the log lines. Defaults to `Level::Info`.
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets the function formatting each item.
+    pub fn format(mut self, format: impl Fn(&T) -> String + 'a) -> Self {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Logs one `wrote N items` line per chunk instead of each item.
+    pub fn summary(mut self, yes: bool) -> Self {
+        self.summary = yes;
+        self
+    }
+
+    /// Builds a `FormattedLoggerWriter` instance.
+    pub fn build(self) -> FormattedLoggerWriter<'a, T> {
+        FormattedLoggerWriter {
+            level: self.level,
+            format: self.format,
+            summary: self.summary,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_write() {
-        let writer = LoggerWriter::default();
+        let writer = LoggerWriter;
         let items = vec![1, 2, 3];
         let result = writer.write(&items);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn summary_should_not_format_items() {
+        let calls = std::cell::Cell::new(0);
+
+        let writer = FormattedLoggerWriterBuilder::new()
+            .level(Level::Error)
+            .format(|item: &u32| {
+                calls.set(calls.get() + 1);
+                item.to_string()
+            })
+            .summary(true)
+            .build();
+
+        assert!(writer.write(&[1, 2, 3]).is_ok());
+        assert_eq!(calls.get(), 0);
+    }
 }
@@ -0,0 +1,197 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::BatchError;
+
+/// Describes a file produced by a step.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ManifestEntry {
+    /// The path of the file.
+    pub path: String,
+    /// The size of the file in bytes.
+    pub size: u64,
+    /// The number of rows written to the file.
+    pub row_count: usize,
+    /// The hexadecimal SHA-256 checksum of the file.
+    pub sha256: String,
+}
+
+/// A manifest listing the files produced by a job.
+///
+/// Row counts are usually taken from the `write_count` of the `StepExecution`
+/// returned by the step which produced the file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use spring_batch_rs::item::manifest::ManifestBuilder;
+///
+/// let manifest = ManifestBuilder::new()
+///     .file("target/cars.csv", 7)
+///     .build()
+///     .unwrap();
+///
+/// manifest.write_json("target/manifest.json").unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Manifest {
+    /// The files listed in the manifest.
+    pub files: Vec<ManifestEntry>,
+}
+
+impl Manifest {
+    /// Writes the manifest as a JSON document.
+    pub fn write_json<P: AsRef<Path>>(&self, path: P) -> Result<(), BatchError> {
+        let file = File::create(path).map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+        let mut writer = BufWriter::new(file);
+
+        serde_json::to_writer_pretty(&mut writer, self)
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+
+        writer
+            .flush()
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))
+    }
+
+    /// Writes the manifest as a CSV document with a header row.
+    pub fn write_csv<P: AsRef<Path>>(&self, path: P) -> Result<(), BatchError> {
+        let file = File::create(path).map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+        let mut writer = BufWriter::new(file);
+
+        let write = |writer: &mut BufWriter<File>| -> io::Result<()> {
+            writeln!(writer, "path,size,row_count,sha256")?;
+            for entry in &self.files {
+                writeln!(
+                    writer,
+                    "{},{},{},{}",
+                    escape(&entry.path),
+                    entry.size,
+                    entry.row_count,
+                    entry.sha256
+                )?;
+            }
+            writer.flush()
+        };
+
+        write(&mut writer).map_err(|error| BatchError::ItemWriter(error.to_string()))
+    }
+}
+
+/// Quotes a CSV field when needed.
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Computes the size and the hexadecimal SHA-256 checksum of a file.
+fn checksum(path: &Path) -> io::Result<(u64, String)> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let size = io::copy(&mut file, &mut hasher)?;
+
+    let sha256 = hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+
+    Ok((size, sha256))
+}
+
+/// Builder for creating a `Manifest`.
+#[derive(Default)]
+pub struct ManifestBuilder {
+    files: Vec<(PathBuf, usize)>,
+}
+
+impl ManifestBuilder {
+    /// Creates a new `ManifestBuilder` instance.
+    pub fn new() -> Self {
+        Self { files: Vec::new() }
+    }
+
+    /// Adds a produced file with the number of rows written to it.
+    pub fn file<P: AsRef<Path>>(mut self, path: P, row_count: usize) -> Self {
+        self.files.push((path.as_ref().to_path_buf(), row_count));
+        self
+    }
+
+    /// Builds the manifest by reading the size and the checksum of each file.
+    ///
+    /// Returns an error if one of the files cannot be read.
+    pub fn build(self) -> Result<Manifest, BatchError> {
+        let files = self
+            .files
+            .into_iter()
+            .map(|(path, row_count)| {
+                let (size, sha256) = checksum(&path).map_err(|error| {
+                    BatchError::ItemWriter(format!("{}: {}", path.display(), error))
+                })?;
+
+                Ok(ManifestEntry {
+                    path: path.display().to_string(),
+                    size,
+                    row_count,
+                    sha256,
+                })
+            })
+            .collect::<Result<Vec<_>, BatchError>>()?;
+
+        Ok(Manifest { files })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::tempdir;
+
+    use super::ManifestBuilder;
+
+    #[test]
+    fn manifest_should_list_files_with_checksum() {
+        let dir = tempdir().unwrap();
+        let data = dir.path().join("data.csv");
+        fs::write(&data, "abc").unwrap();
+
+        let manifest = ManifestBuilder::new().file(&data, 1).build().unwrap();
+
+        assert_eq!(manifest.files.len(), 1);
+        assert_eq!(manifest.files[0].size, 3);
+        assert_eq!(manifest.files[0].row_count, 1);
+        assert_eq!(
+            manifest.files[0].sha256,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        let json = dir.path().join("manifest.json");
+        manifest.write_json(&json).unwrap();
+        assert!(fs::read_to_string(json)
+            .unwrap()
+            .contains("\"row_count\": 1"));
+
+        let csv = dir.path().join("manifest.csv");
+        manifest.write_csv(&csv).unwrap();
+        let content = fs::read_to_string(csv).unwrap();
+        assert!(content.starts_with("path,size,row_count,sha256\n"));
+        assert!(content
+            .ends_with(",3,1,ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad\n"));
+    }
+
+    #[test]
+    fn missing_file_should_fail() {
+        let result = ManifestBuilder::new().file("does/not/exist.csv", 0).build();
+
+        assert!(result.is_err());
+    }
+}
@@ -14,6 +14,10 @@ pub mod fake;
 /// This module provides a JSON item reader and writer implementation for Spring Batch.
 pub mod json;
 
+#[cfg(feature = "fixed-width")]
+/// This module provides a reader and writer for fixed-width (positional) records.
+pub mod fixed_width;
+
 #[cfg(feature = "rdbc-postgres")]
 /// This module provides an RDBC (PostgreSQL) item reader and writer implementation for Spring Batch.
 pub mod rdbc;
@@ -21,3 +25,59 @@ pub mod rdbc;
 #[cfg(feature = "mongodb")]
 /// This module provides a MongoDB item reader and writer implementation for Spring Batch.
 pub mod mongodb;
+
+#[cfg(feature = "http")]
+/// This module provides an HTTP (webhook) item writer and the download of URLs read by readers.
+pub mod http;
+
+#[cfg(feature = "redis")]
+/// This module provides a Redis stream item reader and writer implementation for Spring Batch.
+pub mod redis;
+
+#[cfg(feature = "kafka")]
+/// This module provides a Kafka item reader and writer implementation for Spring Batch.
+pub mod kafka;
+
+#[cfg(feature = "object-store")]
+/// This module provides the download of objects from S3 and other object stores read by readers.
+pub mod object_store;
+
+#[cfg(feature = "framed")]
+/// This module provides a reader of binary records framed by a length prefix or a delimiter.
+pub mod framed;
+
+#[cfg(feature = "manifest")]
+/// This module provides a manifest listing the files produced by a job.
+pub mod manifest;
+
+#[cfg(feature = "queue")]
+/// This module provides a bounded in-memory queue reader and writer to pipeline steps.
+pub mod queue;
+
+#[cfg(feature = "datetime")]
+/// This module provides timezone-aware datetime parsing options for readers.
+pub mod datetime;
+
+#[cfg(feature = "pgp")]
+/// This module provides a reader decrypting OpenPGP encrypted input streams.
+pub mod pgp;
+
+#[cfg(feature = "avro")]
+/// This module provides an Avro item reader and writer implementation for Spring Batch.
+pub mod avro;
+
+#[cfg(feature = "parquet")]
+/// This module provides an Apache Parquet item reader and writer implementation for Spring Batch.
+pub mod parquet;
+
+#[cfg(feature = "xlsx")]
+/// This module provides a reader and writer for Excel workbooks.
+pub mod excel;
+
+#[cfg(feature = "validation")]
+/// This module provides a processor validating items with the `validator` crate.
+pub mod validation;
+
+#[cfg(any(feature = "csv", feature = "json", feature = "fixed-width"))]
+/// This module provides the files opened by file readers and writers, with optional gzip compression.
+pub mod file;
@@ -3,88 +3,111 @@ use std::cell::{Cell, RefCell};
 use mongodb::{
     bson::{doc, oid::ObjectId, Document},
     options::FindOptions,
-    sync::Collection,
+    sync::{Collection, Cursor},
 };
 use serde::de::DeserializeOwned;
 
-use crate::core::item::{ItemReader, ItemReaderResult};
+use crate::{
+    core::item::{ItemReader, ItemReaderResult},
+    BatchError,
+};
 
 pub trait WithObjectId {
     fn get_id(&self) -> ObjectId;
 }
 
 /// A MongoDB item reader that reads items from a MongoDB collection.
+///
+/// Items are deserialized one at a time from the cursor of the current page as they
+/// are read, the driver fetching documents from the server in batches.
+///
+/// Without a sort, items are read by pages in the order of their `oid` field: each
+/// page reads the items with an `oid` greater than the last one read, which stays
+/// fast on large collections unlike skipping documents. With a sort, the whole result
+/// is read from a single cursor in that order, the page size setting its batch size.
 pub struct MongodbItemReader<'a, R: Send + Sync> {
     collection: &'a Collection<R>,
     filter: Document,
-    options: Option<FindOptions>,
+    options: FindOptions,
     page_size: Option<i64>,
-    buffer: RefCell<Vec<R>>,
+    paginated: bool,
+    cursor: RefCell<Option<Cursor<R>>>,
     last_id: Cell<Option<ObjectId>>,
-    offset: Cell<usize>,
+    read_in_page: Cell<i64>,
+    finished: Cell<bool>,
 }
 
 impl<'a, R: DeserializeOwned + WithObjectId + Send + Sync> MongodbItemReader<'a, R> {
-    /// Reads a page of items from the MongoDB collection and stores them in the buffer.
-    fn read_page(&self) {
-        self.buffer.borrow_mut().clear();
-
-        let last_id = self.last_id.get();
-
+    /// Opens the cursor of the next page of items.
+    fn open_page(&self) -> Result<Cursor<R>, BatchError> {
         let mut filter = self.filter.clone();
 
-        if last_id.is_some() {
+        if let Some(last_id) = self.last_id.get() {
             filter.extend(doc! {"oid": { "$gt": last_id }});
         };
 
-        let options = &self.options;
+        self.read_in_page.set(0);
 
-        let mut cursor = self
-            .collection
+        self.collection
             .find(filter)
-            .with_options(options.clone())
+            .with_options(self.options.clone())
             .run()
-            .unwrap();
+            .map_err(|error| BatchError::ItemReader(error.to_string()))
+    }
 
-        while cursor.advance().unwrap() {
-            let result = cursor.deserialize_current();
-            if let Ok(item) = result {
-                self.last_id.set(Some(item.get_id()));
-                self.buffer.borrow_mut().push(item);
-            }
-        }
+    /// Returns `true` if the current page was full, so that a next page may follow.
+    fn has_next_page(&self) -> bool {
+        self.paginated && Some(self.read_in_page.get()) == self.page_size
     }
 }
 
-impl<'a, R: DeserializeOwned + Clone + WithObjectId + Send + Sync> ItemReader<R>
+impl<'a, R: DeserializeOwned + WithObjectId + Send + Sync> ItemReader<R>
     for MongodbItemReader<'a, R>
 {
     /// Reads the next item from the MongoDB collection.
     ///
     /// Returns `Ok(Some(item))` if an item is read successfully,
     /// `Ok(None)` if there are no more items to read,
+    /// `Err(BatchError::Deserialization { .. })` if the document cannot be deserialized,
     /// or an error if reading the item fails.
     fn read(&self) -> ItemReaderResult<R> {
-        let index = if let Some(page_size) = self.page_size {
-            self.offset.get() % (page_size as usize)
-        } else {
-            self.offset.get()
-        };
+        let mut cursor = self.cursor.borrow_mut();
 
-        if index == 0 {
-            self.read_page();
-        }
+        loop {
+            if self.finished.get() {
+                return Ok(None);
+            }
+
+            if cursor.is_none() {
+                *cursor = Some(self.open_page()?);
+            }
+
+            let current = cursor.as_mut().unwrap();
+
+            let has_item = current
+                .advance()
+                .map_err(|error| BatchError::ItemReader(error.to_string()))?;
 
-        let buffer = self.buffer.borrow();
+            if has_item {
+                self.read_in_page.set(self.read_in_page.get() + 1);
 
-        let result = buffer.get(index);
+                let item = current.deserialize_current().map_err(|error| {
+                    // The next page starts after the malformed document
+                    if let Ok(id) = current.current().get_object_id("oid") {
+                        self.last_id.set(Some(id));
+                    }
+                    BatchError::deserialization(None, error)
+                })?;
+                self.last_id.set(Some(item.get_id()));
+
+                return Ok(Some(item));
+            }
 
-        match result {
-            Some(item) => {
-                self.offset.set(self.offset.get() + 1);
-                Ok(Some(item.clone()))
+            if self.has_next_page() {
+                *cursor = None;
+            } else {
+                self.finished.set(true);
             }
-            None => Ok(None),
         }
     }
 }
@@ -93,6 +116,8 @@ impl<'a, R: DeserializeOwned + Clone + WithObjectId + Send + Sync> ItemReader<R>
 pub struct MongodbItemReaderBuilder<'a, R: Send + Sync> {
     collection: Option<&'a Collection<R>>,
     filter: Option<Document>,
+    projection: Option<Document>,
+    sort: Option<Document>,
     page_size: Option<i64>,
 }
 
@@ -102,6 +127,8 @@ impl<'a, R: Send + Sync> MongodbItemReaderBuilder<'a, R> {
         Self {
             collection: None,
             filter: None,
+            projection: None,
+            sort: None,
             page_size: None,
         }
     }
@@ -118,6 +145,24 @@ impl<'a, R: Send + Sync> MongodbItemReaderBuilder<'a, R> {
         self
     }
 
+    /// Sets the fields of the documents to return, such as `doc! {"oid": 1, "title": 1}`.
+    ///
+    /// Fields missing from the projection must be optional or defaulted in the items.
+    /// Without a sort, the projection must keep the `oid` field used to read by pages.
+    pub fn projection(mut self, projection: Document) -> MongodbItemReaderBuilder<'a, R> {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Sets the order in which items are read, such as `doc! {"author": 1, "oid": 1}`.
+    ///
+    /// Ending the sort with a unique field makes the order deterministic.
+    /// Items are then read from a single cursor rather than by pages of `oid`.
+    pub fn sort(mut self, sort: Document) -> MongodbItemReaderBuilder<'a, R> {
+        self.sort = Some(sort);
+        self
+    }
+
     /// Sets the page size for reading items.
     pub fn page_size(mut self, page_size: i64) -> MongodbItemReaderBuilder<'a, R> {
         self.page_size = Some(page_size);
@@ -126,13 +171,6 @@ impl<'a, R: Send + Sync> MongodbItemReaderBuilder<'a, R> {
 
     /// Builds the `MongodbItemReader` with the configured options.
     pub fn build(&self) -> MongodbItemReader<'a, R> {
-        let buffer: Vec<R> = if let Some(page_size) = self.page_size {
-            let buffer_size = page_size.try_into().unwrap_or(1);
-            Vec::with_capacity(buffer_size)
-        } else {
-            Vec::new()
-        };
-
         let filter = if let Some(filter) = self.filter.to_owned() {
             filter
         } else {
@@ -141,19 +179,28 @@ impl<'a, R: Send + Sync> MongodbItemReaderBuilder<'a, R> {
 
         // We do not use skip because of performance issue for large dataset.
         // It is better to sort and filter with an indexed field (_id)
+        let paginated = self.sort.is_none();
+
         let find_options = FindOptions::builder()
-            .sort(doc! { "oid": 1 })
-            .limit(Some(self.page_size.unwrap()))
+            .sort(self.sort.clone().unwrap_or(doc! { "oid": 1 }))
+            .projection(self.projection.clone())
+            .limit(self.page_size.filter(|_| paginated))
+            .batch_size(
+                self.page_size
+                    .and_then(|page_size| page_size.try_into().ok()),
+            )
             .build();
 
         MongodbItemReader {
             collection: self.collection.unwrap(),
             filter,
-            options: Some(find_options),
+            options: find_options,
             page_size: self.page_size,
-            buffer: RefCell::new(buffer),
+            paginated,
+            cursor: RefCell::new(None),
             last_id: Cell::new(None),
-            offset: Cell::new(0),
+            read_in_page: Cell::new(0),
+            finished: Cell::new(false),
         }
     }
 }
@@ -1,4 +1,9 @@
-use mongodb::{options::InsertManyOptions, sync::Collection};
+use mongodb::{
+    bson::{doc, to_document, Document},
+    error::{Error, ErrorKind, InsertManyError},
+    options::InsertManyOptions,
+    sync::Collection,
+};
 
 use crate::{
     core::item::{ItemWriter, ItemWriterResult},
@@ -6,8 +11,93 @@ use crate::{
 };
 
 /// Represents a MongoDB item writer.
+///
+/// Items are inserted, unless an upsert key has been set with `upsert_on`: items then
+/// replace the document having the same key, or are inserted if there is none.
+///
+/// Inserts are unordered by default: the documents of a chunk which can be inserted are,
+/// whatever the failures of the others. Use `ordered` to stop at the first failure instead.
 pub struct MongodbItemWriter<'a, W: Send + Sync> {
     collection: &'a Collection<W>,
+    upsert_key: Option<String>,
+    ordered: bool,
+}
+
+impl<'a, W: serde::Serialize + Send + Sync> MongodbItemWriter<'a, W> {
+    /// Builds the filter matching the document having the same key as the item.
+    fn key_filter(item: &W, key: &str) -> Result<Document, BatchError> {
+        let document =
+            to_document(item).map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+
+        match document.get(key) {
+            Some(value) => Ok(doc! { key: value.clone() }),
+            None => Err(BatchError::ItemWriter(format!(
+                "missing upsert key `{}` in item: {}",
+                key, document
+            ))),
+        }
+    }
+
+    /// Replaces or inserts each item, matching on the upsert key.
+    ///
+    /// Items are replaced one by one: `bulk_write` is only available on the client,
+    /// and requires MongoDB 8.0 or later.
+    fn upsert(&self, items: &[W], key: &str) -> ItemWriterResult {
+        for item in items {
+            let filter = Self::key_filter(item, key)?;
+
+            self.collection
+                .replace_one(filter, item)
+                .upsert(true)
+                .run()
+                .map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Converts the error of an insert into a `BatchError::PartialWrite`, with the number
+    /// of documents inserted and the index in the chunk of each document which failed to be
+    /// inserted.
+    ///
+    /// The inserted ids of an `InsertManyError` are private to the driver: the documents
+    /// inserted are those before the failure of an ordered insert, and all the documents
+    /// without write error of an unordered one. A write concern error leaves them unknown,
+    /// and is converted into a `BatchError::ItemWriter`.
+    fn insert_error(error: Error, count: usize, ordered: bool) -> BatchError {
+        match error.kind.as_ref() {
+            ErrorKind::InsertMany(InsertManyError {
+                write_errors: Some(write_errors),
+                write_concern_error: None,
+                ..
+            }) => {
+                let failures: Vec<String> = write_errors
+                    .iter()
+                    .map(|write_error| format!("[{}] {}", write_error.index, write_error.message))
+                    .collect();
+                let written = if ordered {
+                    write_errors
+                        .iter()
+                        .map(|write_error| write_error.index)
+                        .min()
+                        .unwrap_or(count)
+                } else {
+                    count.saturating_sub(write_errors.len())
+                };
+
+                BatchError::PartialWrite {
+                    written,
+                    message: format!(
+                        "failed to insert {} of {} documents: {}",
+                        count - written,
+                        count,
+                        failures.join(", ")
+                    ),
+                }
+            }
+            _ => BatchError::ItemWriter(error.to_string()),
+        }
+    }
 }
 
 impl<'a, W: serde::Serialize + Send + Sync> ItemWriter<W> for MongodbItemWriter<'a, W> {
@@ -20,14 +110,21 @@ impl<'a, W: serde::Serialize + Send + Sync> ItemWriter<W> for MongodbItemWriter<
     /// # Returns
     ///
     /// Returns an `ItemWriterResult` indicating the result of the write operation.
+    /// Documents which failed to be inserted are reported in a single
+    /// `BatchError::PartialWrite`, by their index in `items`. The step counts the documents
+    /// inserted as written, and only the others as write errors.
     fn write(&self, items: &[W]) -> ItemWriterResult {
-        let opts = InsertManyOptions::builder().ordered(false).build();
+        if let Some(key) = &self.upsert_key {
+            return self.upsert(items, key);
+        }
+
+        let opts = InsertManyOptions::builder().ordered(self.ordered).build();
 
         let result = self.collection.insert_many(items).with_options(opts).run();
 
         match result {
             Ok(_ser) => Ok(()),
-            Err(error) => Err(BatchError::ItemWriter(error.to_string())),
+            Err(error) => Err(Self::insert_error(error, items.len(), self.ordered)),
         }
     }
 }
@@ -36,12 +133,18 @@ impl<'a, W: serde::Serialize + Send + Sync> ItemWriter<W> for MongodbItemWriter<
 #[derive(Default)]
 pub struct MongodbItemWriterBuilder<'a, W: Send + Sync> {
     collection: Option<&'a Collection<W>>,
+    upsert_key: Option<String>,
+    ordered: bool,
 }
 
 impl<'a, W: Send + Sync> MongodbItemWriterBuilder<'a, W> {
     /// Creates a new `MongodbItemWriterBuilder` instance.
     pub fn new() -> Self {
-        Self { collection: None }
+        Self {
+            collection: None,
+            upsert_key: None,
+            ordered: false,
+        }
     }
 
     /// Sets the MongoDB collection for the writer.
@@ -58,6 +161,24 @@ impl<'a, W: Send + Sync> MongodbItemWriterBuilder<'a, W> {
         self
     }
 
+    /// Replaces the document having the same value of `field` as the item,
+    /// or inserts the item if there is none, instead of always inserting.
+    ///
+    /// Writing an item without this field fails.
+    pub fn upsert_on(mut self, field: &str) -> MongodbItemWriterBuilder<'a, W> {
+        self.upsert_key = Some(field.to_string());
+        self
+    }
+
+    /// Inserts the documents of a chunk in order, stopping at the first failure.
+    ///
+    /// Defaults to `false`: unordered inserts are faster, and go on past the documents
+    /// which cannot be inserted, such as duplicates of a unique key.
+    pub fn ordered(mut self, ordered: bool) -> MongodbItemWriterBuilder<'a, W> {
+        self.ordered = ordered;
+        self
+    }
+
     /// Builds a `MongodbItemWriter` instance.
     ///
     /// # Returns
@@ -66,6 +187,86 @@ impl<'a, W: Send + Sync> MongodbItemWriterBuilder<'a, W> {
     pub fn build(&self) -> MongodbItemWriter<'a, W> {
         MongodbItemWriter {
             collection: self.collection.unwrap(),
+            upsert_key: self.upsert_key.clone(),
+            ordered: self.ordered,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use mongodb::{
+        bson::{doc, from_document},
+        error::{Error, ErrorKind, InsertManyError},
+    };
+    use serde::Serialize;
+
+    use crate::BatchError;
+
+    use super::MongodbItemWriter;
+
+    #[derive(Serialize)]
+    struct Book {
+        isbn: String,
+        title: String,
+    }
+
+    #[test]
+    fn key_filter_should_match_the_key_field() {
+        let book = Book {
+            isbn: "978-0".to_string(),
+            title: "Dune".to_string(),
+        };
+
+        let filter = MongodbItemWriter::key_filter(&book, "isbn").unwrap();
+
+        assert_eq!(filter, doc! { "isbn": "978-0" });
+    }
+
+    #[test]
+    fn missing_key_should_identify_the_item() {
+        let book = Book {
+            isbn: "978-0".to_string(),
+            title: "Dune".to_string(),
+        };
+
+        let error = MongodbItemWriter::key_filter(&book, "id").unwrap_err();
+
+        assert!(error.to_string().contains("missing upsert key `id`"));
+        assert!(error.to_string().contains("Dune"));
+    }
+
+    fn insert_many_error() -> Error {
+        let insert_many_error: InsertManyError = from_document(doc! {
+            "writeErrors": [
+                { "index": 1, "code": 11000, "errmsg": "E11000 duplicate key" },
+                { "index": 3, "code": 11000, "errmsg": "E11000 duplicate key" },
+            ],
+        })
+        .unwrap();
+
+        Error::from(ErrorKind::InsertMany(insert_many_error))
+    }
+
+    #[test]
+    fn insert_error_should_list_the_failed_indexes() {
+        let error = MongodbItemWriter::<Book>::insert_error(insert_many_error(), 5, false);
+
+        assert_eq!(
+            error,
+            BatchError::PartialWrite {
+                written: 3,
+                message: "failed to insert 2 of 5 documents: [1] E11000 duplicate key, \
+                          [3] E11000 duplicate key"
+                    .to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn ordered_insert_error_should_count_the_documents_before_the_failure() {
+        let error = MongodbItemWriter::<Book>::insert_error(insert_many_error(), 5, true);
+
+        assert!(matches!(error, BatchError::PartialWrite { written: 1, .. }));
+    }
+}
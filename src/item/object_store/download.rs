@@ -0,0 +1,163 @@
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+    sync::Arc,
+};
+
+use bytes::Buf;
+use futures::{stream::BoxStream, StreamExt};
+use object_store::{path::Path as ObjectPath, ObjectStore, ObjectStoreExt};
+use tokio::runtime::{Builder, Runtime};
+
+use crate::BatchError;
+
+pub use object_store::aws::AmazonS3Builder as S3Builder;
+
+/// The content of an object, streamed from the store as it is read.
+///
+/// Object stores are asynchronous: the body owns a single-threaded runtime on which the
+/// next parts of the object are fetched. It must therefore not be read from within an
+/// asynchronous context.
+pub struct ObjectBody {
+    runtime: Runtime,
+    stream: BoxStream<'static, object_store::Result<bytes::Bytes>>,
+    part: bytes::Bytes,
+}
+
+impl Read for ObjectBody {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while !self.part.has_remaining() {
+            match self.runtime.block_on(self.stream.next()) {
+                Some(part) => self.part = part.map_err(io::Error::other)?,
+                None => return Ok(0),
+            }
+        }
+
+        let len = buf.len().min(self.part.remaining());
+        self.part.copy_to_slice(&mut buf[..len]);
+
+        Ok(len)
+    }
+}
+
+/// Opens the object at `location` in `store`, to be read by a reader.
+///
+/// The object is not loaded in memory but streamed from the store as the reader consumes it.
+///
+/// # Errors
+///
+/// Returns a `BatchError::ItemReader` if the object cannot be fetched.
+pub fn open_object(store: Arc<dyn ObjectStore>, location: &str) -> Result<ObjectBody, BatchError> {
+    let runtime = Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|error| BatchError::ItemReader(error.to_string()))?;
+
+    let result = runtime
+        .block_on(store.get(&ObjectPath::from(location)))
+        .map_err(|error| BatchError::ItemReader(format!("GET {}: {}", location, error)))?;
+
+    Ok(ObjectBody {
+        runtime,
+        stream: result.into_stream(),
+        part: bytes::Bytes::new(),
+    })
+}
+
+/// Opens the object `key` of the S3 `bucket`, to be read by a reader.
+///
+/// The region, the endpoint and the credentials are read from the standard
+/// `AWS_*` environment variables. Use [`open_object`] with a store built by
+/// [`S3Builder`] to set them explicitly.
+///
+/// # Errors
+///
+/// Returns a `BatchError::ItemReader` if the store cannot be configured or the
+/// object cannot be fetched.
+pub fn open_s3_object(bucket: &str, key: &str) -> Result<ObjectBody, BatchError> {
+    let store = S3Builder::from_env()
+        .with_bucket_name(bucket)
+        .build()
+        .map_err(|error| BatchError::ItemReader(error.to_string()))?;
+
+    open_object(Arc::new(store), key)
+}
+
+/// Downloads the object at `location` in `store` to the local file `destination`,
+/// and returns the number of bytes written.
+///
+/// # Errors
+///
+/// Returns a `BatchError::ItemReader` if the object cannot be fetched or written.
+pub fn download(
+    store: Arc<dyn ObjectStore>,
+    location: &str,
+    destination: &Path,
+) -> Result<u64, BatchError> {
+    let mut body = open_object(store, location)?;
+
+    File::create(destination)
+        .and_then(|mut file| io::copy(&mut body, &mut file))
+        .map_err(|error| BatchError::ItemReader(format!("{}: {}", destination.display(), error)))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{fs, io::Read, sync::Arc};
+
+    use object_store::{memory::InMemory, path::Path, ObjectStore, ObjectStoreExt};
+    use tempfile::tempdir;
+
+    use super::{download, open_object};
+
+    /// Creates an in-memory store holding a single object.
+    pub(crate) fn memory_store(location: &str, content: &'static str) -> Arc<dyn ObjectStore> {
+        let store = InMemory::new();
+
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(store.put(&Path::from(location), content.as_bytes().into()))
+            .unwrap();
+
+        Arc::new(store)
+    }
+
+    #[test]
+    fn body_should_be_streamed() {
+        let store = memory_store("data/hello.txt", "hello");
+
+        let mut content = String::new();
+        open_object(store, "data/hello.txt")
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+
+        assert_eq!(content, "hello");
+    }
+
+    #[test]
+    fn missing_object_should_fail() {
+        let store = memory_store("data/hello.txt", "hello");
+
+        let result = open_object(store, "data/missing.txt");
+
+        assert!(result
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("GET data/missing.txt"));
+    }
+
+    #[test]
+    fn object_should_be_downloaded_to_a_file() {
+        let store = memory_store("data/hello.txt", "hello");
+        let dir = tempdir().unwrap();
+        let destination = dir.path().join("hello.txt");
+
+        let written = download(store, "data/hello.txt", &destination).unwrap();
+
+        assert_eq!(written, 5);
+        assert_eq!(fs::read_to_string(destination).unwrap(), "hello");
+    }
+}
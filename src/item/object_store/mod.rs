@@ -0,0 +1,3 @@
+/// This module contains the download of the objects read by the `from_s3` and
+/// `from_object_store` builders of readers.
+pub mod download;
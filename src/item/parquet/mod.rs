@@ -0,0 +1,7 @@
+/// This module provides an Apache Parquet item reader.
+pub mod parquet_reader;
+
+/// This module provides an Apache Parquet item writer.
+///
+/// The schema of the file is derived from the item type with `serde_arrow`.
+pub mod parquet_writer;
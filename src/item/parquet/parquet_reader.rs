@@ -0,0 +1,242 @@
+use std::{
+    cell::RefCell, collections::VecDeque, fs::File, io::Read, marker::PhantomData, path::Path,
+};
+
+use bytes::Bytes;
+use parquet::{
+    arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder},
+    file::reader::ChunkReader,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    core::item::{ItemReader, ItemReaderResult},
+    BatchError,
+};
+
+/// A reader deserializing the rows of an Apache Parquet file.
+///
+/// Rows are decoded by batches of `batch_size` rows, then deserialized into serde structs.
+/// A batch which cannot be decoded or deserialized is skipped as a whole, as a single
+/// read error.
+pub struct ParquetItemReader<T> {
+    batches: RefCell<ParquetRecordBatchReader>,
+    buffer: RefCell<VecDeque<T>>,
+    rows: usize,
+}
+
+impl<T: DeserializeOwned> ParquetItemReader<T> {
+    /// Decodes the next batch of rows into the buffer.
+    ///
+    /// Returns `false` when all the rows have been read. The batch is consumed even if it
+    /// cannot be decoded or deserialized, so the error is a `BatchError::Deserialization`.
+    fn read_batch(&self) -> Result<bool, BatchError> {
+        match self.batches.borrow_mut().next() {
+            Some(Ok(batch)) => {
+                let items: Vec<T> = serde_arrow::from_record_batch(&batch)
+                    .map_err(|error| BatchError::deserialization(None, error))?;
+                self.buffer.borrow_mut().extend(items);
+                Ok(true)
+            }
+            Some(Err(error)) => Err(BatchError::deserialization(None, error)),
+            None => Ok(false),
+        }
+    }
+}
+
+impl<T: DeserializeOwned> ItemReader<T> for ParquetItemReader<T> {
+    /// Reads the next row from the file.
+    ///
+    /// Returns `Ok(Some(row))` if a row is successfully read,
+    /// `Ok(None)` if there are no more rows to read, and
+    /// `Err(BatchError::Deserialization { .. })` if the batch of the row cannot be decoded
+    /// or deserialized.
+    fn read(&self) -> ItemReaderResult<T> {
+        while self.buffer.borrow().is_empty() {
+            if !self.read_batch()? {
+                return Ok(None);
+            }
+        }
+
+        Ok(self.buffer.borrow_mut().pop_front())
+    }
+
+    /// Returns the number of rows of the file, as stored in its metadata.
+    fn total_hint(&self) -> Option<usize> {
+        Some(self.rows)
+    }
+}
+
+/// A builder for configuring Parquet item reading.
+#[derive(Default)]
+pub struct ParquetItemReaderBuilder<T> {
+    batch_size: usize,
+    _pd: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned> ParquetItemReaderBuilder<T> {
+    /// Creates a new `ParquetItemReaderBuilder`.
+    pub fn new() -> Self {
+        Self {
+            batch_size: 1024,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Sets the number of rows decoded at once. Defaults to 1024.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Creates a `ParquetItemReader` from a reader.
+    ///
+    /// The metadata of a Parquet file is stored at its end, so the whole content is
+    /// loaded in memory. Prefer `from_path` for large files.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the content cannot be read or is not a valid Parquet file.
+    pub fn from_reader<R: Read>(self, mut rdr: R) -> ParquetItemReader<T> {
+        let mut content = Vec::new();
+        rdr.read_to_end(&mut content)
+            .expect("Unable to read Parquet content");
+
+        self.build(Bytes::from(content))
+    }
+
+    /// Creates a `ParquetItemReader` from a file path.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the file cannot be opened or is not a valid Parquet file.
+    pub fn from_path<P: AsRef<Path>>(self, path: P) -> ParquetItemReader<T> {
+        let file = File::open(path).expect("Unable to open file");
+
+        self.build(file)
+    }
+
+    fn build<C: ChunkReader + 'static>(self, content: C) -> ParquetItemReader<T> {
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new(content).expect("Invalid Parquet file");
+        let rows = builder.metadata().file_metadata().num_rows();
+        let batches = builder
+            .with_batch_size(self.batch_size)
+            .build()
+            .expect("Invalid Parquet file");
+
+        ParquetItemReader {
+            batches: RefCell::new(batches),
+            buffer: RefCell::new(VecDeque::new()),
+            rows: usize::try_from(rows).unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        core::{
+            item::{ItemReader, ItemReaderResult, ItemWriter, VecItemWriter},
+            step::{Step, StepBuilder, StepInstance},
+        },
+        item::parquet::parquet_writer::ParquetItemWriterBuilder,
+    };
+
+    use super::ParquetItemReaderBuilder;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Car {
+        year: u16,
+        make: String,
+    }
+
+    #[test]
+    fn rows_should_be_read_from_memory() {
+        let mut content = Vec::new();
+
+        let writer = ParquetItemWriterBuilder::<Car>::new().from_writer(&mut content);
+        writer
+            .write(&[Car {
+                year: 1948,
+                make: "Porsche".to_string(),
+            }])
+            .unwrap();
+        ItemWriter::<Car>::close(&writer).unwrap();
+        drop(writer);
+
+        let reader = ParquetItemReaderBuilder::new().from_reader(content.as_slice());
+        assert_eq!(reader.total_hint(), Some(1));
+
+        let car: Car = reader.read().unwrap().unwrap();
+        assert_eq!(car.make, "Porsche");
+
+        let result: ItemReaderResult<Car> = reader.read();
+        assert!(result.unwrap().is_none());
+    }
+
+    #[test]
+    fn malformed_batches_should_be_skipped_but_not_retried() {
+        #[derive(Deserialize, Clone, Debug, PartialEq)]
+        #[serde(try_from = "u16")]
+        struct Year(u16);
+
+        impl TryFrom<u16> for Year {
+            type Error = String;
+
+            fn try_from(year: u16) -> Result<Self, Self::Error> {
+                if year > 0 {
+                    Ok(Year(year))
+                } else {
+                    Err(format!("invalid year: {}", year))
+                }
+            }
+        }
+
+        #[derive(Deserialize, Clone, Debug, PartialEq)]
+        struct DatedCar {
+            year: Year,
+        }
+
+        let mut content = Vec::new();
+        let writer = ParquetItemWriterBuilder::<Car>::new().from_writer(&mut content);
+        let cars: Vec<Car> = [1948, 0, 2012]
+            .into_iter()
+            .map(|year| Car {
+                year,
+                make: "Porsche".to_string(),
+            })
+            .collect();
+        writer.write(&cars).unwrap();
+        ItemWriter::<Car>::close(&writer).unwrap();
+        drop(writer);
+
+        for skip_limit in [0, 1] {
+            let reader = ParquetItemReaderBuilder::new()
+                .batch_size(1)
+                .from_reader(content.as_slice());
+            let writer = VecItemWriter::new();
+
+            let step: StepInstance<DatedCar, DatedCar> = StepBuilder::new()
+                .reader(&reader)
+                .writer(&writer)
+                .retry_limit(1)
+                .skip_limit(skip_limit)
+                .build();
+
+            let result = step.execute();
+
+            assert_eq!(result.is_ok(), skip_limit == 1);
+            assert_eq!(step.get_read_error_count(), 1);
+            assert_eq!(step.get_retry_count(), 0);
+            if skip_limit == 1 {
+                assert_eq!(
+                    writer.items(),
+                    vec![DatedCar { year: Year(1948) }, DatedCar { year: Year(2012) }]
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use std::{cell::RefCell, fs::File, io::Write, marker::PhantomData, path::Path, sync::Arc};
+
+use arrow_schema::{FieldRef, Schema};
+use parquet::{arrow::ArrowWriter, basic::Compression, file::properties::WriterProperties};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_arrow::schema::{SchemaLike, TracingOptions};
+
+use crate::{
+    core::item::{ItemWriter, ItemWriterResult},
+    BatchError,
+};
+
+/// A writer producing an Apache Parquet file.
+///
+/// Each chunk is converted into an Arrow record batch. Rows are buffered by the
+/// underlying Arrow writer: `flush` writes the buffered rows as a row group, and
+/// row groups larger than the configured row group size are split.
+/// `close` writes the remaining rows and the metadata of the file.
+pub struct ParquetItemWriter<T, W: Write + Send> {
+    fields: Vec<FieldRef>,
+    writer: RefCell<Option<ArrowWriter<W>>>,
+    _pd: PhantomData<T>,
+}
+
+impl<T, W: Write + Send> ParquetItemWriter<T, W> {
+    fn closed() -> BatchError {
+        BatchError::ItemWriter("Parquet writer is closed".to_string())
+    }
+}
+
+impl<T: Serialize, W: Write + Send> ItemWriter<T> for ParquetItemWriter<T, W> {
+    fn write(&self, items: &[T]) -> ItemWriterResult {
+        let mut writer = self.writer.borrow_mut();
+        let writer = writer.as_mut().ok_or_else(Self::closed)?;
+
+        let batch = serde_arrow::to_record_batch(&self.fields, &items)
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+
+        writer
+            .write(&batch)
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))
+    }
+
+    /// Writes the buffered rows as a new row group.
+    fn flush(&self) -> ItemWriterResult {
+        let mut writer = self.writer.borrow_mut();
+        let writer = writer.as_mut().ok_or_else(Self::closed)?;
+
+        writer
+            .flush()
+            .map_err(|error| BatchError::ItemWriter(error.to_string()))
+    }
+
+    /// Writes the remaining rows and the metadata of the file.
+    fn close(&self) -> ItemWriterResult {
+        if let Some(writer) = self.writer.borrow_mut().take() {
+            writer
+                .close()
+                .map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A builder for configuring Parquet item writing.
+#[derive(Default)]
+pub struct ParquetItemWriterBuilder<T> {
+    row_group_size: Option<usize>,
+    compression: Option<Compression>,
+    _pd: PhantomData<T>,
+}
+
+impl<T: Serialize + DeserializeOwned> ParquetItemWriterBuilder<T> {
+    /// Creates a new `ParquetItemWriterBuilder`.
+    pub fn new() -> Self {
+        Self {
+            row_group_size: None,
+            compression: None,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Sets the maximum number of rows of a row group.
+    pub fn row_group_size(mut self, row_group_size: usize) -> Self {
+        self.row_group_size = Some(row_group_size);
+        self
+    }
+
+    /// Sets the compression of the columns, for example `Compression::SNAPPY` or
+    /// `Compression::ZSTD(ZstdLevel::default())`. Defaults to no compression.
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Creates a `ParquetItemWriter` writing to a writer.
+    ///
+    /// The schema is traced from the `Deserialize` implementation of the item type.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the schema cannot be derived from the item type.
+    pub fn from_writer<W: Write + Send>(self, wtr: W) -> ParquetItemWriter<T, W> {
+        let fields = Vec::<FieldRef>::from_type::<T>(TracingOptions::default())
+            .expect("Unable to derive the Parquet schema from the item type");
+
+        let mut properties = WriterProperties::builder()
+            .set_compression(self.compression.unwrap_or(Compression::UNCOMPRESSED));
+        if let Some(row_group_size) = self.row_group_size {
+            properties = properties.set_max_row_group_row_count(Some(row_group_size));
+        }
+
+        let schema = Arc::new(Schema::new(fields.clone()));
+        let writer = ArrowWriter::try_new(wtr, schema, Some(properties.build()))
+            .expect("Unable to create the Parquet writer");
+
+        ParquetItemWriter {
+            fields,
+            writer: RefCell::new(Some(writer)),
+            _pd: PhantomData,
+        }
+    }
+
+    /// Creates a `ParquetItemWriter` writing to a file.
+    pub fn from_path<P: AsRef<Path>>(self, path: P) -> ParquetItemWriter<T, File> {
+        let file = File::create(path).expect("Unable to create file");
+
+        self.from_writer(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parquet::{
+        basic::{Compression, ZstdLevel},
+        file::reader::{FileReader, SerializedFileReader},
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        core::item::{ItemReader, ItemWriter},
+        item::parquet::parquet_reader::ParquetItemReaderBuilder,
+    };
+
+    use super::ParquetItemWriterBuilder;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+    struct Car {
+        year: u16,
+        make: String,
+        price: Option<f64>,
+    }
+
+    fn cars() -> Vec<Car> {
+        (0..5)
+            .map(|i| Car {
+                year: 2000 + i,
+                make: format!("make-{}", i),
+                price: (i % 2 == 0).then_some(f64::from(i) * 1000.0),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn written_rows_should_be_read_back() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+
+        let writer = ParquetItemWriterBuilder::<Car>::new()
+            .compression(Compression::ZSTD(ZstdLevel::default()))
+            .from_path(file.path());
+
+        let cars = cars();
+        writer.write(&cars[..3]).unwrap();
+        writer.flush().unwrap();
+        writer.write(&cars[3..]).unwrap();
+        ItemWriter::<Car>::close(&writer).unwrap();
+
+        let reader = ParquetItemReaderBuilder::new()
+            .batch_size(2)
+            .from_path(file.path());
+
+        let read: Vec<Car> = std::iter::from_fn(|| reader.read().unwrap()).collect();
+        assert_eq!(read, cars);
+    }
+
+    #[test]
+    fn row_groups_should_respect_the_configured_size() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+
+        let writer = ParquetItemWriterBuilder::<Car>::new()
+            .row_group_size(2)
+            .compression(Compression::SNAPPY)
+            .from_path(file.path());
+
+        writer.write(&cars()).unwrap();
+        ItemWriter::<Car>::close(&writer).unwrap();
+
+        let metadata = SerializedFileReader::new(file.reopen().unwrap())
+            .unwrap()
+            .metadata()
+            .clone();
+
+        assert_eq!(metadata.num_row_groups(), 3);
+        assert_eq!(metadata.file_metadata().num_rows(), 5);
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+    fmt::Debug,
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use pgp::{
+    composed::{Deserializable, Message, SignedSecretKey},
+    types::Password,
+};
+
+use crate::BatchError;
+
+/// A reader decrypting an OpenPGP (GPG) encrypted stream on the fly.
+///
+/// It implements `std::io::Read`, so it can be given to the `from_reader` method
+/// of any format reader builder: the plaintext never needs to be written to disk.
+///
+/// # Examples
+///
+/// ```no_run
+/// use spring_batch_rs::item::csv::csv_reader::CsvItemReaderBuilder;
+/// use spring_batch_rs::item::pgp::PgpDecryptReaderBuilder;
+///
+/// let input = PgpDecryptReaderBuilder::new()
+///     .secret_key(&std::fs::read("partner.key").unwrap())
+///     .passphrase("secret")
+///     .from_path("cars.csv.gpg")
+///     .unwrap();
+///
+/// let reader = CsvItemReaderBuilder::new()
+///     .has_headers(true)
+///     .from_reader(input);
+/// ```
+pub struct PgpDecryptReader<'a> {
+    message: Message<'a>,
+}
+
+impl Read for PgpDecryptReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.message.read(buf)
+    }
+}
+
+/// Builder for creating a `PgpDecryptReader`.
+#[derive(Default)]
+pub struct PgpDecryptReaderBuilder {
+    secret_key: Option<SignedSecretKey>,
+    passphrase: String,
+}
+
+impl PgpDecryptReaderBuilder {
+    /// Creates a new `PgpDecryptReaderBuilder` instance.
+    pub fn new() -> Self {
+        Self {
+            secret_key: None,
+            passphrase: String::new(),
+        }
+    }
+
+    /// Sets the private key used to decrypt the stream, either ASCII armored or binary.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the key cannot be parsed.
+    pub fn secret_key(mut self, secret_key: &[u8]) -> Self {
+        let (secret_key, _) =
+            SignedSecretKey::from_reader_single(secret_key).expect("Invalid secret key");
+        self.secret_key = Some(secret_key);
+        self
+    }
+
+    /// Sets the passphrase protecting the private key. Defaults to an empty passphrase.
+    pub fn passphrase(mut self, passphrase: &str) -> Self {
+        self.passphrase = passphrase.to_string();
+        self
+    }
+
+    /// Creates a `PgpDecryptReader` from an encrypted stream, either ASCII armored or binary.
+    ///
+    /// Returns an error if the stream is not an OpenPGP message or cannot be decrypted with the key.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the secret key is not set.
+    pub fn from_reader<'a, R: Read + Debug + Send + 'a>(
+        self,
+        rdr: R,
+    ) -> Result<PgpDecryptReader<'a>, BatchError> {
+        let secret_key = self.secret_key.expect("Secret key is mandatory");
+        let passphrase = Password::from(self.passphrase.as_str());
+
+        let (message, _) = Message::from_reader(BufReader::new(rdr))
+            .map_err(|error| BatchError::ItemReader(error.to_string()))?;
+
+        let mut message = message
+            .decrypt(&passphrase, &secret_key)
+            .map_err(|error| BatchError::ItemReader(error.to_string()))?;
+
+        if message.is_compressed() {
+            message = message
+                .decompress()
+                .map_err(|error| BatchError::ItemReader(error.to_string()))?;
+        }
+
+        Ok(PgpDecryptReader { message })
+    }
+
+    /// Creates a `PgpDecryptReader` from an encrypted file.
+    pub fn from_path<P: AsRef<Path>>(
+        self,
+        path: P,
+    ) -> Result<PgpDecryptReader<'static>, BatchError> {
+        let file = File::open(path).map_err(|error| BatchError::ItemReader(error.to_string()))?;
+
+        self.from_reader(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgp::{
+        composed::{
+            EncryptionCaps, KeyType, MessageBuilder, SecretKeyParamsBuilder, SignedPublicKey,
+            SignedSecretKey, SubkeyParamsBuilder,
+        },
+        crypto::{ecc_curve::ECCCurve, sym::SymmetricKeyAlgorithm},
+        ser::Serialize,
+    };
+    use rand::thread_rng;
+    use serde::Deserialize;
+
+    use crate::{core::item::ItemReader, item::csv::csv_reader::CsvItemReaderBuilder};
+
+    use super::PgpDecryptReaderBuilder;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Car {
+        year: u16,
+        make: String,
+    }
+
+    fn generate_key() -> SignedSecretKey {
+        let mut encryption_key = SubkeyParamsBuilder::default();
+        encryption_key
+            .key_type(KeyType::ECDH(ECCCurve::Curve25519Legacy))
+            .can_encrypt(EncryptionCaps::All);
+
+        let mut key_params = SecretKeyParamsBuilder::default();
+        key_params
+            .key_type(KeyType::Ed25519Legacy)
+            .can_certify(true)
+            .primary_user_id("Partner <partner@example.com>".into())
+            .subkeys(vec![encryption_key.build().unwrap()]);
+
+        key_params.build().unwrap().generate(thread_rng()).unwrap()
+    }
+
+    fn encrypt(secret_key: &SignedSecretKey, plaintext: &str) -> Vec<u8> {
+        let public_key = SignedPublicKey::from(secret_key.clone());
+
+        let mut builder = MessageBuilder::from_bytes("", plaintext.as_bytes().to_vec())
+            .seipd_v1(thread_rng(), SymmetricKeyAlgorithm::AES256);
+        builder
+            .encrypt_to_key(thread_rng(), &public_key.public_subkeys[0])
+            .unwrap();
+
+        builder.to_vec(thread_rng()).unwrap()
+    }
+
+    #[test]
+    fn encrypted_csv_should_be_read() {
+        let secret_key = generate_key();
+        let encrypted = encrypt(&secret_key, "year,make\n1948,Porsche\n1967,Ford\n");
+
+        let input = PgpDecryptReaderBuilder::new()
+            .secret_key(&secret_key.to_bytes().unwrap())
+            .from_reader(encrypted.as_slice())
+            .unwrap();
+
+        let reader = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .from_reader(input);
+
+        let car: Car = reader.read().unwrap().unwrap();
+        assert_eq!(car.make, "Porsche");
+        let car: Car = reader.read().unwrap().unwrap();
+        assert_eq!(car.year, 1967);
+        let car: Option<Car> = reader.read().unwrap();
+        assert!(car.is_none());
+    }
+
+    #[test]
+    fn wrong_key_should_fail() {
+        let encrypted = encrypt(&generate_key(), "year,make\n");
+
+        let result = PgpDecryptReaderBuilder::new()
+            .secret_key(&generate_key().to_bytes().unwrap())
+            .from_reader(encrypted.as_slice());
+
+        assert!(result.is_err());
+    }
+}
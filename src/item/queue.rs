@@ -0,0 +1,158 @@
+use std::{
+    cell::RefCell,
+    sync::mpsc::{sync_channel, Receiver, SyncSender},
+};
+
+use crate::{
+    core::item::{ItemReader, ItemReaderResult, ItemWriter, ItemWriterResult},
+    BatchError,
+};
+
+/// Creates a bounded in-memory queue connecting two steps.
+///
+/// Items written to the `QueueItemWriter` are read from the `QueueItemReader`.
+/// When the queue holds `capacity` items, the writer blocks until the reader
+/// consumes some of them. Closing the writer signals the end of the stream:
+/// once the remaining items are consumed, the reader returns `Ok(None)`.
+///
+/// Both ends can be moved to another thread, so that the producing and the
+/// consuming steps run concurrently.
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+/// use spring_batch_rs::core::item::{ItemReader, ItemWriter};
+/// use spring_batch_rs::item::queue::queue;
+///
+/// let (writer, reader) = queue(2);
+///
+/// let producer = thread::spawn(move || {
+///     writer.write(&[1, 2, 3]).unwrap();
+///     writer.close().unwrap();
+/// });
+///
+/// let mut items = Vec::new();
+/// while let Some(item) = reader.read().unwrap() {
+///     items.push(item);
+/// }
+/// producer.join().unwrap();
+///
+/// assert_eq!(items, vec![1, 2, 3]);
+/// ```
+pub fn queue<T>(capacity: usize) -> (QueueItemWriter<T>, QueueItemReader<T>) {
+    let (sender, receiver) = sync_channel(capacity);
+
+    (
+        QueueItemWriter {
+            sender: RefCell::new(Some(sender)),
+        },
+        QueueItemReader { receiver },
+    )
+}
+
+/// The producing end of a queue created with [`queue`].
+pub struct QueueItemWriter<T> {
+    sender: RefCell<Option<SyncSender<T>>>,
+}
+
+impl<T: Clone> ItemWriter<T> for QueueItemWriter<T> {
+    /// Pushes the items to the queue, blocking while the queue is full.
+    ///
+    /// Returns an error if the writer is closed or if the reader has been dropped.
+    fn write(&self, items: &[T]) -> ItemWriterResult {
+        let sender = self.sender.borrow();
+        let sender = sender
+            .as_ref()
+            .ok_or_else(|| BatchError::ItemWriter("queue is closed".to_string()))?;
+
+        for item in items {
+            sender
+                .send(item.clone())
+                .map_err(|_| BatchError::ItemWriter("queue reader is gone".to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Closes the queue, signaling the end of the stream to the reader.
+    fn close(&self) -> ItemWriterResult {
+        self.sender.borrow_mut().take();
+        Ok(())
+    }
+}
+
+/// The consuming end of a queue created with [`queue`].
+pub struct QueueItemReader<T> {
+    receiver: Receiver<T>,
+}
+
+impl<T> ItemReader<T> for QueueItemReader<T> {
+    /// Pops the next item from the queue, blocking until one is available.
+    ///
+    /// Returns `Ok(None)` once the writer is closed and the queue is drained.
+    fn read(&self) -> ItemReaderResult<T> {
+        Ok(self.receiver.recv().ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::core::{
+        item::{ItemReader, ItemWriter},
+        job::{Job, JobBuilder},
+        step::{StepBuilder, StepInstance},
+    };
+
+    use super::queue;
+
+    #[test]
+    fn reader_should_return_none_after_close() {
+        let (writer, reader) = queue(4);
+
+        writer.write(&["a", "b"]).unwrap();
+        writer.close().unwrap();
+
+        assert_eq!(reader.read().unwrap(), Some("a"));
+        assert_eq!(reader.read().unwrap(), Some("b"));
+        assert_eq!(reader.read().unwrap(), None);
+    }
+
+    #[test]
+    fn write_should_fail_once_closed() {
+        let (writer, _reader) = queue(1);
+
+        writer.close().unwrap();
+
+        assert!(writer.write(&[1]).is_err());
+    }
+
+    #[test]
+    fn steps_should_be_pipelined_through_the_queue() {
+        let (writer, reader) = queue::<u32>(1);
+
+        let producer = thread::spawn(move || {
+            let (input_writer, input) = queue(10);
+            input_writer.write(&[1, 2, 3, 4, 5]).unwrap();
+            input_writer.close().unwrap();
+
+            let step: StepInstance<u32, u32> = StepBuilder::new()
+                .reader(&input)
+                .writer(&writer)
+                .chunk(2)
+                .build();
+
+            JobBuilder::new().start(&step).build().run().is_ok()
+        });
+
+        let mut items = Vec::new();
+        while let Some(item) = reader.read().unwrap() {
+            items.push(item);
+        }
+
+        assert!(producer.join().unwrap());
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+    }
+}
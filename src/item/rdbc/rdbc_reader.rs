@@ -12,6 +12,10 @@ pub trait RdbcRowMapper<T> {
 }
 
 /// A reader for reading items from a relational database using SQLx.
+///
+/// The reader runs on the `Any` driver, so the same reader serves Postgres,
+/// MySQL and SQLite pools: switching databases only changes the connection URL.
+/// When a page size is set, rows are fetched page by page with `LIMIT`/`OFFSET`.
 pub struct RdbcItemReader<'a, T> {
     pool: &'a Pool<Any>,
     query: &'a str,
@@ -61,12 +65,8 @@ impl<'a, T> RdbcItemReader<'a, T> {
     fn read_page(&self) {
         let mut query_builder = QueryBuilder::new(self.query);
 
-        if self.page_size.is_some() {
-            query_builder.push(format!(
-                " LIMIT {} OFFSET {}",
-                self.page_size.unwrap(),
-                self.offset.get()
-            ));
+        if let Some(page_size) = self.page_size {
+            query_builder.push(format!(" LIMIT {} OFFSET {}", page_size, self.offset.get()));
         }
 
         let query = query_builder.build();
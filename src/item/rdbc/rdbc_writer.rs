@@ -1,9 +1,12 @@
 use serde::Serialize;
 use sqlx::{query_builder::Separated, Any, Pool, QueryBuilder};
 
-use crate::core::item::{ItemWriter, ItemWriterResult};
+use crate::{
+    core::item::{ItemWriter, ItemWriterResult},
+    BatchError,
+};
 
-// The number of parameters in MySQL must fit in a `u16`.
+// The number of parameters in MySQL and Postgres must fit in a `u16`.
 const BIND_LIMIT: usize = 65535;
 
 pub trait RdbcItemBinder<T> {
@@ -15,6 +18,7 @@ pub struct RdbcItemWriter<'a, W> {
     table: &'a str,
     columns: Vec<&'a str>,
     item_binder: &'a dyn RdbcItemBinder<W>,
+    bind_limit: usize,
 }
 
 impl<'a, W> RdbcItemWriter<'a, W> {
@@ -41,13 +45,24 @@ impl<'a, W> RdbcItemWriter<'a, W> {
             table,
             columns,
             item_binder,
+            bind_limit: BIND_LIMIT,
         }
     }
+
+    /// Returns the maximum number of rows a single `INSERT` statement can hold
+    /// without exceeding the bind parameter limit.
+    fn rows_per_statement(&self) -> usize {
+        (self.bind_limit / self.columns.len()).max(1)
+    }
 }
 
 impl<'a, W: Serialize + Clone> ItemWriter<W> for RdbcItemWriter<'a, W> {
     /// Writes the items to the database.
     ///
+    /// All the items of the chunk are inserted in a single transaction: if any insert
+    /// fails, the whole chunk is rolled back. The step then counts every item of the
+    /// chunk as a write error against its skip limit, and none of them is written.
+    ///
     /// # Arguments
     ///
     /// * `items` - A slice of items to be written.
@@ -56,29 +71,47 @@ impl<'a, W: Serialize + Clone> ItemWriter<W> for RdbcItemWriter<'a, W> {
     ///
     /// An `ItemWriterResult` indicating the result of the write operation.
     fn write(&self, items: &[W]) -> ItemWriterResult {
-        let mut query_builder = QueryBuilder::new("INSERT INTO ");
-
-        query_builder.push(self.table);
-        query_builder.push(" (");
-        query_builder.push(self.columns.join(","));
-        query_builder.push(") ");
-
-        query_builder.push_values(
-            items.iter().take(BIND_LIMIT / self.columns.len()),
-            |b: sqlx::query_builder::Separated<'_, '_, Any, &str>, item| {
-                self.item_binder.bind(item, b);
-            },
-        );
-
-        let query = query_builder.build();
+        if items.is_empty() {
+            return Ok(());
+        }
 
-        let _result = tokio::task::block_in_place(|| {
+        let statements: Vec<_> = items
+            .chunks(self.rows_per_statement())
+            .map(|rows| {
+                let mut query_builder = QueryBuilder::new("INSERT INTO ");
+
+                query_builder.push(self.table);
+                query_builder.push(" (");
+                query_builder.push(self.columns.join(","));
+                query_builder.push(") ");
+
+                query_builder.push_values(
+                    rows,
+                    |b: sqlx::query_builder::Separated<'_, '_, Any, &str>, item| {
+                        self.item_binder.bind(item, b);
+                    },
+                );
+
+                query_builder
+            })
+            .collect();
+
+        // One multi-row INSERT per chunk, split only when the bind limit is reached.
+        // The statements share a transaction so that the chunk is written atomically.
+        tokio::task::block_in_place(|| {
             tokio::runtime::Runtime::new()
-                .unwrap()
-                .block_on(async { query.execute(self.pool).await.unwrap() })
-        });
-
-        Ok(())
+                .map_err(|error| BatchError::ItemWriter(error.to_string()))?
+                .block_on(async {
+                    let mut transaction = self.pool.begin().await?;
+
+                    for mut query_builder in statements {
+                        query_builder.build().execute(&mut *transaction).await?;
+                    }
+
+                    transaction.commit().await
+                })
+                .map_err(|error| BatchError::ItemWriter(error.to_string()))
+        })
     }
 }
 
@@ -88,6 +121,7 @@ pub struct RdbcItemWriterBuilder<'a, T> {
     table: Option<&'a str>,
     columns: Vec<&'a str>,
     item_binder: Option<&'a dyn RdbcItemBinder<T>>,
+    bind_limit: Option<usize>,
 }
 
 impl<'a, T> RdbcItemWriterBuilder<'a, T> {
@@ -102,6 +136,7 @@ impl<'a, T> RdbcItemWriterBuilder<'a, T> {
             table: None,
             columns: Vec::new(),
             item_binder: None,
+            bind_limit: None,
         }
     }
 
@@ -161,6 +196,24 @@ impl<'a, T> RdbcItemWriterBuilder<'a, T> {
         self
     }
 
+    /// Sets the maximum number of bind parameters per `INSERT` statement.
+    ///
+    /// Chunks needing more parameters are split into several statements.
+    /// Defaults to 65535, the limit of MySQL and Postgres; use 999 for SQLite
+    /// builds older than 3.32.
+    ///
+    /// # Arguments
+    ///
+    /// * `bind_limit` - The maximum number of bind parameters.
+    ///
+    /// # Returns
+    ///
+    /// The updated `RdbcItemWriterBuilder` instance.
+    pub fn bind_limit(mut self, bind_limit: usize) -> Self {
+        self.bind_limit = Some(bind_limit);
+        self
+    }
+
     /// Builds an instance of `RdbcItemWriter` based on the configured parameters.
     ///
     /// # Panics
@@ -179,11 +232,17 @@ impl<'a, T> RdbcItemWriterBuilder<'a, T> {
             panic!("One or more columns are required");
         }
 
-        RdbcItemWriter::new(
+        let mut writer = RdbcItemWriter::new(
             self.pool.unwrap(),
             self.table.unwrap(),
             self.columns.clone(),
             self.item_binder.unwrap(),
-        )
+        );
+
+        if let Some(bind_limit) = self.bind_limit {
+            writer.bind_limit = bind_limit;
+        }
+
+        writer
     }
 }
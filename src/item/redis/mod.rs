@@ -0,0 +1,5 @@
+/// This module contains the Redis stream reader implementation.
+pub mod redis_reader;
+
+/// This module contains the Redis stream writer implementation.
+pub mod redis_writer;
@@ -0,0 +1,277 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    marker::PhantomData,
+    time::Duration,
+};
+
+use redis::{
+    streams::{StreamReadOptions, StreamReadReply},
+    Client, Commands, Connection, RedisResult, Value,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Map;
+
+use crate::{
+    core::item::{ItemReader, ItemReaderResult},
+    BatchError,
+};
+
+/// Converts the field map of a stream entry into an item.
+///
+/// Each field is expected to hold a JSON value, as written by the `RedisStreamItemWriter`.
+/// Fields that are not valid JSON are read as plain strings. An entry which cannot be
+/// converted gives a `BatchError::Deserialization`.
+fn from_fields<R: DeserializeOwned>(fields: &HashMap<String, Value>) -> Result<R, BatchError> {
+    let mut map = Map::new();
+
+    for (field, value) in fields {
+        let text: String = redis::from_redis_value(value)
+            .map_err(|error| BatchError::deserialization(None, error))?;
+        let value = serde_json::from_str(&text).unwrap_or(serde_json::Value::String(text));
+        map.insert(field.clone(), value);
+    }
+
+    serde_json::from_value(serde_json::Value::Object(map))
+        .map_err(|error| BatchError::deserialization(None, error))
+}
+
+/// A reader consuming a Redis stream through a consumer group (`XREADGROUP`).
+///
+/// Entries are fetched by pages of `count` entries and acknowledged (`XACK`) once
+/// successfully deserialized. Entries that cannot be deserialized are left pending
+/// in the consumer group so they can be inspected or claimed later.
+///
+/// The reader returns `Ok(None)` when no new entry is available, after waiting up
+/// to the configured `block` duration.
+pub struct RedisStreamItemReader<R> {
+    connection: RefCell<Connection>,
+    stream: String,
+    group: String,
+    options: StreamReadOptions,
+    buffer: RefCell<VecDeque<(String, HashMap<String, Value>)>>,
+    _pd: PhantomData<R>,
+}
+
+impl<R> RedisStreamItemReader<R> {
+    /// Reads the next page of entries from the stream into the buffer.
+    fn read_page(&self) -> Result<(), BatchError> {
+        let reply: RedisResult<Option<StreamReadReply>> = self
+            .connection
+            .borrow_mut()
+            .xread_options(&[&self.stream], &[">"], &self.options);
+
+        let reply = reply.map_err(|error| BatchError::ItemReader(error.to_string()))?;
+
+        let mut buffer = self.buffer.borrow_mut();
+        for key in reply.map(|reply| reply.keys).unwrap_or_default() {
+            for entry in key.ids {
+                buffer.push_back((entry.id, entry.map));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: DeserializeOwned> ItemReader<R> for RedisStreamItemReader<R> {
+    /// Reads the next item from the Redis stream.
+    ///
+    /// Returns `Ok(Some(item))` if an item is read successfully,
+    /// `Ok(None)` if there are no more items to read,
+    /// `Err(BatchError::Deserialization { .. })` if the entry cannot be deserialized,
+    /// or an error if reading the item fails.
+    fn read(&self) -> ItemReaderResult<R> {
+        if self.buffer.borrow().is_empty() {
+            self.read_page()?;
+        }
+
+        let entry = self.buffer.borrow_mut().pop_front();
+
+        match entry {
+            Some((id, fields)) => {
+                let item = from_fields(&fields)?;
+
+                let result: RedisResult<()> =
+                    self.connection
+                        .borrow_mut()
+                        .xack(&self.stream, &self.group, &[&id]);
+                result.map_err(|error| BatchError::ItemReader(error.to_string()))?;
+
+                Ok(Some(item))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// Builder for `RedisStreamItemReader`.
+#[derive(Default)]
+pub struct RedisStreamItemReaderBuilder<R> {
+    url: Option<String>,
+    stream: Option<String>,
+    group: Option<String>,
+    consumer: Option<String>,
+    count: Option<usize>,
+    block: Option<Duration>,
+    _pd: PhantomData<R>,
+}
+
+impl<R> RedisStreamItemReaderBuilder<R> {
+    /// Creates a new `RedisStreamItemReaderBuilder` instance.
+    pub fn new() -> Self {
+        Self {
+            url: None,
+            stream: None,
+            group: None,
+            consumer: None,
+            count: None,
+            block: None,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Sets the URL of the Redis server (for example `redis://127.0.0.1:6379`).
+    pub fn url(mut self, url: &str) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    /// Sets the name of the stream to read from.
+    pub fn stream(mut self, stream: &str) -> Self {
+        self.stream = Some(stream.to_string());
+        self
+    }
+
+    /// Sets the consumer group. The group is created if it does not exist.
+    pub fn group(mut self, group: &str) -> Self {
+        self.group = Some(group.to_string());
+        self
+    }
+
+    /// Sets the consumer name within the group.
+    pub fn consumer(mut self, consumer: &str) -> Self {
+        self.consumer = Some(consumer.to_string());
+        self
+    }
+
+    /// Sets the maximum number of entries fetched per request.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets how long to wait for new entries before considering the stream as exhausted.
+    pub fn block(mut self, block: Duration) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Builds a `RedisStreamItemReader` instance.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the URL, the stream or the group is not set,
+    /// or if the connection to the Redis server fails.
+    pub fn build(self) -> RedisStreamItemReader<R> {
+        let url = self.url.expect("URL is mandatory");
+        let stream = self.stream.expect("Stream name is mandatory");
+        let group = self.group.expect("Consumer group is mandatory");
+        let consumer = self.consumer.unwrap_or_else(|| "spring-batch".to_string());
+
+        let mut connection = Client::open(url)
+            .and_then(|client| client.get_connection())
+            .expect("Unable to connect to Redis");
+
+        let created: RedisResult<()> = connection.xgroup_create_mkstream(&stream, &group, "0");
+        if let Err(error) = created {
+            // The group already exists
+            if error.code() != Some("BUSYGROUP") {
+                panic!("Unable to create consumer group: {}", error);
+            }
+        }
+
+        let mut options = StreamReadOptions::default().group(&group, &consumer);
+        if let Some(count) = self.count {
+            options = options.count(count);
+        }
+        if let Some(block) = self.block {
+            options = options.block(block.as_millis() as usize);
+        }
+
+        RedisStreamItemReader {
+            connection: RefCell::new(connection),
+            stream,
+            group,
+            options,
+            buffer: RefCell::new(VecDeque::new()),
+            _pd: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use redis::Value;
+    use serde::Deserialize;
+
+    use crate::BatchError;
+
+    use super::from_fields;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Event {
+        name: String,
+        count: u32,
+    }
+
+    #[test]
+    fn fields_should_be_deserialized_from_json() {
+        let fields = HashMap::from([
+            (
+                "name".to_string(),
+                Value::BulkString(b"\"created\"".to_vec()),
+            ),
+            ("count".to_string(), Value::BulkString(b"3".to_vec())),
+        ]);
+
+        let event: Event = from_fields(&fields).unwrap();
+
+        assert_eq!(
+            event,
+            Event {
+                name: "created".to_string(),
+                count: 3
+            }
+        );
+    }
+
+    #[test]
+    fn plain_strings_should_be_accepted() {
+        let fields = HashMap::from([
+            ("name".to_string(), Value::BulkString(b"created".to_vec())),
+            ("count".to_string(), Value::BulkString(b"3".to_vec())),
+        ]);
+
+        let event: Event = from_fields(&fields).unwrap();
+
+        assert_eq!(event.name, "created");
+    }
+
+    #[test]
+    fn malformed_entries_should_be_deserialization_errors() {
+        let fields = HashMap::from([
+            ("name".to_string(), Value::BulkString(b"created".to_vec())),
+            ("count".to_string(), Value::BulkString(b"many".to_vec())),
+        ]);
+
+        let result: Result<Event, BatchError> = from_fields(&fields);
+
+        assert!(matches!(
+            result,
+            Err(BatchError::Deserialization { record: None, .. })
+        ));
+    }
+}
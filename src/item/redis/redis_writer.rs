@@ -0,0 +1,167 @@
+use std::{cell::RefCell, marker::PhantomData};
+
+use redis::{streams::StreamMaxlen, Client, Connection};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    core::item::{ItemWriter, ItemWriterResult},
+    BatchError,
+};
+
+/// Converts an item into the field map of a stream entry.
+///
+/// Each top-level field of the item is stored as its JSON representation,
+/// so it can be deserialized back by the `RedisStreamItemReader`.
+fn to_fields<W: Serialize>(item: &W) -> Result<Vec<(String, String)>, BatchError> {
+    let value =
+        serde_json::to_value(item).map_err(|error| BatchError::ItemWriter(error.to_string()))?;
+
+    match value {
+        Value::Object(map) => Ok(map
+            .into_iter()
+            .map(|(field, value)| (field, value.to_string()))
+            .collect()),
+        _ => Err(BatchError::ItemWriter(
+            "item must be serialized as a JSON object".to_string(),
+        )),
+    }
+}
+
+/// A writer appending items to a Redis stream with `XADD`.
+///
+/// Every item of a chunk is added as a new entry, in a single pipeline per chunk.
+pub struct RedisStreamItemWriter<W> {
+    connection: RefCell<Connection>,
+    stream: String,
+    max_len: Option<usize>,
+    _pd: PhantomData<W>,
+}
+
+impl<W: Serialize> ItemWriter<W> for RedisStreamItemWriter<W> {
+    /// Writes the items to the Redis stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The items to be written.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `ItemWriterResult` indicating the result of the write operation.
+    fn write(&self, items: &[W]) -> ItemWriterResult {
+        let mut pipeline = redis::pipe();
+
+        for item in items {
+            let fields = to_fields(item)?;
+
+            match self.max_len {
+                Some(max_len) => {
+                    pipeline.xadd_maxlen(&self.stream, StreamMaxlen::Approx(max_len), "*", &fields)
+                }
+                None => pipeline.xadd(&self.stream, "*", &fields),
+            };
+        }
+
+        let result: redis::RedisResult<()> = pipeline.query(&mut self.connection.borrow_mut());
+
+        result.map_err(|error| BatchError::ItemWriter(error.to_string()))
+    }
+}
+
+/// Builder for `RedisStreamItemWriter`.
+#[derive(Default)]
+pub struct RedisStreamItemWriterBuilder<W> {
+    url: Option<String>,
+    stream: Option<String>,
+    max_len: Option<usize>,
+    _pd: PhantomData<W>,
+}
+
+impl<W> RedisStreamItemWriterBuilder<W> {
+    /// Creates a new `RedisStreamItemWriterBuilder` instance.
+    pub fn new() -> Self {
+        Self {
+            url: None,
+            stream: None,
+            max_len: None,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Sets the URL of the Redis server (for example `redis://127.0.0.1:6379`).
+    pub fn url(mut self, url: &str) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    /// Sets the name of the stream to write to.
+    pub fn stream(mut self, stream: &str) -> Self {
+        self.stream = Some(stream.to_string());
+        self
+    }
+
+    /// Caps the stream to approximately `max_len` entries (`MAXLEN ~`).
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Builds a `RedisStreamItemWriter` instance.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the URL or the stream is not set,
+    /// or if the connection to the Redis server fails.
+    pub fn build(self) -> RedisStreamItemWriter<W> {
+        let url = self.url.expect("URL is mandatory");
+        let stream = self.stream.expect("Stream name is mandatory");
+
+        let connection = Client::open(url)
+            .and_then(|client| client.get_connection())
+            .expect("Unable to connect to Redis");
+
+        RedisStreamItemWriter {
+            connection: RefCell::new(connection),
+            stream,
+            max_len: self.max_len,
+            _pd: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+
+    use super::to_fields;
+
+    #[derive(Serialize)]
+    struct Event {
+        name: String,
+        count: u32,
+    }
+
+    #[test]
+    fn fields_should_be_serialized_as_json() {
+        let event = Event {
+            name: "created".to_string(),
+            count: 3,
+        };
+
+        let mut fields = to_fields(&event).unwrap();
+        fields.sort();
+
+        assert_eq!(
+            fields,
+            vec![
+                ("count".to_string(), "3".to_string()),
+                ("name".to_string(), "\"created\"".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn scalar_item_should_be_rejected() {
+        assert!(to_fields(&42).is_err());
+    }
+}
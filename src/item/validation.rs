@@ -0,0 +1,135 @@
+use std::marker::PhantomData;
+
+use validator::Validate;
+
+use crate::{
+    core::item::{ItemProcessor, ItemProcessorResult},
+    BatchError,
+};
+
+/// A processor validating items with the rules derived by the `validator` crate.
+///
+/// Valid items are passed through unchanged. An invalid item fails with a
+/// `BatchError::ItemProcessor` listing the field errors, so that the skip limit of the
+/// step applies and the item can be recorded by a skip writer.
+///
+/// Placed first in a [`CompositeItemProcessor`](crate::core::item::CompositeItemProcessor),
+/// it makes the following stages only receive valid items.
+///
+/// # Examples
+///
+/// ```
+/// use spring_batch_rs::{
+///     core::item::{CompositeItemProcessor, ItemProcessor, ItemProcessorResult},
+///     item::validation::ValidatingProcessor,
+/// };
+/// use validator::Validate;
+///
+/// #[derive(Clone, Validate)]
+/// struct Customer {
+///     #[validate(length(min = 1))]
+///     name: String,
+///     #[validate(email)]
+///     email: String,
+/// }
+///
+/// struct Greeting;
+/// impl ItemProcessor<Customer, String> for Greeting {
+///     fn process(&self, item: &Customer) -> ItemProcessorResult<String> {
+///         Ok(Some(format!("Hello {} <{}>", item.name, item.email)))
+///     }
+/// }
+///
+/// let validator = ValidatingProcessor::new();
+/// let processor = CompositeItemProcessor::new(&validator).then(&Greeting);
+///
+/// let valid = Customer { name: "Alice".to_string(), email: "alice@example.com".to_string() };
+/// assert_eq!(
+///     processor.process(&valid).unwrap(),
+///     Some("Hello Alice <alice@example.com>".to_string())
+/// );
+///
+/// let invalid = Customer { name: "Bob".to_string(), email: "bob".to_string() };
+/// assert!(processor.process(&invalid).unwrap_err().to_string().contains("email"));
+/// ```
+pub struct ValidatingProcessor<T> {
+    _pd: PhantomData<T>,
+}
+
+impl<T> ValidatingProcessor<T> {
+    /// Creates a new `ValidatingProcessor`.
+    pub fn new() -> Self {
+        Self { _pd: PhantomData }
+    }
+}
+
+impl<T> Default for ValidatingProcessor<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Validate + Clone> ItemProcessor<T, T> for ValidatingProcessor<T> {
+    fn process(&self, item: &T) -> ItemProcessorResult<T> {
+        item.validate().map_err(|errors| {
+            BatchError::ItemProcessor(format!("invalid item: {}", errors).replace('\n', "; "))
+        })?;
+
+        Ok(Some(item.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use validator::Validate;
+
+    use crate::core::{
+        item::{ItemReader, ItemReaderResult, VecItemWriter},
+        step::{Step, StepBuilder, StepInstance},
+    };
+
+    use super::ValidatingProcessor;
+
+    #[derive(Clone, Debug, PartialEq, Validate)]
+    struct Measure {
+        #[validate(range(min = 0, max = 100))]
+        value: i32,
+    }
+
+    struct Measures(Cell<usize>);
+
+    impl ItemReader<Measure> for Measures {
+        fn read(&self) -> ItemReaderResult<Measure> {
+            let index = self.0.get();
+            self.0.set(index + 1);
+            Ok([12, -4, 99, 250]
+                .get(index)
+                .map(|value| Measure { value: *value }))
+        }
+    }
+
+    #[test]
+    fn invalid_items_should_be_skipped() {
+        let reader = Measures(Cell::new(0));
+        let processor = ValidatingProcessor::new();
+        let writer = VecItemWriter::new();
+
+        let step: StepInstance<Measure, Measure> = StepBuilder::new()
+            .reader(&reader)
+            .processor(&processor)
+            .writer(&writer)
+            .chunk(2)
+            .skip_limit(2)
+            .build();
+
+        assert!(step.execute().is_ok());
+        assert_eq!(step.get_read_count(), 4);
+        assert_eq!(step.get_write_count(), 2);
+        assert_eq!(
+            writer.items(),
+            vec![Measure { value: 12 }, Measure { value: 99 }]
+        );
+    }
+}
@@ -27,8 +27,25 @@
 | rdbc-sqlite   | Enable rdbc reader and writer for Sqlite database             |
 | json          | Enable json reader and writer                                 |
 | csv           | Enable csv reader and writer                                  |
+| fixed-width   | Enable fixed-width (positional) reader and writer             |
 | fake          | Enable fake reader. Useful for generate fake dataset          |
 | logger        | Enable logger writer. Useful for debugging                    |
+| http          | Enable webhook writer and csv/json readers from URLs          |
+| redis         | Enable reader and writer for Redis streams                    |
+| manifest      | Enable manifest of produced files with sizes and checksums    |
+| queue         | Enable in-memory queue reader and writer to pipeline steps    |
+| datetime      | Enable timezone-aware datetime parsing for csv/json readers   |
+| pgp           | Enable decryption of OpenPGP (GPG) encrypted input streams    |
+| avro          | Enable avro (object container file) reader and writer         |
+| gzip          | Enable gzip compression of csv/json files in from_path        |
+| parquet       | Enable parquet reader and writer                              |
+| xlsx          | Enable Excel reader (xlsx, xls, ods) and writer (xlsx)        |
+| tracing       | Enable tracing spans around steps and chunks                  |
+| validation    | Enable processor validating items (validator)                 |
+| zip           | Enable csv/json readers from an entry of a zip archive        |
+| kafka         | Enable reader and writer for Kafka topics                     |
+| object-store  | Enable csv/json readers from S3 and other object stores       |
+| framed        | Enable reader for length-prefixed or delimited binary records |
 
  ## Roadmap
  + XML reader and writer
@@ -78,7 +95,7 @@ Then, on your main.rs:
 #             model: item.model.to_uppercase(),
 #             description: item.description.to_uppercase(),
 #         };
-#         Ok(car)
+#         Ok(Some(car))
 #     }
 # }
 
@@ -15,11 +15,13 @@ use std::{
 
 use spring_batch_rs::{
     core::{
+        item::ItemWriter,
         job::{Job, JobBuilder},
         step::{Step, StepBuilder, StepInstance},
     },
     item::csv::csv_reader::CsvItemReaderBuilder,
     item::json::json_writer::JsonItemWriterBuilder,
+    BatchError,
 };
 
 use time::{format_description, Date};
@@ -174,3 +176,33 @@ fn transform_csv_stream_to_writer_with_error() {
     assert!(step.get_read_error_count() == 0);
     assert!(step.get_write_error_count() == 1);
 }
+
+#[test]
+fn json_writer_should_report_unserializable_items() {
+    fn fail<S: Serializer>(_value: &str, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(S::Error::custom("not serializable"))
+    }
+
+    #[derive(Serialize)]
+    struct Secret {
+        #[serde(serialize_with = "fail")]
+        value: &'static str,
+    }
+
+    let mut output = Vec::new();
+    {
+        let writer = JsonItemWriterBuilder::new().from_writer(&mut output);
+
+        ItemWriter::<Secret>::open(&writer).unwrap();
+        let result = writer.write(&[Secret { value: "hidden" }]);
+
+        let Err(BatchError::Serialization { source, .. }) = result else {
+            panic!("expected a serialization error");
+        };
+        assert_eq!(source.to_string(), "not serializable");
+
+        ItemWriter::<Secret>::close(&writer).unwrap();
+    }
+
+    assert_eq!(String::from_utf8(output).unwrap(), "[]\n");
+}
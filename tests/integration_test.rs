@@ -4,22 +4,25 @@ use std::{
     env::temp_dir,
     fs::{self, read_to_string, File},
     path::Path,
+    str::FromStr,
 };
 
 use ::serde::{ser::Error, Deserialize, Serialize};
 use rand::distributions::{Alphanumeric, DistString};
+use rust_decimal::Decimal;
 use serde::Serializer;
 
 use spring_batch_rs::{
     core::{
-        item::{ItemProcessor, ItemProcessorResult},
+        item::{ItemProcessor, ItemProcessorResult, ItemReader},
         job::{Job, JobBuilder},
         step::{Step, StepBuilder, StepInstance, StepStatus},
     },
     item::csv::csv_reader::CsvItemReaderBuilder,
     item::csv::csv_writer::CsvItemWriterBuilder,
+    item::json::json_line_reader::JsonLineItemReaderBuilder,
     item::json::json_reader::JsonItemReaderBuilder,
-    item::json::json_writer::JsonItemWriterBuilder,
+    item::json::json_writer::{JsonItemWriterBuilder, JsonOutputFormat},
 };
 use time::{format_description, Date, Month};
 
@@ -61,7 +64,7 @@ impl ItemProcessor<Person, Person> for UpperCaseProcessor {
             birth_date: Date::from_calendar_date(2019, Month::January, 1).unwrap(),
         };
 
-        Ok(person)
+        Ok(Some(person))
     }
 }
 
@@ -73,7 +76,7 @@ fn transform_from_json_file_to_csv_file_without_error() {
 
     let reader = JsonItemReaderBuilder::new().from_reader(file);
 
-    let processor = UpperCaseProcessor::default();
+    let processor = UpperCaseProcessor;
 
     let writer = CsvItemWriterBuilder::new()
         .has_headers(true)
@@ -246,3 +249,203 @@ fn transform_csv_stream_to_writer_with_3_errors_should_failed() {
 "#
     );
 }
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+struct Invoice {
+    reference: String,
+    #[serde(with = "rust_decimal::serde::str")]
+    amount: Decimal,
+}
+
+#[test]
+fn decimal_should_round_trip_from_csv_to_json_without_precision_loss() {
+    let csv = "reference,amount
+INV-1,1299.99
+INV-2,12345678901234567.89
+INV-3,0.10";
+
+    let reader = CsvItemReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv.as_bytes());
+
+    let file_name = Alphanumeric.sample_string(&mut rand::thread_rng(), 16);
+    let writer = JsonItemWriterBuilder::new().from_path(temp_dir().join(file_name.clone()));
+
+    let step: StepInstance<Invoice, Invoice> = StepBuilder::new()
+        .reader(&reader)
+        .writer(&writer)
+        .chunk(2)
+        .build();
+
+    let job = JobBuilder::new().start(&step).build();
+    assert!(job.run().is_ok());
+
+    let file_content = fs::read_to_string(temp_dir().join(file_name.clone()))
+        .expect("Should have been able to read the file");
+
+    assert_eq!(
+        file_content,
+        r#"[{"reference":"INV-1","amount":"1299.99"},{"reference":"INV-2","amount":"12345678901234567.89"},{"reference":"INV-3","amount":"0.10"}]
+"#
+    );
+
+    let file = File::open(temp_dir().join(file_name)).expect("Unable to open file");
+    let reader = JsonItemReaderBuilder::new().from_reader(file);
+
+    let first: Invoice = reader.read().unwrap().unwrap();
+    let second: Invoice = reader.read().unwrap().unwrap();
+    let third: Invoice = reader.read().unwrap().unwrap();
+
+    assert_eq!(first.amount, Decimal::from_str("1299.99").unwrap());
+    assert_eq!(
+        second.amount,
+        Decimal::from_str("12345678901234567.89").unwrap()
+    );
+    assert_eq!(third.amount.to_string(), "0.10");
+}
+
+#[test]
+fn step_should_stop_cleanly_when_the_pipe_is_closed() {
+    let csv = "year,make,model,description\n".to_string()
+        + &"1948,Porsche,356,Luxury sports car\n".repeat(1000);
+
+    let reader = CsvItemReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv.as_bytes());
+
+    let (pipe_reader, pipe_writer) = std::io::pipe().unwrap();
+    drop(pipe_reader);
+
+    let writer = JsonItemWriterBuilder::new()
+        .stop_on_broken_pipe(true)
+        .from_writer(pipe_writer);
+
+    let step: StepInstance<Car, Car> = StepBuilder::new()
+        .reader(&reader)
+        .writer(&writer)
+        .chunk(10)
+        .build();
+
+    let result = step.execute();
+
+    assert!(result.is_ok());
+    assert_eq!(step.get_status(), StepStatus::Success);
+    assert_eq!(step.get_read_count(), 10);
+    assert_eq!(step.get_write_error_count(), 0);
+
+    let reader = CsvItemReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv.as_bytes());
+
+    let (pipe_reader, pipe_writer) = std::io::pipe().unwrap();
+    drop(pipe_reader);
+
+    let writer = JsonItemWriterBuilder::new().from_writer(pipe_writer);
+
+    let step: StepInstance<Car, Car> = StepBuilder::new()
+        .reader(&reader)
+        .writer(&writer)
+        .chunk(10)
+        .build();
+
+    let result = step.execute();
+
+    assert!(result.is_err());
+    assert_eq!(step.get_status(), StepStatus::WriteError);
+}
+
+#[test]
+fn gzip_files_should_round_trip() {
+    let csv = "year,make,model,description
+    1948,Porsche,356,Luxury sports car
+    2011,Peugeot,206+,City car
+    2012,Citroën,C4 Picasso,SUV";
+
+    let dir = tempfile::tempdir().unwrap();
+    let json_path = dir.path().join("cars.json.gz");
+    let csv_path = dir.path().join("cars.csv.gz");
+
+    // CSV string to gzip-compressed JSON file
+    let reader = CsvItemReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv.as_bytes());
+
+    let writer = JsonItemWriterBuilder::new().from_path(&json_path);
+
+    let step: StepInstance<Car, Car> = StepBuilder::new()
+        .reader(&reader)
+        .writer(&writer)
+        .chunk(2)
+        .build();
+
+    assert!(step.execute().is_ok());
+
+    // Gzip-compressed JSON file to gzip-compressed CSV file
+    let reader = JsonItemReaderBuilder::new().from_path(&json_path);
+
+    let writer = CsvItemWriterBuilder::new()
+        .has_headers(true)
+        .from_path(&csv_path);
+
+    let step: StepInstance<Car, Car> = StepBuilder::new()
+        .reader(&reader)
+        .writer(&writer)
+        .chunk(2)
+        .build();
+
+    assert!(step.execute().is_ok());
+    assert_eq!(step.get_write_count(), 3);
+
+    // Gzip-compressed CSV file back to items
+    let reader = CsvItemReaderBuilder::new()
+        .has_headers(true)
+        .from_path(&csv_path);
+
+    let cars: Vec<Car> = std::iter::from_fn(|| reader.read().unwrap()).collect();
+
+    assert_eq!(cars.len(), 3);
+    assert_eq!(cars[1].model, "206+");
+    assert_eq!(cars[2].make, "Citroën");
+    assert_ne!(&fs::read(&csv_path).unwrap()[..2], b"ye");
+}
+
+#[test]
+fn json_lines_should_be_written_without_enclosing_array() {
+    let csv = "year,make,model,description
+    1948,Porsche,356,Luxury sports car
+    2011,Peugeot,206+,City car
+    2012,Citroën,C4 Picasso,SUV";
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("cars.jsonl");
+
+    let reader = CsvItemReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv.as_bytes());
+
+    let writer = JsonItemWriterBuilder::new()
+        .format(JsonOutputFormat::Lines)
+        .pretty_formatter(true)
+        .from_path(&path);
+
+    let step: StepInstance<Car, Car> = StepBuilder::new()
+        .reader(&reader)
+        .writer(&writer)
+        .chunk(2)
+        .build();
+
+    assert!(step.execute().is_ok());
+
+    let content = fs::read_to_string(&path).unwrap();
+    assert_eq!(content.lines().count(), 3);
+    assert_eq!(
+        content.lines().next().unwrap(),
+        r#"{"year":1948,"make":"Porsche","model":"356","description":"Luxury sports car"}"#
+    );
+
+    let reader = JsonLineItemReaderBuilder::new().from_path(&path);
+    let cars: Vec<Car> = std::iter::from_fn(|| reader.read().unwrap()).collect();
+
+    assert_eq!(cars.len(), 3);
+    assert_eq!(cars[2].make, "Citroën");
+}
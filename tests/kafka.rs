@@ -0,0 +1,83 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use testcontainers_modules::{
+    kafka::apache::{Kafka, KAFKA_PORT},
+    testcontainers::runners::SyncRunner,
+};
+
+use spring_batch_rs::{
+    core::{
+        job::{Job, JobBuilder},
+        step::{Step, StepBuilder, StepInstance, StepStatus},
+    },
+    item::csv::csv_reader::CsvItemReaderBuilder,
+    item::csv::csv_writer::CsvItemWriterBuilder,
+    item::kafka::{kafka_reader::KafkaItemReaderBuilder, kafka_writer::KafkaItemWriterBuilder},
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct Book {
+    title: String,
+    author: String,
+    year: u16,
+}
+
+#[test]
+fn write_then_read_items_from_topic() -> Result<()> {
+    let container = Kafka::default().start().unwrap();
+    let host_ip = container.get_host().unwrap();
+    let host_port = container.get_host_port_ipv4(KAFKA_PORT).unwrap();
+
+    let brokers = format!("{host_ip}:{host_port}");
+
+    // Write books into the topic
+    let csv = "title,author,year
+            Shining,Stephen King,1977
+            Un sac de billes,Joseph Joffo,1973
+            Dune,Frank Herbert,1965";
+
+    let reader = CsvItemReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv.as_bytes());
+
+    let writer = KafkaItemWriterBuilder::new()
+        .brokers(&brokers)
+        .topic("books")
+        .key(|book: &Book| book.author.clone())
+        .build();
+
+    let step: StepInstance<Book, Book> = StepBuilder::new()
+        .reader(&reader)
+        .writer(&writer)
+        .chunk(2)
+        .build();
+
+    let job = JobBuilder::new().start(&step).build();
+    let result = job.run();
+    assert!(result.is_ok());
+    assert!(step.get_status() == StepStatus::Success);
+    assert!(step.get_write_count() == 3);
+
+    // Read books back from the topic
+    let reader = KafkaItemReaderBuilder::new()
+        .brokers(&brokers)
+        .topic("books")
+        .build();
+
+    let writer = CsvItemWriterBuilder::new().from_writer(vec![]);
+
+    let step: StepInstance<Book, Book> = StepBuilder::new()
+        .reader(&reader)
+        .writer(&writer)
+        .chunk(2)
+        .build();
+
+    let job = JobBuilder::new().start(&step).build();
+    let result = job.run();
+    assert!(result.is_ok());
+    assert!(step.get_status() == StepStatus::Success);
+    assert!(step.get_read_count() == 3);
+    assert!(step.get_read_error_count() == 0);
+
+    Ok(())
+}
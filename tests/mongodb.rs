@@ -3,13 +3,13 @@ use std::io::Read;
 use testcontainers_modules::{mongo, testcontainers::runners::SyncRunner};
 
 use mongodb::{
-    bson::{doc, oid::ObjectId},
+    bson::{doc, oid::ObjectId, Document},
     sync::Client,
 };
 use serde::{Deserialize, Serialize};
 use spring_batch_rs::{
     core::{
-        item::{ItemProcessor, ItemProcessorResult},
+        item::{ItemProcessor, ItemProcessorResult, ItemReader},
         job::{Job, JobBuilder},
         step::{Step, StepBuilder, StepInstance, StepStatus},
     },
@@ -50,7 +50,7 @@ impl ItemProcessor<Book, FormattedBook> for FormatBookProcessor {
             author: item.author.replace(" ", "_").to_uppercase(),
         };
 
-        Ok(book)
+        Ok(Some(book))
     }
 }
 
@@ -198,6 +198,135 @@ fn read_items_from_database() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn read_projected_items_in_sort_order() -> Result<()> {
+    #[derive(Debug, Deserialize)]
+    struct BookTitle {
+        #[serde(rename = "oid")]
+        id: ObjectId,
+        title: String,
+        author: Option<String>,
+    }
+
+    impl WithObjectId for BookTitle {
+        fn get_id(&self) -> ObjectId {
+            self.id
+        }
+    }
+
+    let container = mongo::Mongo::default().start().unwrap();
+    let host_ip = container.get_host().unwrap();
+    let host_port = container.get_host_port_ipv4(27017).unwrap();
+
+    let url = format!("mongodb://{host_ip}:{host_port}/");
+
+    let client: Client = Client::with_uri_str(&url).unwrap();
+
+    let db = client.database("test");
+
+    let book_collection = db.collection::<Book>("sorted_books");
+
+    let books: Vec<Book> = ["Dune", "Carrie", "Anathem", "Emma", "Blindsight"]
+        .iter()
+        .map(|title| Book {
+            id: ObjectId::new(),
+            title: title.to_string(),
+            author: if *title == "Emma" { "Austen" } else { "Other" }.to_string(),
+        })
+        .collect();
+
+    book_collection.insert_many(books).run()?;
+
+    let title_collection = book_collection.clone_with_type::<BookTitle>();
+    let read_titles = |reader: &dyn ItemReader<BookTitle>| -> Result<Vec<String>> {
+        let mut titles = Vec::new();
+        while let Some(book) = reader.read()? {
+            assert!(book.author.is_none());
+            titles.push(book.title);
+        }
+        Ok(titles)
+    };
+
+    // Sorted, filtered and projected items are read from a single cursor
+    let reader = MongodbItemReaderBuilder::new()
+        .collection(&title_collection)
+        .filter(doc! {"author": "Other"})
+        .projection(doc! {"oid": 1, "title": 1})
+        .sort(doc! {"title": -1, "oid": 1})
+        .page_size(2)
+        .build();
+
+    assert_eq!(
+        read_titles(&reader)?,
+        ["Dune", "Carrie", "Blindsight", "Anathem"]
+    );
+
+    // Projected items are read by pages of oid without a sort
+    let reader = MongodbItemReaderBuilder::new()
+        .collection(&title_collection)
+        .projection(doc! {"oid": 1, "title": 1})
+        .page_size(2)
+        .build();
+
+    assert_eq!(
+        read_titles(&reader)?,
+        ["Dune", "Carrie", "Anathem", "Emma", "Blindsight"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn malformed_items_should_be_skipped_but_not_retried() -> Result<()> {
+    let container = mongo::Mongo::default().start().unwrap();
+    let host_ip = container.get_host().unwrap();
+    let host_port = container.get_host_port_ipv4(27017).unwrap();
+
+    let url = format!("mongodb://{host_ip}:{host_port}/");
+
+    let client: Client = Client::with_uri_str(&url).unwrap();
+
+    let db = client.database("test");
+
+    // The second book has no author
+    let documents = vec![
+        doc! {"oid": ObjectId::new(), "title": "Shining", "author": "Stephen King"},
+        doc! {"oid": ObjectId::new(), "title": "Un sac de billes"},
+        doc! {"oid": ObjectId::new(), "title": "Dune", "author": "Frank Herbert"},
+    ];
+    db.collection::<Document>("raw_books")
+        .insert_many(documents)
+        .run()?;
+
+    let book_collection = db.collection::<Book>("raw_books");
+
+    for skip_limit in [0, 1] {
+        let reader = MongodbItemReaderBuilder::new()
+            .collection(&book_collection)
+            .page_size(1)
+            .build();
+
+        let writer = CsvItemWriterBuilder::new().from_writer(vec![]);
+
+        let step: StepInstance<Book, Book> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .retry_limit(1)
+            .skip_limit(skip_limit)
+            .build();
+
+        let result = step.execute();
+        assert_eq!(result.is_ok(), skip_limit == 1);
+        assert_eq!(step.get_read_error_count(), 1);
+        assert_eq!(step.get_retry_count(), 0);
+        if skip_limit == 1 {
+            assert_eq!(step.get_write_count(), 2);
+        }
+    }
+
+    Ok(())
+}
+
 #[test]
 fn write_items_to_database() -> Result<()> {
     let container = mongo::Mongo::default().start().unwrap();
@@ -244,3 +373,54 @@ fn write_items_to_database() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn upsert_items_to_database() -> Result<()> {
+    let container = mongo::Mongo::default().start().unwrap();
+    let host_ip = container.get_host().unwrap();
+    let host_port = container.get_host_port_ipv4(27017).unwrap();
+
+    let url = format!("mongodb://{host_ip}:{host_port}/");
+
+    let client: Client = Client::with_uri_str(&url).unwrap();
+
+    let db = client.database("test");
+
+    let book_collection = db.collection::<FormattedBook>("upserted_books");
+
+    let writer = MongodbItemWriterBuilder::new()
+        .collection(&book_collection)
+        .upsert_on("title")
+        .build();
+
+    // First load inserts, second load updates the existing document
+    for csv in [
+        "title,author
+            Shining,Stephen King
+            UN SAC DE BILLES,JOSEPH JOFFO",
+        "title,author
+            Shining,STEPHEN KING",
+    ] {
+        let reader = CsvItemReaderBuilder::new()
+            .has_headers(true)
+            .from_reader(csv.as_bytes());
+
+        let step: StepInstance<FormattedBook, FormattedBook> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .chunk(3)
+            .build();
+
+        assert!(step.execute().is_ok());
+    }
+
+    assert_eq!(book_collection.count_documents(doc! {}).run()?, 2);
+
+    let shining = book_collection
+        .find_one(doc! { "title": "Shining" })
+        .run()?
+        .unwrap();
+    assert_eq!(shining.author, "STEPHEN KING");
+
+    Ok(())
+}
@@ -71,7 +71,7 @@ async fn read_items_from_database() -> Result<(), sqlx::Error> {
     let row_mapper = PersonRowMapper::default();
     let reader = RdbcItemReaderBuilder::new()
         .pool(&pool)
-        .query(&query)
+        .query(query)
         .row_mapper(&row_mapper)
         .page_size(5)
         .build();
@@ -224,3 +224,120 @@ async fn write_items_to_database() -> Result<(), sqlx::Error> {
     assert!(!car_results.is_empty());
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn write_items_beyond_bind_limit() -> Result<(), sqlx::Error> {
+    let csv = "year,make,model,description
+            1948,Porsche,356,Luxury sports car
+            2011,Peugeot,206+,City car
+            2012,Citroën,C4 Picasso,SUV
+            2021,Mazda,CX-30,SUV Compact
+            1967,Ford,Mustang fastback 1967,American car";
+
+    let reader = CsvItemReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv.as_bytes());
+
+    let database_file = NamedTempFile::new()?;
+    let database_path = database_file.path().to_str().unwrap();
+    let connection_uri = format!("sqlite://{}", database_path);
+
+    sqlx::any::install_default_drivers();
+    let pool = AnyPool::connect(&connection_uri).await?;
+
+    let create_query = sqlx::query("CREATE TABLE IF NOT EXISTS cars (year INTEGER NOT NULL, make VARCHAR(25) NOT NULL, model VARCHAR(25) NOT NULL, description VARCHAR(25) NOT NULL);");
+    create_query.execute(&pool).await?;
+
+    let item_binder = CarItemBinder {};
+
+    // Two rows of four columns per statement, a chunk of five needs three
+    let writer = RdbcItemWriterBuilder::new()
+        .table("cars")
+        .add_column("year")
+        .add_column("make")
+        .add_column("model")
+        .add_column("description")
+        .pool(&pool)
+        .item_binder(&item_binder)
+        .bind_limit(8)
+        .build();
+
+    let step: StepInstance<Car, Car> = StepBuilder::new()
+        .reader(&reader)
+        .writer(&writer)
+        .chunk(5)
+        .build();
+
+    let job = JobBuilder::new().start(&step).build();
+    let result = job.run();
+    assert!(result.is_ok());
+    assert!(step.get_write_count() == 5);
+    assert!(step.get_write_error_count() == 0);
+
+    let car_results = sqlx::query_as::<_, Car>("SELECT year, make, model, description FROM cars")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+
+    assert_eq!(car_results.len(), 5);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failed_chunk_should_be_rolled_back() -> Result<(), sqlx::Error> {
+    // The last car duplicates the model of the first one
+    let csv = "year,make,model,description
+            1948,Porsche,356,Luxury sports car
+            2011,Peugeot,206+,City car
+            2012,Citroën,C4 Picasso,SUV
+            2021,Mazda,CX-30,SUV Compact
+            1950,Porsche,356,Luxury sports car";
+
+    let reader = CsvItemReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv.as_bytes());
+
+    let database_file = NamedTempFile::new()?;
+    let database_path = database_file.path().to_str().unwrap();
+    let connection_uri = format!("sqlite://{}", database_path);
+
+    sqlx::any::install_default_drivers();
+    let pool = AnyPool::connect(&connection_uri).await?;
+
+    let create_query = sqlx::query("CREATE TABLE IF NOT EXISTS cars (year INTEGER NOT NULL, make VARCHAR(25) NOT NULL, model VARCHAR(25) NOT NULL UNIQUE, description VARCHAR(25) NOT NULL);");
+    create_query.execute(&pool).await?;
+
+    let item_binder = CarItemBinder {};
+
+    // The chunk is split in three statements, only the last one fails
+    let writer = RdbcItemWriterBuilder::new()
+        .table("cars")
+        .add_column("year")
+        .add_column("make")
+        .add_column("model")
+        .add_column("description")
+        .pool(&pool)
+        .item_binder(&item_binder)
+        .bind_limit(8)
+        .build();
+
+    let step: StepInstance<Car, Car> = StepBuilder::new()
+        .reader(&reader)
+        .writer(&writer)
+        .chunk(5)
+        .skip_limit(5)
+        .build();
+
+    let job = JobBuilder::new().start(&step).build();
+    let result = job.run();
+    assert!(result.is_ok());
+    assert!(step.get_write_error_count() == 5);
+
+    let car_results = sqlx::query_as::<_, Car>("SELECT year, make, model, description FROM cars")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+
+    assert!(car_results.is_empty());
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use testcontainers_modules::{
+    redis::{Redis, REDIS_PORT},
+    testcontainers::runners::SyncRunner,
+};
+
+use spring_batch_rs::{
+    core::{
+        job::{Job, JobBuilder},
+        step::{Step, StepBuilder, StepInstance, StepStatus},
+    },
+    item::csv::csv_reader::CsvItemReaderBuilder,
+    item::csv::csv_writer::CsvItemWriterBuilder,
+    item::redis::{
+        redis_reader::RedisStreamItemReaderBuilder, redis_writer::RedisStreamItemWriterBuilder,
+    },
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct Book {
+    title: String,
+    author: String,
+    year: u16,
+}
+
+#[test]
+fn write_then_read_items_from_stream() -> Result<()> {
+    let container = Redis::default().start().unwrap();
+    let host_ip = container.get_host().unwrap();
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).unwrap();
+
+    let url = format!("redis://{host_ip}:{host_port}/");
+
+    // Write books into the stream
+    let csv = "title,author,year
+            Shining,Stephen King,1977
+            Un sac de billes,Joseph Joffo,1973
+            Dune,Frank Herbert,1965";
+
+    let reader = CsvItemReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv.as_bytes());
+
+    let writer = RedisStreamItemWriterBuilder::new()
+        .url(&url)
+        .stream("books")
+        .build();
+
+    let step: StepInstance<Book, Book> = StepBuilder::new()
+        .reader(&reader)
+        .writer(&writer)
+        .chunk(2)
+        .build();
+
+    let job = JobBuilder::new().start(&step).build();
+    let result = job.run();
+    assert!(result.is_ok());
+    assert!(step.get_status() == StepStatus::Success);
+    assert!(step.get_write_count() == 3);
+
+    // Read books back from the stream
+    let reader = RedisStreamItemReaderBuilder::new()
+        .url(&url)
+        .stream("books")
+        .group("batch")
+        .consumer("worker-1")
+        .count(2)
+        .block(Duration::from_millis(100))
+        .build();
+
+    let writer = CsvItemWriterBuilder::new().from_writer(vec![]);
+
+    let step: StepInstance<Book, Book> = StepBuilder::new()
+        .reader(&reader)
+        .writer(&writer)
+        .chunk(2)
+        .build();
+
+    let job = JobBuilder::new().start(&step).build();
+    let result = job.run();
+    assert!(result.is_ok());
+    assert!(step.get_status() == StepStatus::Success);
+    assert!(step.get_read_count() == 3);
+    assert!(step.get_read_error_count() == 0);
+
+    Ok(())
+}
+
+#[test]
+fn malformed_entries_should_be_skipped_but_not_retried() -> Result<()> {
+    #[derive(Serialize, Deserialize, Clone)]
+    struct RawBook {
+        title: String,
+        year: serde_json::Value,
+    }
+
+    let container = Redis::default().start().unwrap();
+    let host_ip = container.get_host().unwrap();
+    let host_port = container.get_host_port_ipv4(REDIS_PORT).unwrap();
+
+    let url = format!("redis://{host_ip}:{host_port}/");
+
+    // Write books into the stream, one of them with an invalid year
+    let csv = "title,year
+            Shining,1977
+            Un sac de billes,unknown
+            Dune,1965";
+
+    let reader = CsvItemReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv.as_bytes());
+
+    let writer = RedisStreamItemWriterBuilder::new()
+        .url(&url)
+        .stream("raw_books")
+        .build();
+
+    let step: StepInstance<RawBook, RawBook> =
+        StepBuilder::new().reader(&reader).writer(&writer).build();
+
+    assert!(step.execute().is_ok());
+
+    #[derive(Deserialize, Serialize, Clone)]
+    struct DatedBook {
+        title: String,
+        year: u16,
+    }
+
+    // Each consumer group reads the whole stream
+    for (group, skip_limit) in [("strict", 0), ("tolerant", 1)] {
+        let reader = RedisStreamItemReaderBuilder::new()
+            .url(&url)
+            .stream("raw_books")
+            .group(group)
+            .consumer("worker-1")
+            .block(Duration::from_millis(100))
+            .build();
+
+        let writer = CsvItemWriterBuilder::new().from_writer(vec![]);
+
+        let step: StepInstance<DatedBook, DatedBook> = StepBuilder::new()
+            .reader(&reader)
+            .writer(&writer)
+            .retry_limit(1)
+            .skip_limit(skip_limit)
+            .build();
+
+        let result = step.execute();
+        assert_eq!(result.is_ok(), skip_limit == 1);
+        assert_eq!(step.get_read_error_count(), 1);
+        assert_eq!(step.get_retry_count(), 0);
+        if skip_limit == 1 {
+            assert_eq!(step.get_write_count(), 2);
+        }
+    }
+
+    Ok(())
+}